@@ -0,0 +1,3 @@
+-- 用户角色：user / editor / admin
+ALTER TABLE users
+    ADD COLUMN role VARCHAR(20) NOT NULL DEFAULT 'user' AFTER password_hash;
@@ -0,0 +1,22 @@
+-- 文章分类表（物化路径存储树结构）
+-- path 形如 /<祖先 id>/.../<自身 id>/，子树查询只需 path 前缀匹配
+CREATE TABLE IF NOT EXISTS categories (
+    id CHAR(36) PRIMARY KEY,
+    parent_id CHAR(36) NULL,
+    name VARCHAR(50) NOT NULL,
+    slug VARCHAR(64) UNIQUE NOT NULL,
+    path VARCHAR(512) NOT NULL,
+    depth INT NOT NULL,
+    sort_order INT NOT NULL DEFAULT 0,
+    created_at DATETIME NOT NULL,
+    updated_at DATETIME NOT NULL,
+    INDEX idx_parent_id (parent_id),
+    INDEX idx_path (path),
+    FOREIGN KEY (parent_id) REFERENCES categories (id)
+);
+
+-- 文章所属分类
+ALTER TABLE articles
+    ADD COLUMN category_id CHAR(36) NULL,
+    ADD INDEX idx_category_id (category_id),
+    ADD FOREIGN KEY (category_id) REFERENCES categories (id) ON DELETE SET NULL;
@@ -0,0 +1,18 @@
+-- 文章评论表（通过 parent_id 形成有限深度的嵌套结构）
+-- 删除为软删除：保留行以维持评论树结构，内容置空
+CREATE TABLE IF NOT EXISTS comments (
+    id CHAR(36) PRIMARY KEY,
+    article_id CHAR(36) NOT NULL,
+    user_id CHAR(36) NOT NULL,
+    parent_id CHAR(36) NULL,
+    depth INT NOT NULL,
+    content TEXT NOT NULL,
+    is_deleted BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at DATETIME NOT NULL,
+    updated_at DATETIME NOT NULL,
+    INDEX idx_article_created (article_id, created_at),
+    INDEX idx_parent_id (parent_id),
+    FOREIGN KEY (article_id) REFERENCES articles (id) ON DELETE CASCADE,
+    FOREIGN KEY (user_id) REFERENCES users (id),
+    FOREIGN KEY (parent_id) REFERENCES comments (id)
+);
@@ -0,0 +1,23 @@
+-- 文章表情反应（每个用户对每篇文章最多一个反应）
+CREATE TABLE IF NOT EXISTS article_reactions (
+    user_id CHAR(36) NOT NULL,
+    article_id CHAR(36) NOT NULL,
+    reaction VARCHAR(20) NOT NULL,
+    created_at DATETIME NOT NULL,
+    PRIMARY KEY (user_id, article_id),
+    INDEX idx_article_reaction (article_id, reaction),
+    FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE,
+    FOREIGN KEY (article_id) REFERENCES articles (id) ON DELETE CASCADE
+);
+
+-- 文章收藏
+CREATE TABLE IF NOT EXISTS article_bookmarks (
+    user_id CHAR(36) NOT NULL,
+    article_id CHAR(36) NOT NULL,
+    created_at DATETIME NOT NULL,
+    PRIMARY KEY (user_id, article_id),
+    INDEX idx_user_created (user_id, created_at),
+    INDEX idx_article_id (article_id),
+    FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE,
+    FOREIGN KEY (article_id) REFERENCES articles (id) ON DELETE CASCADE
+);
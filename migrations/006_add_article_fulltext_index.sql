@@ -0,0 +1,4 @@
+-- 文章全文索引（ngram 解析器支持中日韩文本分词，默认 ngram_token_size = 2）
+ALTER TABLE articles
+    ADD FULLTEXT INDEX ft_title (title) WITH PARSER ngram,
+    ADD FULLTEXT INDEX ft_title_content (title, content) WITH PARSER ngram;
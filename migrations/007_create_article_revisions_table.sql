@@ -0,0 +1,14 @@
+-- 文章修订历史（只追加，不修改）
+CREATE TABLE IF NOT EXISTS article_revisions (
+    id CHAR(36) PRIMARY KEY,
+    article_id CHAR(36) NOT NULL,
+    revision_number INT NOT NULL,
+    title VARCHAR(100) NOT NULL,
+    content TEXT NOT NULL,
+    author_id CHAR(36) NOT NULL,
+    restored_from INT NULL,
+    created_at DATETIME NOT NULL,
+    UNIQUE INDEX uk_article_revision (article_id, revision_number),
+    FOREIGN KEY (article_id) REFERENCES articles (id) ON DELETE CASCADE,
+    FOREIGN KEY (author_id) REFERENCES users (id)
+);
@@ -0,0 +1,5 @@
+-- 文章正文格式与渲染缓存
+-- content_html 在写入时生成；历史数据为 NULL，读取时按需渲染
+ALTER TABLE articles
+    ADD COLUMN content_format VARCHAR(20) NOT NULL DEFAULT 'markdown' AFTER content,
+    ADD COLUMN content_html MEDIUMTEXT NULL AFTER content_format;
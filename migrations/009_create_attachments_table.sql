@@ -0,0 +1,15 @@
+-- 文章附件（文件内容保存在存储后端，表中只保存元数据）
+CREATE TABLE IF NOT EXISTS attachments (
+    id CHAR(36) PRIMARY KEY,
+    article_id CHAR(36) NOT NULL,
+    user_id CHAR(36) NOT NULL,
+    storage_key VARCHAR(255) NOT NULL,
+    filename VARCHAR(255) NOT NULL,
+    content_type VARCHAR(100) NOT NULL,
+    size_bytes BIGINT NOT NULL,
+    created_at DATETIME NOT NULL,
+    INDEX idx_article_id (article_id),
+    INDEX idx_user_id (user_id),
+    FOREIGN KEY (article_id) REFERENCES articles (id) ON DELETE CASCADE,
+    FOREIGN KEY (user_id) REFERENCES users (id)
+);
@@ -0,0 +1,13 @@
+-- 文章累计浏览量
+ALTER TABLE articles
+    ADD COLUMN view_count BIGINT NOT NULL DEFAULT 0;
+
+-- 按小时聚合的浏览量，用于计算热门排行（带时间衰减）
+CREATE TABLE IF NOT EXISTS article_view_buckets (
+    article_id CHAR(36) NOT NULL,
+    bucket_start DATETIME NOT NULL,
+    views INT NOT NULL,
+    PRIMARY KEY (article_id, bucket_start),
+    INDEX idx_bucket_start (bucket_start),
+    FOREIGN KEY (article_id) REFERENCES articles (id) ON DELETE CASCADE
+);
@@ -0,0 +1,8 @@
+-- 文章最后修改时间（用于订阅源的 Last-Modified 等）
+-- 不使用 ON UPDATE CURRENT_TIMESTAMP，避免浏览量累加时也被刷新
+ALTER TABLE articles
+    ADD COLUMN updated_at DATETIME NULL AFTER created_at;
+
+UPDATE articles SET updated_at = created_at WHERE updated_at IS NULL;
+
+CREATE INDEX idx_articles_public_created ON articles (is_public, created_at);
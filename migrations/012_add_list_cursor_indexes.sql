@@ -0,0 +1,3 @@
+-- 游标分页按 (created_at, id) 倒序扫描
+CREATE INDEX idx_articles_created_id ON articles (created_at, id);
+CREATE INDEX idx_users_created_id ON users (created_at, id);
@@ -0,0 +1,6 @@
+-- 乐观并发控制：每次更新递增版本号，作为 ETag 返回
+ALTER TABLE articles
+    ADD COLUMN version INT NOT NULL DEFAULT 1;
+
+ALTER TABLE users
+    ADD COLUMN version INT NOT NULL DEFAULT 1;
@@ -0,0 +1,43 @@
+-- 文章系列（由作者维护的有序文章组）
+CREATE TABLE IF NOT EXISTS series (
+    id CHAR(36) PRIMARY KEY,
+    user_id CHAR(36) NOT NULL,
+    title VARCHAR(100) NOT NULL,
+    description TEXT NULL,
+    created_at DATETIME NOT NULL,
+    updated_at DATETIME NOT NULL,
+    INDEX idx_user_created (user_id, created_at),
+    FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
+
+-- 文章所属系列及在系列中的位置（从 1 开始，每篇文章最多属于一个系列）
+ALTER TABLE articles
+    ADD COLUMN series_id CHAR(36) NULL,
+    ADD COLUMN series_position INT NULL,
+    ADD INDEX idx_series_position (series_id, series_position),
+    ADD FOREIGN KEY (series_id) REFERENCES series (id) ON DELETE SET NULL;
+
+-- 用户收藏夹（可收录任意可见文章）
+CREATE TABLE IF NOT EXISTS collections (
+    id CHAR(36) PRIMARY KEY,
+    user_id CHAR(36) NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    description TEXT NULL,
+    is_public BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at DATETIME NOT NULL,
+    updated_at DATETIME NOT NULL,
+    INDEX idx_user_created (user_id, created_at),
+    FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
+
+-- 收藏夹中的文章（按加入时间排序）
+CREATE TABLE IF NOT EXISTS collection_items (
+    collection_id CHAR(36) NOT NULL,
+    article_id CHAR(36) NOT NULL,
+    created_at DATETIME NOT NULL,
+    PRIMARY KEY (collection_id, article_id),
+    INDEX idx_collection_created (collection_id, created_at),
+    INDEX idx_article_id (article_id),
+    FOREIGN KEY (collection_id) REFERENCES collections (id) ON DELETE CASCADE,
+    FOREIGN KEY (article_id) REFERENCES articles (id) ON DELETE CASCADE
+);
@@ -0,0 +1,6 @@
+-- 文章摘要、字数和预计阅读时间（写入时计算并保存）
+-- 历史数据保持为 NULL，读取时即时计算，下次更新文章时写入
+ALTER TABLE articles
+    ADD COLUMN excerpt VARCHAR(255) NULL AFTER content_html,
+    ADD COLUMN word_count INT NULL AFTER excerpt,
+    ADD COLUMN reading_minutes INT NULL AFTER word_count;
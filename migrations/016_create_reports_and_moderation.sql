@@ -0,0 +1,35 @@
+-- 被管理员隐藏的文章（隐藏后所有读取路径都不再返回）
+ALTER TABLE articles
+    ADD COLUMN hidden_at DATETIME NULL;
+
+-- 内容举报（同一用户对同一内容只保留一条举报）
+CREATE TABLE IF NOT EXISTS content_reports (
+    id CHAR(36) PRIMARY KEY,
+    target_type VARCHAR(20) NOT NULL,
+    target_id CHAR(36) NOT NULL,
+    reporter_id CHAR(36) NOT NULL,
+    reason VARCHAR(20) NOT NULL,
+    details TEXT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'open',
+    created_at DATETIME NOT NULL,
+    resolved_at DATETIME NULL,
+    resolved_by CHAR(36) NULL,
+    UNIQUE KEY uk_target_reporter (target_type, target_id, reporter_id),
+    INDEX idx_status_created (status, created_at),
+    FOREIGN KEY (reporter_id) REFERENCES users (id) ON DELETE CASCADE
+);
+
+-- 管理操作记录（隐藏、恢复、删除、警告作者、驳回举报）
+CREATE TABLE IF NOT EXISTS moderation_actions (
+    id CHAR(36) PRIMARY KEY,
+    target_type VARCHAR(20) NOT NULL,
+    target_id CHAR(36) NOT NULL,
+    target_user_id CHAR(36) NULL,
+    moderator_id CHAR(36) NOT NULL,
+    action VARCHAR(20) NOT NULL,
+    note TEXT NULL,
+    created_at DATETIME NOT NULL,
+    INDEX idx_target (target_type, target_id, created_at),
+    INDEX idx_target_user (target_user_id, created_at),
+    FOREIGN KEY (moderator_id) REFERENCES users (id)
+);
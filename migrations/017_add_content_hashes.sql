@@ -0,0 +1,13 @@
+-- 内容过滤：正文指纹（用于重复内容检测）
+-- 历史数据保持为 NULL，下次更新时写入
+ALTER TABLE articles
+    ADD COLUMN content_hash CHAR(64) NULL,
+    ADD INDEX idx_articles_content_hash (content_hash);
+
+ALTER TABLE comments
+    ADD COLUMN content_hash CHAR(64) NULL,
+    ADD INDEX idx_comments_user_content_hash (user_id, content_hash);
+
+-- 内容过滤器自动提交的举报没有举报人
+ALTER TABLE content_reports
+    MODIFY COLUMN reporter_id CHAR(36) NULL;
@@ -0,0 +1,24 @@
+-- 文章原文的语言（BCP 47 语言标签，例如 zh、en、zh-TW）
+ALTER TABLE articles
+    ADD COLUMN language VARCHAR(16) NOT NULL DEFAULT 'zh' AFTER content_hash;
+
+-- 文章译文（每篇文章每种语言最多一个译文，同一语言内 slug 唯一）
+CREATE TABLE IF NOT EXISTS article_translations (
+    id CHAR(36) PRIMARY KEY,
+    article_id CHAR(36) NOT NULL,
+    language VARCHAR(16) NOT NULL,
+    title VARCHAR(100) NOT NULL,
+    slug VARCHAR(120) NOT NULL,
+    content TEXT NOT NULL,
+    content_format VARCHAR(20) NOT NULL DEFAULT 'markdown',
+    content_html MEDIUMTEXT NULL,
+    excerpt VARCHAR(255) NULL,
+    word_count INT NULL,
+    reading_minutes INT NULL,
+    is_published BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at DATETIME NOT NULL,
+    updated_at DATETIME NOT NULL,
+    UNIQUE KEY uk_article_language (article_id, language),
+    UNIQUE KEY uk_language_slug (language, slug),
+    FOREIGN KEY (article_id) REFERENCES articles (id) ON DELETE CASCADE
+);
@@ -0,0 +1,8 @@
+-- 编辑置顶的文章：位置从 1 开始，置顶文章在首页精选区和缺省排序的文章列表中排在最前
+-- pinned_until 为空表示长期置顶，过期的置顶视为未置顶，在下一次调整置顶时清除
+ALTER TABLE articles
+    ADD COLUMN pinned_position INT NULL,
+    ADD COLUMN pinned_until DATETIME NULL,
+    ADD COLUMN pinned_at DATETIME NULL,
+    ADD COLUMN pinned_by CHAR(36) NULL,
+    ADD INDEX idx_pinned_position (pinned_position);
@@ -0,0 +1,16 @@
+-- 文章分享链接（带签名的令牌，无需登录即可阅读指定文章，可撤销、会过期）
+CREATE TABLE IF NOT EXISTS article_share_links (
+    id CHAR(36) PRIMARY KEY,
+    article_id CHAR(36) NOT NULL,
+    created_by CHAR(36) NOT NULL,
+    label VARCHAR(100) NULL,
+    expires_at DATETIME NOT NULL,
+    revoked_at DATETIME NULL,
+    created_at DATETIME NOT NULL,
+    -- 最近一次通过该链接阅读的时间和累计使用次数
+    last_used_at DATETIME NULL,
+    use_count INT NOT NULL DEFAULT 0,
+    INDEX idx_article_created (article_id, created_at),
+    FOREIGN KEY (article_id) REFERENCES articles (id) ON DELETE CASCADE,
+    FOREIGN KEY (created_by) REFERENCES users (id) ON DELETE CASCADE
+);
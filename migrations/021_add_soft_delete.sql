@@ -0,0 +1,9 @@
+-- 软删除：删除的文章和用户先进入回收站，保留期过后由后台任务彻底删除
+-- 删除用户时其文章使用同一个 deleted_at 一起进入回收站，恢复用户时一起恢复
+ALTER TABLE articles
+    ADD COLUMN deleted_at DATETIME NULL,
+    ADD INDEX idx_deleted_at (deleted_at);
+
+ALTER TABLE users
+    ADD COLUMN deleted_at DATETIME NULL,
+    ADD INDEX idx_deleted_at (deleted_at);
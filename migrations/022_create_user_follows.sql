@@ -0,0 +1,16 @@
+-- 关注关系（follower 关注 followee）
+CREATE TABLE IF NOT EXISTS user_follows (
+    follower_id CHAR(36) NOT NULL,
+    followee_id CHAR(36) NOT NULL,
+    created_at DATETIME NOT NULL,
+    PRIMARY KEY (follower_id, followee_id),
+    INDEX idx_followee_created (followee_id, created_at),
+    INDEX idx_follower_created (follower_id, created_at),
+    FOREIGN KEY (follower_id) REFERENCES users (id) ON DELETE CASCADE,
+    FOREIGN KEY (followee_id) REFERENCES users (id) ON DELETE CASCADE
+);
+
+-- 首页动态按作者过滤后按 (created_at, id) 倒序取一页：
+-- 关注作者少时逐个作者走该索引，关注作者多时倒序扫描 idx_articles_created_id，
+-- 用 user_follows 主键判断作者是否被关注，两种情况都不需要排序整个结果集
+CREATE INDEX idx_articles_user_created_id ON articles (user_id, created_at, id);
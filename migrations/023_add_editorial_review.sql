@@ -0,0 +1,20 @@
+-- 编辑审核流程：文章的审核状态（未启用审核流程时与 is_public 保持一致）
+ALTER TABLE articles
+    ADD COLUMN review_status VARCHAR(20) NOT NULL DEFAULT 'draft',
+    ADD INDEX idx_review_status_updated (review_status, updated_at);
+
+UPDATE articles SET review_status = 'published' WHERE is_public = TRUE;
+
+-- 审核状态变更记录（只追加，不修改）
+CREATE TABLE IF NOT EXISTS article_review_events (
+    id CHAR(36) PRIMARY KEY,
+    article_id CHAR(36) NOT NULL,
+    actor_id CHAR(36) NOT NULL,
+    from_status VARCHAR(20) NOT NULL,
+    to_status VARCHAR(20) NOT NULL,
+    comment TEXT NULL,
+    created_at DATETIME NOT NULL,
+    INDEX idx_article_created (article_id, created_at),
+    FOREIGN KEY (article_id) REFERENCES articles (id) ON DELETE CASCADE,
+    FOREIGN KEY (actor_id) REFERENCES users (id)
+);
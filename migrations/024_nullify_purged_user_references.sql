@@ -0,0 +1,26 @@
+-- 彻底删除用户时保留其在他人文章下留下的评论、修订、附件、管理操作和审核记录，只清空用户引用
+-- （外键未命名，按 InnoDB 自动生成的 <表名>_ibfk_<序号> 删除后重建）
+ALTER TABLE comments
+    DROP FOREIGN KEY comments_ibfk_2,
+    MODIFY user_id CHAR(36) NULL,
+    ADD CONSTRAINT fk_comments_user FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE SET NULL;
+
+ALTER TABLE article_revisions
+    DROP FOREIGN KEY article_revisions_ibfk_2,
+    MODIFY author_id CHAR(36) NULL,
+    ADD CONSTRAINT fk_article_revisions_author FOREIGN KEY (author_id) REFERENCES users (id) ON DELETE SET NULL;
+
+ALTER TABLE attachments
+    DROP FOREIGN KEY attachments_ibfk_2,
+    MODIFY user_id CHAR(36) NULL,
+    ADD CONSTRAINT fk_attachments_user FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE SET NULL;
+
+ALTER TABLE moderation_actions
+    DROP FOREIGN KEY moderation_actions_ibfk_1,
+    MODIFY moderator_id CHAR(36) NULL,
+    ADD CONSTRAINT fk_moderation_actions_moderator FOREIGN KEY (moderator_id) REFERENCES users (id) ON DELETE SET NULL;
+
+ALTER TABLE article_review_events
+    DROP FOREIGN KEY article_review_events_ibfk_2,
+    MODIFY actor_id CHAR(36) NULL,
+    ADD CONSTRAINT fk_article_review_events_actor FOREIGN KEY (actor_id) REFERENCES users (id) ON DELETE SET NULL;
@@ -0,0 +1,7 @@
+-- 修订记录正文格式，恢复旧修订时一并恢复（历史修订按文章当前格式回填）
+ALTER TABLE article_revisions
+    ADD COLUMN content_format VARCHAR(20) NOT NULL DEFAULT 'markdown' AFTER content;
+
+UPDATE article_revisions r
+    JOIN articles a ON a.id = r.article_id
+    SET r.content_format = a.content_format;
@@ -0,0 +1,6 @@
+-- 回复的 parent_id 外键改为级联删除：彻底删除文章时评论随文章级联删除，
+-- 原外键（RESTRICT）会在父评论先于回复被删除时报错 1451，导致带回复的文章无法清理
+-- （外键未命名，按 InnoDB 自动生成的 <表名>_ibfk_<序号> 删除后重建）
+ALTER TABLE comments
+    DROP FOREIGN KEY comments_ibfk_3,
+    ADD CONSTRAINT fk_comments_parent FOREIGN KEY (parent_id) REFERENCES comments (id) ON DELETE CASCADE;
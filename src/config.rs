@@ -1,5 +1,7 @@
 use serde::Deserialize;
 use std::env;
+use std::net::IpAddr;
+use std::path::PathBuf;
 use std::time::Duration;
 
 /// 应用配置
@@ -8,6 +10,16 @@ pub struct Config {
     pub server: ServerConfig,
     pub database: DatabaseConfig,
     pub jwt: JwtConfig,
+    pub storage: StorageConfig,
+    pub views: ViewConfig,
+    pub feed: FeedConfig,
+    pub sitemap: SitemapConfig,
+    pub pagination: PaginationConfig,
+    pub import: ImportConfig,
+    pub content_filter: ContentFilterConfig,
+    pub share: ShareConfig,
+    pub trash: TrashConfig,
+    pub editorial: EditorialConfig,
 }
 
 /// 服务器配置
@@ -15,6 +27,11 @@ pub struct Config {
 pub struct ServerConfig {
     pub host: String,
     pub port: u16,
+    /// 对外访问地址（不含末尾的 `/`），用于生成订阅源等场景中的绝对链接
+    pub public_url: String,
+    /// 可信反向代理的 IP；只有直连地址在其中时才读取 `X-Forwarded-For` / `X-Real-IP`，
+    /// 为空表示服务不在反向代理之后
+    pub trusted_proxies: Vec<IpAddr>,
 }
 
 /// 数据库配置
@@ -36,18 +53,149 @@ pub struct JwtConfig {
     pub expiration_days: i64,
 }
 
+/// 文件存储配置
+#[derive(Debug, Clone, Deserialize)]
+pub struct StorageConfig {
+    pub backend: StorageBackend,
+    /// 本地存储根目录
+    pub local_root: PathBuf,
+    /// 单个附件最大字节数
+    pub max_file_size: u64,
+    /// 每个用户的附件总配额（字节）
+    pub user_quota: u64,
+}
+
+/// 存储后端
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StorageBackend {
+    /// 本地文件系统
+    Local,
+}
+
+/// 文章浏览量统计配置
+#[derive(Debug, Clone, Deserialize)]
+pub struct ViewConfig {
+    /// 同一访客在该时间窗口内重复访问只计一次
+    pub dedup_window: Duration,
+    /// 浏览量批量写入数据库的间隔
+    pub flush_interval: Duration,
+    /// 去重窗口内最多记录的访客数，超出后新访客的浏览不再计入
+    pub max_tracked_visitors: usize,
+}
+
+/// 订阅源（RSS/Atom）配置
+#[derive(Debug, Clone, Deserialize)]
+pub struct FeedConfig {
+    /// 站点标题
+    pub title: String,
+    /// 站点描述
+    pub description: String,
+    /// 每个订阅源包含的最大文章数
+    pub max_items: u64,
+}
+
+/// 分页配置
+#[derive(Debug, Clone, Deserialize)]
+pub struct PaginationConfig {
+    /// 未指定 `page_size` 时的每页数量
+    pub default_page_size: u64,
+    /// `page_size` 的上限，超过时按上限处理
+    pub max_page_size: u64,
+}
+
+/// 文章批量导入配置
+#[derive(Debug, Clone, Deserialize)]
+pub struct ImportConfig {
+    /// 单个导入文件最大字节数
+    pub max_file_size: u64,
+    /// 单次导入的最大文章数（所有文件合计）
+    pub max_rows: usize,
+}
+
+/// 内容过滤配置（文章和评论写入前检查）
+#[derive(Debug, Clone, Deserialize)]
+pub struct ContentFilterConfig {
+    /// 命中即拒绝的词语
+    pub blocked_words: Vec<String>,
+    /// 命中后交给管理员审核的词语
+    pub flagged_words: Vec<String>,
+    /// 链接数超过该值时交给管理员审核
+    pub flag_links: usize,
+    /// 链接数超过该值时拒绝
+    pub max_links: usize,
+    /// 是否检测重复内容
+    pub detect_duplicates: bool,
+}
+
+/// 文章分享链接配置
+#[derive(Debug, Clone, Deserialize)]
+pub struct ShareConfig {
+    /// 未指定有效期时的分享链接有效期
+    pub default_ttl: Duration,
+    /// 分享链接有效期的上限
+    pub max_ttl: Duration,
+}
+
+/// 回收站配置
+#[derive(Debug, Clone, Deserialize)]
+pub struct TrashConfig {
+    /// 删除的文章和用户在回收站中的保留时间，过后彻底删除
+    pub retention: Duration,
+    /// 后台清理回收站的间隔
+    pub purge_interval: Duration,
+}
+
+/// 编辑审核流程配置
+#[derive(Debug, Clone, Deserialize)]
+pub struct EditorialConfig {
+    /// 是否启用审核流程：启用后文章需作者提交、编辑通过后才能公开
+    pub enabled: bool,
+}
+
+/// 站点地图配置
+#[derive(Debug, Clone, Deserialize)]
+pub struct SitemapConfig {
+    /// 后台重新生成站点地图的间隔
+    pub refresh_interval: Duration,
+}
+
+impl std::str::FromStr for StorageBackend {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "local" => Ok(StorageBackend::Local),
+            other => Err(anyhow::anyhow!("不支持的存储后端: {}", other)),
+        }
+    }
+}
+
 impl Config {
     /// 从环境变量加载配置
     pub fn from_env() -> Result<Self, anyhow::Error> {
         dotenv::dotenv().ok();
 
+        let port = env::var("SERVER_PORT")
+            .unwrap_or_else(|_| "3000".to_string())
+            .parse()
+            .unwrap_or(3000);
+
         Ok(Config {
             server: ServerConfig {
                 host: env::var("SERVER_HOST").unwrap_or_else(|_| "0.0.0.0".to_string()),
-                port: env::var("SERVER_PORT")
-                    .unwrap_or_else(|_| "3000".to_string())
-                    .parse()
-                    .unwrap_or(3000),
+                port,
+                public_url: env::var("PUBLIC_BASE_URL")
+                    .unwrap_or_else(|_| format!("http://localhost:{}", port))
+                    .trim_end_matches('/')
+                    .to_string(),
+                trusted_proxies: word_list("TRUSTED_PROXIES")
+                    .iter()
+                    .map(|ip| {
+                        ip.parse()
+                            .map_err(|_| anyhow::anyhow!("TRUSTED_PROXIES 中的 IP 无效: {}", ip))
+                    })
+                    .collect::<Result<_, _>>()?,
             },
             database: DatabaseConfig {
                 url: env::var("DATABASE_URL")
@@ -70,6 +218,149 @@ impl Config {
                     .parse()
                     .unwrap_or(7),
             },
+            storage: StorageConfig {
+                backend: env::var("STORAGE_BACKEND")
+                    .unwrap_or_else(|_| "local".to_string())
+                    .parse()?,
+                local_root: env::var("STORAGE_LOCAL_ROOT")
+                    .unwrap_or_else(|_| "./uploads".to_string())
+                    .into(),
+                max_file_size: env::var("ATTACHMENT_MAX_SIZE")
+                    .unwrap_or_else(|_| (10 * 1024 * 1024).to_string())
+                    .parse()
+                    .unwrap_or(10 * 1024 * 1024),
+                user_quota: env::var("ATTACHMENT_USER_QUOTA")
+                    .unwrap_or_else(|_| (100 * 1024 * 1024).to_string())
+                    .parse()
+                    .unwrap_or(100 * 1024 * 1024),
+            },
+            views: ViewConfig {
+                dedup_window: Duration::from_secs(
+                    env::var("VIEW_DEDUP_WINDOW_SECS")
+                        .unwrap_or_else(|_| "1800".to_string())
+                        .parse()
+                        .unwrap_or(1800),
+                ),
+                flush_interval: Duration::from_secs(
+                    env::var("VIEW_FLUSH_INTERVAL_SECS")
+                        .unwrap_or_else(|_| "30".to_string())
+                        .parse()
+                        .unwrap_or(30),
+                ),
+                max_tracked_visitors: env::var("VIEW_MAX_TRACKED_VISITORS")
+                    .unwrap_or_else(|_| "100000".to_string())
+                    .parse()
+                    .unwrap_or(100_000),
+            },
+            feed: FeedConfig {
+                title: env::var("FEED_TITLE").unwrap_or_else(|_| "Axum Demo".to_string()),
+                description: env::var("FEED_DESCRIPTION")
+                    .unwrap_or_else(|_| "最新公开文章".to_string()),
+                max_items: env::var("FEED_MAX_ITEMS")
+                    .unwrap_or_else(|_| "50".to_string())
+                    .parse()
+                    .unwrap_or(50),
+            },
+            sitemap: SitemapConfig {
+                refresh_interval: Duration::from_secs(
+                    env::var("SITEMAP_REFRESH_INTERVAL_SECS")
+                        .unwrap_or_else(|_| "3600".to_string())
+                        .parse()
+                        .unwrap_or(3600),
+                ),
+            },
+            pagination: {
+                let max_page_size = env::var("PAGE_SIZE_MAX")
+                    .unwrap_or_else(|_| "100".to_string())
+                    .parse()
+                    .unwrap_or(100)
+                    .max(1);
+                PaginationConfig {
+                    default_page_size: env::var("PAGE_SIZE_DEFAULT")
+                        .unwrap_or_else(|_| "10".to_string())
+                        .parse()
+                        .unwrap_or(10)
+                        .clamp(1, max_page_size),
+                    max_page_size,
+                }
+            },
+            import: ImportConfig {
+                max_file_size: env::var("IMPORT_MAX_FILE_SIZE")
+                    .unwrap_or_else(|_| (10 * 1024 * 1024).to_string())
+                    .parse()
+                    .unwrap_or(10 * 1024 * 1024),
+                max_rows: env::var("IMPORT_MAX_ROWS")
+                    .unwrap_or_else(|_| "1000".to_string())
+                    .parse()
+                    .unwrap_or(1000),
+            },
+            content_filter: ContentFilterConfig {
+                blocked_words: word_list("CONTENT_FILTER_BLOCKED_WORDS"),
+                flagged_words: word_list("CONTENT_FILTER_FLAGGED_WORDS"),
+                flag_links: env::var("CONTENT_FILTER_FLAG_LINKS")
+                    .unwrap_or_else(|_| "5".to_string())
+                    .parse()
+                    .unwrap_or(5),
+                max_links: env::var("CONTENT_FILTER_MAX_LINKS")
+                    .unwrap_or_else(|_| "20".to_string())
+                    .parse()
+                    .unwrap_or(20),
+                detect_duplicates: env::var("CONTENT_FILTER_DETECT_DUPLICATES")
+                    .unwrap_or_else(|_| "true".to_string())
+                    .parse()
+                    .unwrap_or(true),
+            },
+            share: {
+                let max_hours: u64 = env::var("SHARE_LINK_MAX_TTL_HOURS")
+                    .unwrap_or_else(|_| "720".to_string())
+                    .parse()
+                    .unwrap_or(720)
+                    .max(1);
+                ShareConfig {
+                    default_ttl: Duration::from_secs(
+                        env::var("SHARE_LINK_DEFAULT_TTL_HOURS")
+                            .unwrap_or_else(|_| "168".to_string())
+                            .parse()
+                            .unwrap_or(168)
+                            .clamp(1, max_hours)
+                            * 3600,
+                    ),
+                    max_ttl: Duration::from_secs(max_hours * 3600),
+                }
+            },
+            trash: TrashConfig {
+                retention: Duration::from_secs(
+                    env::var("TRASH_RETENTION_DAYS")
+                        .unwrap_or_else(|_| "30".to_string())
+                        .parse()
+                        .unwrap_or(30)
+                        * 24
+                        * 3600,
+                ),
+                purge_interval: Duration::from_secs(
+                    env::var("TRASH_PURGE_INTERVAL_SECS")
+                        .unwrap_or_else(|_| "3600".to_string())
+                        .parse()
+                        .unwrap_or(3600),
+                ),
+            },
+            editorial: EditorialConfig {
+                enabled: env::var("EDITORIAL_WORKFLOW_ENABLED")
+                    .unwrap_or_else(|_| "false".to_string())
+                    .parse()
+                    .unwrap_or(false),
+            },
         })
     }
 }
+
+/// 读取逗号分隔的词表（同时支持中文逗号），忽略空白项
+fn word_list(key: &str) -> Vec<String> {
+    env::var(key)
+        .unwrap_or_default()
+        .split([',', '，'])
+        .map(str::trim)
+        .filter(|word| !word.is_empty())
+        .map(str::to_string)
+        .collect()
+}
@@ -0,0 +1,61 @@
+use super::{content_hash, normalize, ContentFilter, ContentKind, FilterInput, Verdict};
+use crate::errors::Result;
+use crate::repositories::{article_repository, comment_repository};
+use axum::async_trait;
+use sea_orm::DatabaseConnection;
+
+/// 规范化后少于该字符数的内容不做重复检测（"谢谢分享" 这类短评论很常见）
+const MIN_CHARS: usize = 10;
+
+/// 同一作者重复发表相同评论的检测窗口
+const COMMENT_WINDOW_HOURS: i64 = 24;
+
+/// 重复内容过滤器（比较规范化正文的指纹）
+///
+/// - 文章：与同一作者的其他文章相同时拒绝，与其他作者的文章相同时交给管理员审核（可能是搬运）
+/// - 评论：同一作者 24 小时内发表过相同评论时拒绝
+pub struct DuplicateFilter;
+
+#[async_trait]
+impl ContentFilter for DuplicateFilter {
+    fn name(&self) -> &'static str {
+        "duplicate"
+    }
+
+    async fn check(&self, db: &DatabaseConnection, input: &FilterInput<'_>) -> Result<Verdict> {
+        if normalize(input.body).chars().count() < MIN_CHARS {
+            return Ok(Verdict::Accept);
+        }
+        let hash = content_hash(input.body);
+
+        match input.kind {
+            ContentKind::Article => {
+                let authors =
+                    article_repository::find_authors_by_content_hash(db, &hash, input.id).await?;
+                if authors.contains(&Some(input.author_id)) {
+                    Ok(Verdict::Reject("已发布过相同内容的文章".to_string()))
+                } else if !authors.is_empty() {
+                    Ok(Verdict::Flag("与其他作者的文章内容相同".to_string()))
+                } else {
+                    Ok(Verdict::Accept)
+                }
+            }
+            ContentKind::Comment => {
+                let since = chrono::Utc::now() - chrono::Duration::hours(COMMENT_WINDOW_HOURS);
+                if comment_repository::exists_recent_by_content_hash(
+                    db,
+                    input.author_id,
+                    &hash,
+                    since,
+                    input.id,
+                )
+                .await?
+                {
+                    Ok(Verdict::Reject("请勿重复发表相同的评论".to_string()))
+                } else {
+                    Ok(Verdict::Accept)
+                }
+            }
+        }
+    }
+}
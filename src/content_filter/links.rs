@@ -0,0 +1,38 @@
+use super::{ContentFilter, FilterInput, Verdict};
+use crate::errors::Result;
+use axum::async_trait;
+use sea_orm::DatabaseConnection;
+
+/// 链接数过滤器：链接过多时交给管理员审核，超过上限时拒绝
+///
+/// 按 `http://`、`https://` 出现的次数计数，Markdown 链接和 HTML 链接都只计一次
+pub struct LinkLimitFilter {
+    flag_above: usize,
+    reject_above: usize,
+}
+
+impl LinkLimitFilter {
+    pub fn new(flag_above: usize, reject_above: usize) -> Self {
+        Self { flag_above, reject_above }
+    }
+}
+
+#[async_trait]
+impl ContentFilter for LinkLimitFilter {
+    fn name(&self) -> &'static str {
+        "link_limit"
+    }
+
+    async fn check(&self, _db: &DatabaseConnection, input: &FilterInput<'_>) -> Result<Verdict> {
+        let text = input.text().to_ascii_lowercase();
+        let links = text.matches("http://").count() + text.matches("https://").count();
+
+        if links > self.reject_above {
+            Ok(Verdict::Reject(format!("链接数量不能超过 {} 个", self.reject_above)))
+        } else if links > self.flag_above {
+            Ok(Verdict::Flag(format!("包含 {} 个链接", links)))
+        } else {
+            Ok(Verdict::Accept)
+        }
+    }
+}
@@ -0,0 +1,145 @@
+// 内容过滤模块
+//
+// 文章和评论写入前依次经过 `ContentFilter` 管道，每个过滤器给出接受、交给管理员审核或拒绝的结论，
+// 管道取最严重的结论；新增过滤器只需实现该 trait 并在 `create_content_filter` 中注册
+pub mod duplicate;
+pub mod links;
+pub mod word_list;
+
+use crate::config::ContentFilterConfig;
+use crate::errors::Result;
+use axum::async_trait;
+use sea_orm::DatabaseConnection;
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub use duplicate::DuplicateFilter;
+pub use links::LinkLimitFilter;
+pub use word_list::WordListFilter;
+
+/// 被检查的内容类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentKind {
+    Article,
+    Comment,
+}
+
+/// 待检查的内容
+#[derive(Debug, Clone, Copy)]
+pub struct FilterInput<'a> {
+    pub kind: ContentKind,
+    /// 更新时为已有内容的 ID（重复检测时排除自身），创建时为空
+    pub id: Option<Uuid>,
+    pub author_id: Uuid,
+    /// 标题（评论没有标题）
+    pub title: Option<&'a str>,
+    pub body: &'a str,
+}
+
+impl FilterInput<'_> {
+    /// 标题和正文合并后的文本
+    pub fn text(&self) -> String {
+        match self.title {
+            Some(title) => format!("{}\n{}", title, self.body),
+            None => self.body.to_string(),
+        }
+    }
+}
+
+/// 单个过滤器的结论
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Accept,
+    /// 允许写入，但交给管理员审核（附原因）
+    Flag(String),
+    /// 拒绝写入（附返回给用户的原因）
+    Reject(String),
+}
+
+/// 管道的最终结论
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Decision {
+    Accept,
+    /// 允许写入，但交给管理员审核（各过滤器给出的原因，带过滤器名称）
+    Flag(Vec<String>),
+    /// 拒绝写入（第一个拒绝的过滤器给出的原因）
+    Reject(String),
+}
+
+/// 内容过滤器
+#[async_trait]
+pub trait ContentFilter: Send + Sync {
+    /// 过滤器名称（记录在审核原因中）
+    fn name(&self) -> &'static str;
+
+    /// 检查内容
+    async fn check(&self, db: &DatabaseConnection, input: &FilterInput<'_>) -> Result<Verdict>;
+}
+
+/// 内容过滤管道
+///
+/// 按注册顺序执行过滤器，遇到拒绝立即返回，否则汇总所有需要审核的原因
+#[derive(Default)]
+pub struct ContentFilterPipeline {
+    filters: Vec<Box<dyn ContentFilter>>,
+}
+
+impl ContentFilterPipeline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 追加过滤器
+    pub fn with_filter(mut self, filter: impl ContentFilter + 'static) -> Self {
+        self.filters.push(Box::new(filter));
+        self
+    }
+
+    /// 依次执行过滤器
+    pub async fn check(&self, db: &DatabaseConnection, input: &FilterInput<'_>) -> Result<Decision> {
+        let mut flags = Vec::new();
+
+        for filter in &self.filters {
+            match filter.check(db, input).await? {
+                Verdict::Accept => {}
+                Verdict::Flag(reason) => flags.push(format!("{}: {}", filter.name(), reason)),
+                Verdict::Reject(reason) => return Ok(Decision::Reject(reason)),
+            }
+        }
+
+        if flags.is_empty() {
+            Ok(Decision::Accept)
+        } else {
+            Ok(Decision::Flag(flags))
+        }
+    }
+}
+
+/// 根据配置创建内容过滤管道（词表 → 链接数 → 重复内容）
+pub fn create_content_filter(config: &ContentFilterConfig) -> Arc<ContentFilterPipeline> {
+    let mut pipeline = ContentFilterPipeline::new();
+
+    if !config.blocked_words.is_empty() || !config.flagged_words.is_empty() {
+        pipeline = pipeline.with_filter(WordListFilter::new(&config.blocked_words, &config.flagged_words));
+    }
+    pipeline = pipeline.with_filter(LinkLimitFilter::new(config.flag_links, config.max_links));
+    if config.detect_duplicates {
+        pipeline = pipeline.with_filter(DuplicateFilter);
+    }
+
+    Arc::new(pipeline)
+}
+
+/// 规范化文本：转为小写，只保留字母和数字（包括中文等非拉丁文字），去掉空白和标点
+pub fn normalize(text: &str) -> String {
+    text.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// 正文指纹：规范化正文的 SHA-256（十六进制）
+pub fn content_hash(body: &str) -> String {
+    format!("{:x}", Sha256::digest(normalize(body).as_bytes()))
+}
@@ -0,0 +1,99 @@
+use super::{normalize, ContentFilter, FilterInput, Verdict};
+use crate::errors::Result;
+use axum::async_trait;
+use sea_orm::DatabaseConnection;
+use std::collections::HashSet;
+
+/// 词表过滤器：命中拒绝词时拒绝，命中审核词时交给管理员审核
+///
+/// - 只由 ASCII 字母数字组成的词语按整词匹配（忽略大小写），避免 "class" 命中 "ass"
+/// - 其他词语（中文、含空格的短语等）规范化后按子串匹配，
+///   "赌 博"、"赌-博" 同样会命中 "赌博"
+pub struct WordListFilter {
+    blocked: WordList,
+    flagged: WordList,
+}
+
+impl WordListFilter {
+    pub fn new(blocked: &[String], flagged: &[String]) -> Self {
+        Self {
+            blocked: WordList::new(blocked),
+            flagged: WordList::new(flagged),
+        }
+    }
+}
+
+#[async_trait]
+impl ContentFilter for WordListFilter {
+    fn name(&self) -> &'static str {
+        "word_list"
+    }
+
+    async fn check(&self, _db: &DatabaseConnection, input: &FilterInput<'_>) -> Result<Verdict> {
+        let text = input.text();
+        let tokens: HashSet<String> = text
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|token| !token.is_empty())
+            .map(str::to_ascii_lowercase)
+            .collect();
+        let normalized = normalize(&text);
+
+        if let Some(word) = self.blocked.find(&tokens, &normalized) {
+            return Ok(Verdict::Reject(format!("内容包含违禁词「{}」", word)));
+        }
+        if let Some(word) = self.flagged.find(&tokens, &normalized) {
+            return Ok(Verdict::Flag(format!("命中审核词「{}」", word)));
+        }
+        Ok(Verdict::Accept)
+    }
+}
+
+/// 预处理后的词表
+struct WordList {
+    entries: Vec<WordEntry>,
+}
+
+struct WordEntry {
+    /// 配置中的原始词语（命中时返回）
+    word: String,
+    /// 匹配用的形式：整词匹配时为小写，子串匹配时为规范化后的文本
+    pattern: String,
+    whole_word: bool,
+}
+
+impl WordList {
+    fn new(words: &[String]) -> Self {
+        let entries = words
+            .iter()
+            .filter_map(|word| {
+                let whole_word = word.chars().all(|c| c.is_ascii_alphanumeric());
+                let pattern = if whole_word {
+                    word.to_ascii_lowercase()
+                } else {
+                    normalize(word)
+                };
+                (!pattern.is_empty()).then(|| WordEntry {
+                    word: word.clone(),
+                    pattern,
+                    whole_word,
+                })
+            })
+            .collect();
+
+        Self { entries }
+    }
+
+    /// 返回第一个命中的词语
+    fn find(&self, tokens: &HashSet<String>, normalized: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|entry| {
+                if entry.whole_word {
+                    tokens.contains(&entry.pattern)
+                } else {
+                    normalized.contains(&entry.pattern)
+                }
+            })
+            .map(|entry| entry.word.as_str())
+    }
+}
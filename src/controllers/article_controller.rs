@@ -1,24 +1,43 @@
 use crate::errors::Result;
-use crate::extractors::{AuthUser, OptionalAuthUser, Pagination};
-use crate::models::{ArticleResponse, CreateArticleRequest};
-use crate::response::ApiResponse;
-use crate::services::{article_service, PagedResult};
+use crate::extractors::{
+    AuthUser, CursorQuery, IfMatch, IfNoneMatch, ListQuery, OptionalAuthUser, Pagination,
+    PaginationLinks, PreferredLanguages,
+};
+use crate::models::{
+    ArticleField, ArticleListQuery, ArticleResponse, AssignCategoryRequest, BodyFormatQuery, CreateArticleRequest,
+    PinArticleRequest, ShareQuery, TrendingArticleResponse, TrendingQuery,
+};
+use crate::response::{ApiResponse, ETag};
+use crate::services::{article_service, share_service, view_service, ListPage, PagedResult};
+use crate::view_counter::visitor_key;
 use crate::AppState;
 use axum::{
-    extract::{Path, Query, State},
+    extract::{ConnectInfo, Path, Query, State},
+    http::{header, HeaderMap},
+    response::{IntoResponse, Response},
     Json,
 };
+use std::net::SocketAddr;
 use uuid::Uuid;
 
 /// 获取文章列表（示例：使用 From<Result> trait）
 pub async fn list_articles_simple(
     State(state): State<AppState>,
-    Query(pagination): Query<Pagination>,
+    pagination: Pagination,
     optional_user: OptionalAuthUser,
 ) -> ApiResponse<Vec<ArticleResponse>> {
     let user_id = optional_user.user_id();
     let result: Result<Vec<ArticleResponse>> = async {
-        let articles = article_service::list_articles(&state.db, pagination, user_id).await?;
+        let articles = article_service::list_articles(
+            &state.db,
+            &pagination,
+            ArticleListQuery::default(),
+            ListQuery::default(),
+            Default::default(),
+            &Default::default(),
+            user_id,
+        )
+        .await?;
         Ok(articles.list)
     }
     .await;
@@ -27,41 +46,202 @@ pub async fn list_articles_simple(
 }
 
 /// 获取文章列表（推荐方式：使用 ? 运算符）
+///
+/// 带 `after` 或 `limit` 参数时使用游标分页，否则使用页码分页；
+/// 标题和摘要按 `?lang=` 或 `Accept-Language` 选择译文
+#[allow(clippy::too_many_arguments)]
 pub async fn list_articles(
     State(state): State<AppState>,
-    Query(pagination): Query<Pagination>,
+    pagination: Pagination,
+    CursorQuery(cursor): CursorQuery,
+    Query(query): Query<ArticleListQuery>,
+    list_query: ListQuery<ArticleField>,
+    Query(body_format): Query<BodyFormatQuery>,
+    languages: PreferredLanguages,
     optional_user: OptionalAuthUser,
-) -> Result<ApiResponse<PagedResult<Vec<ArticleResponse>>>> {
-    let result =
-        article_service::list_articles(&state.db, pagination, optional_user.user_id()).await?;
-
-    Ok(ApiResponse::success(PagedResult {
-        list: result.list,
-        pagination: result.pagination,
-    }))
+) -> Result<(
+    PaginationLinks,
+    [(header::HeaderName, &'static str); 1],
+    ApiResponse<ListPage<Vec<ArticleResponse>>>,
+)> {
+    if let Some(cursor) = cursor {
+        let result = article_service::list_articles_after_cursor(
+            &state.db,
+            &cursor,
+            query,
+            list_query,
+            body_format,
+            &languages,
+            optional_user.user_id(),
+        )
+        .await?;
+        let links = cursor.links(result.cursor.next_cursor.as_deref());
+        return Ok((links, vary_language(), ApiResponse::success(ListPage::Cursor(result))));
+    }
+
+    let result = article_service::list_articles(
+        &state.db,
+        &pagination,
+        query,
+        list_query,
+        body_format,
+        &languages,
+        optional_user.user_id(),
+    )
+    .await?;
+
+    Ok((
+        pagination.links(result.pagination.total),
+        vary_language(),
+        ApiResponse::success(ListPage::Paged(PagedResult {
+            list: result.list,
+            pagination: result.pagination,
+        })),
+    ))
 }
 
 /// 根据 ID 获取文章（简单方式）
 pub async fn get_article_simple(
     State(state): State<AppState>,
     Path(article_id): Path<Uuid>,
-    _optional_user: OptionalAuthUser,
+    optional_user: OptionalAuthUser,
 ) -> ApiResponse<ArticleResponse> {
-    let result: Result<ArticleResponse> =
-        async { article_service::get_article_by_id(&state.db, article_id).await }.await;
+    let user_id = optional_user.user_id();
+    let result: Result<ArticleResponse> = async {
+        article_service::get_article_by_id(
+            &state.db,
+            article_id,
+            Default::default(),
+            &Default::default(),
+            user_id,
+        )
+        .await
+    }
+    .await;
 
     result.into()
 }
 
 /// 根据 ID 获取文章（推荐方式）
+///
+/// 成功读取后记录一次浏览（同一访客在去重窗口内只计一次，批量异步写入）；
+/// 响应带 `ETag`（文章版本号，可直接作为更新时的 `If-Match`），`If-None-Match` 命中当前版本时返回 304；
+/// 按 `?lang=` 或 `Accept-Language` 返回译文，响应带 `Vary: Accept-Language`；
+/// 带 `?share=<token>` 时凭分享令牌读取该文章（无需登录，私有文章同样可读）
+#[allow(clippy::too_many_arguments)]
 pub async fn get_article(
     State(state): State<AppState>,
     Path(article_id): Path<Uuid>,
-    _optional_user: OptionalAuthUser,
+    Query(body_format): Query<BodyFormatQuery>,
+    Query(share): Query<ShareQuery>,
+    languages: PreferredLanguages,
+    optional_user: OptionalAuthUser,
+    if_none_match: IfNoneMatch,
+    headers: HeaderMap,
+    connect_info: Option<ConnectInfo<SocketAddr>>,
+) -> Result<Response> {
+    let user_id = optional_user.user_id();
+    let article = match share.share.as_deref() {
+        Some(token) => {
+            share_service::get_shared_article(
+                &state.db,
+                &state.config,
+                article_id,
+                token,
+                body_format.format,
+                &languages,
+            )
+            .await?
+        }
+        None => {
+            article_service::get_article_by_id(
+                &state.db,
+                article_id,
+                body_format.format,
+                &languages,
+                user_id,
+            )
+            .await?
+        }
+    };
+
+    let visitor = visitor_key(
+        user_id,
+        &headers,
+        connect_info.map(|ConnectInfo(addr)| addr),
+        &state.config.server.trusted_proxies,
+    );
+    state.view_counter.record(article_id, visitor);
+
+    let etag = ETag(article.version);
+    if if_none_match.matches(article.version) {
+        return Ok((vary_language(), etag.not_modified()).into_response());
+    }
+
+    Ok((etag, vary_language(), ApiResponse::success(article)).into_response())
+}
+
+/// 响应内容随请求语言变化，缓存需要区分 `Accept-Language`
+pub(crate) fn vary_language() -> [(header::HeaderName, &'static str); 1] {
+    [(header::VARY, "Accept-Language")]
+}
+
+/// 获取热门文章（可选认证，仅公开文章）
+pub async fn trending_articles(
+    State(state): State<AppState>,
+    Query(query): Query<TrendingQuery>,
+    optional_user: OptionalAuthUser,
+) -> Result<ApiResponse<Vec<TrendingArticleResponse>>> {
+    let articles =
+        view_service::trending_articles(&state.db, query, optional_user.user_id()).await?;
+
+    Ok(ApiResponse::success(articles))
+}
+
+/// 获取精选文章（可选认证，置顶有效的公开文章，按置顶位置排序）
+pub async fn featured_articles(
+    State(state): State<AppState>,
+    languages: PreferredLanguages,
+    optional_user: OptionalAuthUser,
+) -> Result<([(header::HeaderName, &'static str); 1], ApiResponse<Vec<ArticleResponse>>)> {
+    let articles =
+        article_service::featured_articles(&state.db, &languages, optional_user.user_id()).await?;
+
+    Ok((vary_language(), ApiResponse::success(articles)))
+}
+
+/// 删除文章（需要认证，作者或管理员，放入回收站）
+pub async fn delete_article(
+    State(state): State<AppState>,
+    Path(article_id): Path<Uuid>,
+    auth_user: AuthUser,
+) -> Result<ApiResponse<()>> {
+    article_service::delete_article(&state.db, article_id, &auth_user).await?;
+
+    Ok(ApiResponse::success_with_message((), "文章已移入回收站"))
+}
+
+/// 置顶文章（需要认证，编辑或管理员）
+pub async fn pin_article(
+    State(state): State<AppState>,
+    Path(article_id): Path<Uuid>,
+    auth_user: AuthUser,
+    Json(payload): Json<PinArticleRequest>,
 ) -> Result<ApiResponse<ArticleResponse>> {
-    let article = article_service::get_article_by_id(&state.db, article_id).await?;
+    let article = article_service::pin_article(&state.db, article_id, &auth_user, payload).await?;
+
+    Ok(ApiResponse::success_with_message(article, "置顶成功"))
+}
+
+/// 取消置顶（需要认证，编辑或管理员）
+pub async fn unpin_article(
+    State(state): State<AppState>,
+    Path(article_id): Path<Uuid>,
+    auth_user: AuthUser,
+) -> Result<ApiResponse<()>> {
+    article_service::unpin_article(&state.db, article_id, &auth_user).await?;
 
-    Ok(ApiResponse::success(article))
+    Ok(ApiResponse::success_with_message((), "已取消置顶"))
 }
 
 /// 创建文章（需要认证）
@@ -70,7 +250,52 @@ pub async fn create_article(
     auth_user: AuthUser, // 直接使用 AuthUser，更清晰
     Json(payload): Json<CreateArticleRequest>,
 ) -> Result<ApiResponse<ArticleResponse>> {
-    let article = article_service::create_article(&state.db, auth_user.user_id, payload).await?;
+    let article = article_service::create_article(
+        &state.db,
+        &state.content_filter,
+        &state.config.editorial,
+        auth_user.user_id,
+        payload,
+    )
+    .await?;
 
     Ok(ApiResponse::success_with_message(article, "文章创建成功"))
 }
+
+/// 更新文章（需要认证，作者或管理员；每次更新都会记录修订）
+///
+/// 必须携带 `If-Match`（缺少时返回 428，版本不一致时返回 412）
+pub async fn update_article(
+    State(state): State<AppState>,
+    Path(article_id): Path<Uuid>,
+    auth_user: AuthUser,
+    if_match: IfMatch,
+    Json(payload): Json<CreateArticleRequest>,
+) -> Result<(ETag, ApiResponse<ArticleResponse>)> {
+    let article = article_service::update_article(
+        &state.db,
+        &state.content_filter,
+        &state.config.editorial,
+        article_id,
+        &auth_user,
+        payload,
+        &if_match,
+    )
+    .await?;
+
+    Ok((ETag(article.version), ApiResponse::success_with_message(article, "文章更新成功")))
+}
+
+/// 设置文章分类（需要认证，作者本人或管理员）
+pub async fn assign_article_category(
+    State(state): State<AppState>,
+    Path(article_id): Path<Uuid>,
+    auth_user: AuthUser,
+    Json(payload): Json<AssignCategoryRequest>,
+) -> Result<ApiResponse<ArticleResponse>> {
+    let article =
+        article_service::assign_category(&state.db, article_id, &auth_user, payload.category_id)
+            .await?;
+
+    Ok(ApiResponse::success_with_message(article, "分类设置成功"))
+}
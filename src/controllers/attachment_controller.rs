@@ -0,0 +1,200 @@
+use crate::errors::{AppError, Result};
+use crate::extractors::{AuthUser, IfNoneMatch, OptionalAuthUser};
+use crate::models::AttachmentResponse;
+use crate::response::ApiResponse;
+use crate::services::attachment_service;
+use crate::AppState;
+use axum::{
+    body::{Body, Bytes},
+    extract::{Multipart, Path, Request, State},
+    http::{header, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+};
+use tower::ServiceExt;
+use tower_http::services::ServeFile;
+use uuid::Uuid;
+
+/// 上传附件（需要认证，文章作者或管理员）
+///
+/// 请求体为 `multipart/form-data`，文件放在名为 `file` 的字段中
+pub async fn upload_attachment(
+    State(state): State<AppState>,
+    Path(article_id): Path<Uuid>,
+    auth_user: AuthUser,
+    mut multipart: Multipart,
+) -> Result<ApiResponse<AttachmentResponse>> {
+    let max_size = state.config.storage.max_file_size;
+
+    while let Some(mut field) = multipart.next_field().await.map_err(multipart_error)? {
+        if field.name() != Some("file") {
+            continue;
+        }
+        let filename = field.file_name().map(str::to_string);
+
+        // 边读边检查大小，超限立即拒绝而不是先读完整个文件
+        let mut data = Vec::new();
+        while let Some(chunk) = field.chunk().await.map_err(multipart_error)? {
+            if (data.len() + chunk.len()) as u64 > max_size {
+                return Err(AppError::PayloadTooLarge(format!(
+                    "单个附件不能超过 {} 字节",
+                    max_size
+                )));
+            }
+            data.extend_from_slice(&chunk);
+        }
+
+        let attachment = attachment_service::upload_attachment(
+            &state.db,
+            state.storage.as_ref(),
+            &state.config.storage,
+            article_id,
+            &auth_user,
+            filename.as_deref(),
+            Bytes::from(data),
+        )
+        .await?;
+
+        return Ok(ApiResponse::success_with_message(attachment, "上传成功"));
+    }
+
+    Err(AppError::Validation("缺少 file 字段".to_string()))
+}
+
+/// 获取文章附件列表（可选认证，遵循文章可见性）
+pub async fn list_attachments(
+    State(state): State<AppState>,
+    Path(article_id): Path<Uuid>,
+    optional_user: OptionalAuthUser,
+) -> Result<ApiResponse<Vec<AttachmentResponse>>> {
+    let attachments =
+        attachment_service::list_attachments(&state.db, article_id, optional_user.user_id())
+            .await?;
+
+    Ok(ApiResponse::success(attachments))
+}
+
+/// 删除附件（需要认证，上传者、文章作者或管理员）
+pub async fn delete_attachment(
+    State(state): State<AppState>,
+    Path((article_id, attachment_id)): Path<(Uuid, Uuid)>,
+    auth_user: AuthUser,
+) -> Result<ApiResponse<()>> {
+    attachment_service::delete_attachment(
+        &state.db,
+        state.storage.as_ref(),
+        article_id,
+        attachment_id,
+        &auth_user,
+    )
+    .await?;
+
+    Ok(ApiResponse::success_with_message((), "附件已删除"))
+}
+
+/// 下载附件（可选认证，私有文章的附件只有作者可以下载）
+///
+/// 附件内容不可变，因此使用附件 ID 作为强 ETag；文章之后可能被设为私有、隐藏或删除，
+/// 所以公开附件也要求缓存每次使用前重新验证（`no-cache`），验证时同样检查可见性；
+/// 本地存储交给 `ServeFile` 处理，自动支持 Range 和 If-Modified-Since
+pub async fn download_attachment(
+    State(state): State<AppState>,
+    Path(attachment_id): Path<Uuid>,
+    optional_user: OptionalAuthUser,
+    if_none_match: IfNoneMatch,
+    request: Request,
+) -> Result<Response> {
+    let (attachment, is_public) =
+        attachment_service::find_downloadable(&state.db, attachment_id, optional_user.user_id())
+            .await?;
+
+    let etag = format!("\"{}\"", attachment.id);
+    let cache_control = if is_public {
+        "public, no-cache"
+    } else {
+        "private, no-cache"
+    };
+
+    let not_modified = if_none_match.matches_tag(&attachment.id.to_string());
+
+    let mut response = if not_modified {
+        StatusCode::NOT_MODIFIED.into_response()
+    } else if let Some(path) = state.storage.local_path(&attachment.storage_key) {
+        let response = ServeFile::new(path)
+            .oneshot(request)
+            .await
+            .map_err(|e| AppError::Internal(anyhow::anyhow!("读取附件失败: {}", e)))?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Err(AppError::NotFound);
+        }
+        response.map(Body::new)
+    } else {
+        let data = state.storage.get(&attachment.storage_key).await?;
+        Body::from(data).into_response()
+    };
+
+    let headers = response.headers_mut();
+    headers.insert(header::ETAG, header_value(&etag)?);
+    headers.insert(header::CACHE_CONTROL, HeaderValue::from_static(cache_control));
+    headers.insert(
+        header::X_CONTENT_TYPE_OPTIONS,
+        HeaderValue::from_static("nosniff"),
+    );
+    if !not_modified {
+        headers.insert(header::CONTENT_TYPE, header_value(&attachment.content_type)?);
+        headers.insert(
+            header::CONTENT_DISPOSITION,
+            header_value(&content_disposition(&attachment.content_type, &attachment.filename))?,
+        );
+    }
+
+    Ok(response)
+}
+
+pub(crate) fn multipart_error(e: axum::extract::multipart::MultipartError) -> AppError {
+    if e.status() == StatusCode::PAYLOAD_TOO_LARGE {
+        AppError::PayloadTooLarge(e.body_text())
+    } else {
+        AppError::Validation(e.body_text())
+    }
+}
+
+fn header_value(value: &str) -> Result<HeaderValue> {
+    HeaderValue::from_str(value)
+        .map_err(|e| AppError::Internal(anyhow::anyhow!("非法的响应头: {}", e)))
+}
+
+/// 生成 Content-Disposition：图片内联展示，其他类型作为下载
+///
+/// 同时提供 ASCII 的 `filename` 和 RFC 5987 编码的 `filename*`，兼容中文文件名
+fn content_disposition(content_type: &str, filename: &str) -> String {
+    let disposition = if content_type.starts_with("image/") {
+        "inline"
+    } else {
+        "attachment"
+    };
+    let ascii: String = filename
+        .chars()
+        .map(|c| {
+            if (c.is_ascii_graphic() && c != '"' && c != '\\') || c == ' ' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    let encoded: String = filename
+        .bytes()
+        .map(|b| {
+            if b.is_ascii_alphanumeric() || b"!#$&+-.^_`|~".contains(&b) {
+                (b as char).to_string()
+            } else {
+                format!("%{:02X}", b)
+            }
+        })
+        .collect();
+
+    format!(
+        "{}; filename=\"{}\"; filename*=UTF-8''{}",
+        disposition, ascii, encoded
+    )
+}
@@ -0,0 +1,70 @@
+use crate::errors::Result;
+use crate::extractors::AuthUser;
+use crate::models::{CategoryResponse, CategoryTreeNode, CreateCategoryRequest, UpdateCategoryRequest};
+use crate::response::ApiResponse;
+use crate::services::category_service;
+use crate::AppState;
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use uuid::Uuid;
+
+/// 获取分类树
+pub async fn list_categories(
+    State(state): State<AppState>,
+) -> Result<ApiResponse<Vec<CategoryTreeNode>>> {
+    let tree = category_service::list_category_tree(&state.db).await?;
+
+    Ok(ApiResponse::success(tree))
+}
+
+/// 根据 ID 获取分类（包含子分类）
+pub async fn get_category(
+    State(state): State<AppState>,
+    Path(category_id): Path<Uuid>,
+) -> Result<ApiResponse<CategoryTreeNode>> {
+    let category = category_service::get_category(&state.db, category_id).await?;
+
+    Ok(ApiResponse::success(category))
+}
+
+/// 创建分类（仅管理员）
+pub async fn create_category(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Json(payload): Json<CreateCategoryRequest>,
+) -> Result<ApiResponse<CategoryResponse>> {
+    auth_user.require_admin()?;
+
+    let category = category_service::create_category(&state.db, payload).await?;
+
+    Ok(ApiResponse::success_with_message(category, "分类创建成功"))
+}
+
+/// 更新分类（仅管理员）
+pub async fn update_category(
+    State(state): State<AppState>,
+    Path(category_id): Path<Uuid>,
+    auth_user: AuthUser,
+    Json(payload): Json<UpdateCategoryRequest>,
+) -> Result<ApiResponse<CategoryResponse>> {
+    auth_user.require_admin()?;
+
+    let category = category_service::update_category(&state.db, category_id, payload).await?;
+
+    Ok(ApiResponse::success_with_message(category, "分类更新成功"))
+}
+
+/// 删除分类（仅管理员）
+pub async fn delete_category(
+    State(state): State<AppState>,
+    Path(category_id): Path<Uuid>,
+    auth_user: AuthUser,
+) -> Result<ApiResponse<()>> {
+    auth_user.require_admin()?;
+
+    category_service::delete_category(&state.db, category_id).await?;
+
+    Ok(ApiResponse::success_with_message((), "分类已删除"))
+}
@@ -0,0 +1,118 @@
+use crate::errors::Result;
+use crate::extractors::{AuthUser, OptionalAuthUser, Pagination, PaginationLinks};
+use crate::models::{
+    ArticleResponse, CollectionResponse, CreateCollectionRequest, UpdateCollectionRequest,
+};
+use crate::response::ApiResponse;
+use crate::services::{collection_service, PagedResult};
+use crate::AppState;
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use uuid::Uuid;
+
+/// 获取用户的收藏夹列表（可选认证，本人可见私有收藏夹）
+pub async fn list_user_collections(
+    State(state): State<AppState>,
+    Path(user_id): Path<Uuid>,
+    optional_user: OptionalAuthUser,
+) -> Result<ApiResponse<Vec<CollectionResponse>>> {
+    let collections =
+        collection_service::list_user_collections(&state.db, user_id, optional_user.user_id())
+            .await?;
+
+    Ok(ApiResponse::success(collections))
+}
+
+/// 获取收藏夹（可选认证，私有收藏夹只有创建者可见）
+pub async fn get_collection(
+    State(state): State<AppState>,
+    Path(collection_id): Path<Uuid>,
+    optional_user: OptionalAuthUser,
+) -> Result<ApiResponse<CollectionResponse>> {
+    let collection =
+        collection_service::get_collection(&state.db, collection_id, optional_user.user_id())
+            .await?;
+
+    Ok(ApiResponse::success(collection))
+}
+
+/// 获取收藏夹中的文章（可选认证，分页）
+pub async fn list_collection_articles(
+    State(state): State<AppState>,
+    Path(collection_id): Path<Uuid>,
+    pagination: Pagination,
+    optional_user: OptionalAuthUser,
+) -> Result<(PaginationLinks, ApiResponse<PagedResult<Vec<ArticleResponse>>>)> {
+    let result = collection_service::list_collection_articles(
+        &state.db,
+        collection_id,
+        optional_user.user_id(),
+        &pagination,
+    )
+    .await?;
+
+    Ok((pagination.links(result.pagination.total), ApiResponse::success(result)))
+}
+
+/// 创建收藏夹（需要认证）
+pub async fn create_collection(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Json(payload): Json<CreateCollectionRequest>,
+) -> Result<ApiResponse<CollectionResponse>> {
+    let collection =
+        collection_service::create_collection(&state.db, auth_user.user_id, payload).await?;
+
+    Ok(ApiResponse::success_with_message(collection, "收藏夹创建成功"))
+}
+
+/// 更新收藏夹（需要认证，仅创建者）
+pub async fn update_collection(
+    State(state): State<AppState>,
+    Path(collection_id): Path<Uuid>,
+    auth_user: AuthUser,
+    Json(payload): Json<UpdateCollectionRequest>,
+) -> Result<ApiResponse<CollectionResponse>> {
+    let collection =
+        collection_service::update_collection(&state.db, collection_id, &auth_user, payload)
+            .await?;
+
+    Ok(ApiResponse::success_with_message(collection, "收藏夹更新成功"))
+}
+
+/// 删除收藏夹（需要认证，创建者或管理员）
+pub async fn delete_collection(
+    State(state): State<AppState>,
+    Path(collection_id): Path<Uuid>,
+    auth_user: AuthUser,
+) -> Result<ApiResponse<()>> {
+    collection_service::delete_collection(&state.db, collection_id, &auth_user).await?;
+
+    Ok(ApiResponse::success_with_message((), "收藏夹已删除"))
+}
+
+/// 把文章加入收藏夹（需要认证，仅创建者，幂等）
+pub async fn add_collection_article(
+    State(state): State<AppState>,
+    Path((collection_id, article_id)): Path<(Uuid, Uuid)>,
+    auth_user: AuthUser,
+) -> Result<ApiResponse<()>> {
+    collection_service::add_collection_article(&state.db, collection_id, article_id, &auth_user)
+        .await?;
+
+    Ok(ApiResponse::success_with_message((), "已加入收藏夹"))
+}
+
+/// 把文章移出收藏夹（需要认证，仅创建者，幂等）
+pub async fn remove_collection_article(
+    State(state): State<AppState>,
+    Path((collection_id, article_id)): Path<(Uuid, Uuid)>,
+    auth_user: AuthUser,
+) -> Result<ApiResponse<()>> {
+    collection_service::remove_collection_article(&state.db, collection_id, article_id, &auth_user)
+        .await?;
+
+    Ok(ApiResponse::success_with_message((), "已移出收藏夹"))
+}
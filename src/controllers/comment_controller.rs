@@ -0,0 +1,73 @@
+use crate::errors::Result;
+use crate::extractors::{AuthUser, OptionalAuthUser};
+use crate::models::{CommentResponse, CreateCommentRequest, UpdateCommentRequest};
+use crate::response::ApiResponse;
+use crate::services::comment_service;
+use crate::AppState;
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use uuid::Uuid;
+
+/// 获取文章评论树（可选认证，私有文章仅作者可见）
+pub async fn list_comments(
+    State(state): State<AppState>,
+    Path(article_id): Path<Uuid>,
+    optional_user: OptionalAuthUser,
+) -> Result<ApiResponse<Vec<CommentResponse>>> {
+    let comments =
+        comment_service::list_comments(&state.db, article_id, optional_user.user_id()).await?;
+
+    Ok(ApiResponse::success(comments))
+}
+
+/// 发表评论（需要认证）
+pub async fn create_comment(
+    State(state): State<AppState>,
+    Path(article_id): Path<Uuid>,
+    auth_user: AuthUser,
+    Json(payload): Json<CreateCommentRequest>,
+) -> Result<ApiResponse<CommentResponse>> {
+    let comment = comment_service::create_comment(
+        &state.db,
+        &state.content_filter,
+        article_id,
+        auth_user.user_id,
+        payload,
+    )
+    .await?;
+
+    Ok(ApiResponse::success_with_message(comment, "评论成功"))
+}
+
+/// 编辑评论（需要认证，仅评论作者）
+pub async fn update_comment(
+    State(state): State<AppState>,
+    Path((article_id, comment_id)): Path<(Uuid, Uuid)>,
+    auth_user: AuthUser,
+    Json(payload): Json<UpdateCommentRequest>,
+) -> Result<ApiResponse<CommentResponse>> {
+    let comment = comment_service::update_comment(
+        &state.db,
+        &state.content_filter,
+        article_id,
+        comment_id,
+        auth_user.user_id,
+        payload,
+    )
+    .await?;
+
+    Ok(ApiResponse::success_with_message(comment, "评论已更新"))
+}
+
+/// 删除评论（需要认证，评论作者或管理员）
+pub async fn delete_comment(
+    State(state): State<AppState>,
+    Path((article_id, comment_id)): Path<(Uuid, Uuid)>,
+    auth_user: AuthUser,
+) -> Result<ApiResponse<()>> {
+    comment_service::delete_comment(&state.db, article_id, comment_id, &auth_user).await?;
+
+    Ok(ApiResponse::success_with_message((), "评论已删除"))
+}
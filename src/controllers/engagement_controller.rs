@@ -0,0 +1,69 @@
+use crate::errors::Result;
+use crate::extractors::{AuthUser, Pagination, PaginationLinks};
+use crate::models::{ArticleResponse, SetReactionRequest};
+use crate::response::ApiResponse;
+use crate::services::{engagement_service, PagedResult};
+use crate::AppState;
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use uuid::Uuid;
+
+/// 设置表情反应（需要认证，幂等）
+pub async fn set_reaction(
+    State(state): State<AppState>,
+    Path(article_id): Path<Uuid>,
+    auth_user: AuthUser,
+    Json(payload): Json<SetReactionRequest>,
+) -> Result<ApiResponse<()>> {
+    engagement_service::set_reaction(&state.db, article_id, auth_user.user_id, payload.reaction)
+        .await?;
+
+    Ok(ApiResponse::success_with_message((), "已设置反应"))
+}
+
+/// 取消表情反应（需要认证，幂等）
+pub async fn remove_reaction(
+    State(state): State<AppState>,
+    Path(article_id): Path<Uuid>,
+    auth_user: AuthUser,
+) -> Result<ApiResponse<()>> {
+    engagement_service::remove_reaction(&state.db, article_id, auth_user.user_id).await?;
+
+    Ok(ApiResponse::success_with_message((), "已取消反应"))
+}
+
+/// 收藏文章（需要认证，幂等）
+pub async fn add_bookmark(
+    State(state): State<AppState>,
+    Path(article_id): Path<Uuid>,
+    auth_user: AuthUser,
+) -> Result<ApiResponse<()>> {
+    engagement_service::add_bookmark(&state.db, article_id, auth_user.user_id).await?;
+
+    Ok(ApiResponse::success_with_message((), "收藏成功"))
+}
+
+/// 取消收藏（需要认证，幂等）
+pub async fn remove_bookmark(
+    State(state): State<AppState>,
+    Path(article_id): Path<Uuid>,
+    auth_user: AuthUser,
+) -> Result<ApiResponse<()>> {
+    engagement_service::remove_bookmark(&state.db, article_id, auth_user.user_id).await?;
+
+    Ok(ApiResponse::success_with_message((), "已取消收藏"))
+}
+
+/// 获取当前用户的收藏列表（需要认证，带分页）
+pub async fn list_my_bookmarks(
+    State(state): State<AppState>,
+    pagination: Pagination,
+    auth_user: AuthUser,
+) -> Result<(PaginationLinks, ApiResponse<PagedResult<Vec<ArticleResponse>>>)> {
+    let result =
+        engagement_service::list_bookmarks(&state.db, auth_user.user_id, &pagination).await?;
+
+    Ok((pagination.links(result.pagination.total), ApiResponse::success(result)))
+}
@@ -0,0 +1,144 @@
+use crate::errors::{AppError, Result};
+use crate::extractors::IfNoneMatch;
+use crate::services::feed_service::{self, FeedFormat, FeedScope};
+use crate::AppState;
+use axum::{
+    extract::{Path, State},
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+
+/// 全站 RSS 订阅源
+pub async fn articles_rss(
+    State(state): State<AppState>,
+    if_none_match: IfNoneMatch,
+    headers: HeaderMap,
+) -> Result<Response> {
+    serve_feed(&state, &if_none_match, &headers, FeedScope::All, FeedFormat::Rss).await
+}
+
+/// 全站 Atom 订阅源
+pub async fn articles_atom(
+    State(state): State<AppState>,
+    if_none_match: IfNoneMatch,
+    headers: HeaderMap,
+) -> Result<Response> {
+    serve_feed(&state, &if_none_match, &headers, FeedScope::All, FeedFormat::Atom).await
+}
+
+/// 作者 RSS 订阅源
+pub async fn author_rss(
+    State(state): State<AppState>,
+    Path(username): Path<String>,
+    if_none_match: IfNoneMatch,
+    headers: HeaderMap,
+) -> Result<Response> {
+    serve_feed(&state, &if_none_match, &headers, FeedScope::Author(username), FeedFormat::Rss).await
+}
+
+/// 作者 Atom 订阅源
+pub async fn author_atom(
+    State(state): State<AppState>,
+    Path(username): Path<String>,
+    if_none_match: IfNoneMatch,
+    headers: HeaderMap,
+) -> Result<Response> {
+    serve_feed(&state, &if_none_match, &headers, FeedScope::Author(username), FeedFormat::Atom).await
+}
+
+/// 分类 RSS 订阅源（包含子孙分类）
+pub async fn category_rss(
+    State(state): State<AppState>,
+    Path(slug): Path<String>,
+    if_none_match: IfNoneMatch,
+    headers: HeaderMap,
+) -> Result<Response> {
+    serve_feed(&state, &if_none_match, &headers, FeedScope::Category(slug), FeedFormat::Rss).await
+}
+
+/// 分类 Atom 订阅源（包含子孙分类）
+pub async fn category_atom(
+    State(state): State<AppState>,
+    Path(slug): Path<String>,
+    if_none_match: IfNoneMatch,
+    headers: HeaderMap,
+) -> Result<Response> {
+    serve_feed(&state, &if_none_match, &headers, FeedScope::Category(slug), FeedFormat::Atom).await
+}
+
+/// 生成订阅源响应
+///
+/// 带 `ETag` 和 `Last-Modified`，订阅器携带 `If-None-Match` / `If-Modified-Since`
+/// 轮询时，内容未变化则返回 304，不渲染也不传输正文
+async fn serve_feed(
+    state: &AppState,
+    if_none_match: &IfNoneMatch,
+    headers: &HeaderMap,
+    scope: FeedScope,
+    format: FeedFormat,
+) -> Result<Response> {
+    let feed = feed_service::build_feed(
+        &state.db,
+        &state.config.server,
+        &state.config.feed,
+        scope,
+        format,
+    )
+    .await?;
+
+    let mut response = if is_not_modified(if_none_match, headers, &feed.etag, feed.updated) {
+        StatusCode::NOT_MODIFIED.into_response()
+    } else {
+        let mut response = feed_service::render_feed(&feed).into_response();
+        response.headers_mut().insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static(format.content_type()),
+        );
+        response
+    };
+
+    let response_headers = response.headers_mut();
+    response_headers.insert(header::ETAG, header_value(&format!("W/\"{}\"", feed.etag))?);
+    if let Some(updated) = feed.updated {
+        response_headers.insert(header::LAST_MODIFIED, header_value(&http_date(updated))?);
+    }
+    response_headers.insert(
+        header::CACHE_CONTROL,
+        HeaderValue::from_static("public, max-age=300"),
+    );
+
+    Ok(response)
+}
+
+/// 判断条件请求是否命中（`If-None-Match` 优先于 `If-Modified-Since`）
+fn is_not_modified(
+    if_none_match: &IfNoneMatch,
+    headers: &HeaderMap,
+    etag: &str,
+    updated: Option<DateTime<Utc>>,
+) -> bool {
+    if if_none_match.is_present() {
+        return if_none_match.matches_tag(etag);
+    }
+
+    let if_modified_since = headers
+        .get(header::IF_MODIFIED_SINCE)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| DateTime::parse_from_rfc2822(v).ok());
+    match (if_modified_since, updated) {
+        // HTTP 日期精确到秒
+        (Some(since), Some(updated)) => updated.timestamp() <= since.timestamp(),
+        _ => false,
+    }
+}
+
+/// 格式化为 HTTP 日期（RFC 7231 IMF-fixdate）
+fn http_date(time: DateTime<Utc>) -> String {
+    time.format("%a, %d %b %Y %H:%M:%S GMT").to_string()
+}
+
+fn header_value(value: &str) -> Result<HeaderValue> {
+    HeaderValue::from_str(value)
+        .map_err(|e| AppError::Internal(anyhow::anyhow!("非法的响应头: {}", e)))
+}
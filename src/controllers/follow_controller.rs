@@ -0,0 +1,76 @@
+use crate::errors::Result;
+use crate::extractors::{AuthUser, CursorPagination, Pagination, PaginationLinks, PreferredLanguages};
+use crate::models::{ArticleResponse, UserResponse};
+use crate::response::ApiResponse;
+use crate::services::{follow_service, CursorPagedResult, PagedResult};
+use crate::controllers::article_controller::vary_language;
+use crate::AppState;
+use axum::{
+    extract::{Path, State},
+    http::header,
+};
+use uuid::Uuid;
+
+/// 关注用户（需要认证，幂等）
+pub async fn follow_user(
+    State(state): State<AppState>,
+    Path(user_id): Path<Uuid>,
+    auth_user: AuthUser,
+) -> Result<ApiResponse<()>> {
+    follow_service::follow_user(&state.db, auth_user.user_id, user_id).await?;
+
+    Ok(ApiResponse::success_with_message((), "关注成功"))
+}
+
+/// 取消关注（需要认证，幂等）
+pub async fn unfollow_user(
+    State(state): State<AppState>,
+    Path(user_id): Path<Uuid>,
+    auth_user: AuthUser,
+) -> Result<ApiResponse<()>> {
+    follow_service::unfollow_user(&state.db, auth_user.user_id, user_id).await?;
+
+    Ok(ApiResponse::success_with_message((), "已取消关注"))
+}
+
+/// 获取用户的关注者列表（不需要认证，带分页）
+pub async fn list_followers(
+    State(state): State<AppState>,
+    Path(user_id): Path<Uuid>,
+    pagination: Pagination,
+) -> Result<(PaginationLinks, ApiResponse<PagedResult<Vec<UserResponse>>>)> {
+    let result = follow_service::list_followers(&state.db, user_id, &pagination).await?;
+
+    Ok((pagination.links(result.pagination.total), ApiResponse::success(result)))
+}
+
+/// 获取用户关注的人（不需要认证，带分页）
+pub async fn list_following(
+    State(state): State<AppState>,
+    Path(user_id): Path<Uuid>,
+    pagination: Pagination,
+) -> Result<(PaginationLinks, ApiResponse<PagedResult<Vec<UserResponse>>>)> {
+    let result = follow_service::list_following(&state.db, user_id, &pagination).await?;
+
+    Ok((pagination.links(result.pagination.total), ApiResponse::success(result)))
+}
+
+/// 获取首页动态（需要认证，游标分页）
+///
+/// 标题和摘要按 `?lang=` 或 `Accept-Language` 选择译文
+pub async fn home_feed(
+    State(state): State<AppState>,
+    pagination: CursorPagination,
+    languages: PreferredLanguages,
+    auth_user: AuthUser,
+) -> Result<(
+    PaginationLinks,
+    [(header::HeaderName, &'static str); 1],
+    ApiResponse<CursorPagedResult<Vec<ArticleResponse>>>,
+)> {
+    let result =
+        follow_service::home_feed(&state.db, auth_user.user_id, &pagination, &languages).await?;
+    let links = pagination.links(result.cursor.next_cursor.as_deref());
+
+    Ok((links, vary_language(), ApiResponse::success(result)))
+}
@@ -2,10 +2,44 @@
 pub mod auth_controller;
 pub mod user_controller;
 pub mod article_controller;
+pub mod category_controller;
+pub mod comment_controller;
+pub mod engagement_controller;
+pub mod search_controller;
+pub mod revision_controller;
+pub mod attachment_controller;
+pub mod feed_controller;
+pub mod sitemap_controller;
+pub mod transfer_controller;
+pub mod series_controller;
+pub mod collection_controller;
+pub mod moderation_controller;
+pub mod translation_controller;
+pub mod share_controller;
+pub mod trash_controller;
+pub mod follow_controller;
+pub mod review_controller;
 pub mod health_controller;
 
 pub use auth_controller::*;
 pub use user_controller::*;
 pub use article_controller::*;
+pub use category_controller::*;
+pub use comment_controller::*;
+pub use engagement_controller::*;
+pub use search_controller::*;
+pub use revision_controller::*;
+pub use attachment_controller::*;
+pub use feed_controller::*;
+pub use sitemap_controller::*;
+pub use transfer_controller::*;
+pub use series_controller::*;
+pub use collection_controller::*;
+pub use moderation_controller::*;
+pub use translation_controller::*;
+pub use share_controller::*;
+pub use trash_controller::*;
+pub use follow_controller::*;
+pub use review_controller::*;
 pub use health_controller::*;
 
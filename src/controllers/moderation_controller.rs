@@ -0,0 +1,77 @@
+use crate::errors::Result;
+use crate::extractors::{AuthUser, Pagination, PaginationLinks};
+use crate::models::{
+    CreateReportRequest, ModerateContentRequest, ModerationActionQuery, ModerationActionResponse,
+    ModerationQueueItem,
+};
+use crate::response::ApiResponse;
+use crate::services::{moderation_service, PagedResult};
+use crate::AppState;
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use uuid::Uuid;
+
+/// 举报文章（需要认证，幂等）
+pub async fn report_article(
+    State(state): State<AppState>,
+    Path(article_id): Path<Uuid>,
+    auth_user: AuthUser,
+    Json(payload): Json<CreateReportRequest>,
+) -> Result<ApiResponse<()>> {
+    moderation_service::report_article(&state.db, article_id, &auth_user, payload).await?;
+
+    Ok(ApiResponse::success_with_message((), "举报已提交"))
+}
+
+/// 获取待处理队列（仅管理员，带分页）
+pub async fn moderation_queue(
+    State(state): State<AppState>,
+    pagination: Pagination,
+    auth_user: AuthUser,
+) -> Result<(PaginationLinks, ApiResponse<PagedResult<Vec<ModerationQueueItem>>>)> {
+    let result = moderation_service::moderation_queue(&state.db, &auth_user, &pagination).await?;
+
+    Ok((pagination.links(result.pagination.total), ApiResponse::success(result)))
+}
+
+/// 对文章执行管理操作（仅管理员）
+pub async fn moderate_article(
+    State(state): State<AppState>,
+    Path(article_id): Path<Uuid>,
+    auth_user: AuthUser,
+    Json(payload): Json<ModerateContentRequest>,
+) -> Result<ApiResponse<ModerationActionResponse>> {
+    let action =
+        moderation_service::moderate_article(&state.db, article_id, &auth_user, payload).await?;
+
+    Ok(ApiResponse::success_with_message(action, "操作成功"))
+}
+
+/// 对评论执行管理操作（仅管理员）
+pub async fn moderate_comment(
+    State(state): State<AppState>,
+    Path(comment_id): Path<Uuid>,
+    auth_user: AuthUser,
+    Json(payload): Json<ModerateContentRequest>,
+) -> Result<ApiResponse<ModerationActionResponse>> {
+    let action =
+        moderation_service::moderate_comment(&state.db, comment_id, &auth_user, payload).await?;
+
+    Ok(ApiResponse::success_with_message(action, "操作成功"))
+}
+
+/// 获取管理操作记录（仅管理员，带分页）
+pub async fn list_moderation_actions(
+    State(state): State<AppState>,
+    Query(query): Query<ModerationActionQuery>,
+    pagination: Pagination,
+    auth_user: AuthUser,
+) -> Result<(PaginationLinks, ApiResponse<PagedResult<Vec<ModerationActionResponse>>>)> {
+    let result =
+        moderation_service::list_moderation_actions(&state.db, &auth_user, query, &pagination)
+            .await?;
+
+    Ok((pagination.links(result.pagination.total), ApiResponse::success(result)))
+}
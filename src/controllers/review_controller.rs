@@ -0,0 +1,113 @@
+use crate::errors::Result;
+use crate::extractors::{AuthUser, Pagination, PaginationLinks};
+use crate::models::{ArticleResponse, ArticleReviewResponse, ReviewActionRequest, ReviewQueueQuery};
+use crate::response::{ApiResponse, ETag};
+use crate::services::{review_service, PagedResult};
+use crate::AppState;
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use uuid::Uuid;
+
+/// 获取文章的审核详情和变更记录（需要认证，作者、编辑或管理员）
+pub async fn get_article_review(
+    State(state): State<AppState>,
+    Path(article_id): Path<Uuid>,
+    auth_user: AuthUser,
+) -> Result<ApiResponse<ArticleReviewResponse>> {
+    let review =
+        review_service::get_article_review(&state.db, &state.config.editorial, article_id, &auth_user)
+            .await?;
+
+    Ok(ApiResponse::success(review))
+}
+
+/// 提交审核（需要认证，作者或管理员）
+pub async fn submit_article(
+    State(state): State<AppState>,
+    Path(article_id): Path<Uuid>,
+    auth_user: AuthUser,
+    Json(payload): Json<ReviewActionRequest>,
+) -> Result<(ETag, ApiResponse<ArticleResponse>)> {
+    let article = review_service::submit_article(
+        &state.db,
+        &state.config.editorial,
+        article_id,
+        &auth_user,
+        payload,
+    )
+    .await?;
+
+    Ok((ETag(article.version), ApiResponse::success_with_message(article, "已提交审核")))
+}
+
+/// 审核通过（需要认证，编辑或管理员）
+pub async fn approve_article(
+    State(state): State<AppState>,
+    Path(article_id): Path<Uuid>,
+    auth_user: AuthUser,
+    Json(payload): Json<ReviewActionRequest>,
+) -> Result<(ETag, ApiResponse<ArticleResponse>)> {
+    let article = review_service::approve_article(
+        &state.db,
+        &state.config.editorial,
+        article_id,
+        &auth_user,
+        payload,
+    )
+    .await?;
+
+    Ok((ETag(article.version), ApiResponse::success_with_message(article, "审核已通过")))
+}
+
+/// 退回修改（需要认证，编辑或管理员，必须填写 `comment`）
+pub async fn request_changes(
+    State(state): State<AppState>,
+    Path(article_id): Path<Uuid>,
+    auth_user: AuthUser,
+    Json(payload): Json<ReviewActionRequest>,
+) -> Result<(ETag, ApiResponse<ArticleResponse>)> {
+    let article = review_service::request_changes(
+        &state.db,
+        &state.config.editorial,
+        article_id,
+        &auth_user,
+        payload,
+    )
+    .await?;
+
+    Ok((ETag(article.version), ApiResponse::success_with_message(article, "已退回修改")))
+}
+
+/// 发布已通过审核的文章（需要认证，作者、编辑或管理员）
+pub async fn publish_article(
+    State(state): State<AppState>,
+    Path(article_id): Path<Uuid>,
+    auth_user: AuthUser,
+) -> Result<(ETag, ApiResponse<ArticleResponse>)> {
+    let article =
+        review_service::publish_article(&state.db, &state.config.editorial, article_id, &auth_user)
+            .await?;
+
+    Ok((ETag(article.version), ApiResponse::success_with_message(article, "文章已发布")))
+}
+
+/// 获取审核队列（需要认证，编辑或管理员，带分页）
+pub async fn list_review_queue(
+    State(state): State<AppState>,
+    pagination: Pagination,
+    Query(query): Query<ReviewQueueQuery>,
+    auth_user: AuthUser,
+) -> Result<(PaginationLinks, ApiResponse<PagedResult<Vec<ArticleResponse>>>)> {
+    let result = review_service::list_review_queue(
+        &state.db,
+        &state.config.editorial,
+        &auth_user,
+        query.status,
+        &pagination,
+    )
+    .await?;
+
+    Ok((pagination.links(result.pagination.total), ApiResponse::success(result)))
+}
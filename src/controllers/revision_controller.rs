@@ -0,0 +1,75 @@
+use crate::errors::Result;
+use crate::extractors::{AuthUser, IfMatch};
+use crate::models::{
+    ArticleResponse, RevisionDiffQuery, RevisionDiffResponse, RevisionResponse, RevisionSummary,
+};
+use crate::response::{ApiResponse, ETag};
+use crate::services::revision_service;
+use crate::AppState;
+use axum::extract::{Path, Query, State};
+use uuid::Uuid;
+
+/// 获取文章修订列表（需要认证，作者或管理员）
+pub async fn list_revisions(
+    State(state): State<AppState>,
+    Path(article_id): Path<Uuid>,
+    auth_user: AuthUser,
+) -> Result<ApiResponse<Vec<RevisionSummary>>> {
+    let revisions = revision_service::list_revisions(&state.db, article_id, &auth_user).await?;
+
+    Ok(ApiResponse::success(revisions))
+}
+
+/// 获取指定修订（需要认证，作者或管理员）
+pub async fn get_revision(
+    State(state): State<AppState>,
+    Path((article_id, revision_number)): Path<(Uuid, i32)>,
+    auth_user: AuthUser,
+) -> Result<ApiResponse<RevisionResponse>> {
+    let revision =
+        revision_service::get_revision(&state.db, article_id, revision_number, &auth_user).await?;
+
+    Ok(ApiResponse::success(revision))
+}
+
+/// 对比两个修订（需要认证，作者或管理员）
+pub async fn diff_revisions(
+    State(state): State<AppState>,
+    Path(article_id): Path<Uuid>,
+    Query(query): Query<RevisionDiffQuery>,
+    auth_user: AuthUser,
+) -> Result<ApiResponse<RevisionDiffResponse>> {
+    let diff = revision_service::diff_revisions(
+        &state.db,
+        article_id,
+        query.from,
+        query.to,
+        &auth_user,
+    )
+    .await?;
+
+    Ok(ApiResponse::success(diff))
+}
+
+/// 恢复到指定修订（需要认证，作者或管理员）
+///
+/// 必须携带 `If-Match`（缺少时返回 428，版本不一致时返回 412）
+pub async fn restore_revision(
+    State(state): State<AppState>,
+    Path((article_id, revision_number)): Path<(Uuid, i32)>,
+    auth_user: AuthUser,
+    if_match: IfMatch,
+) -> Result<(ETag, ApiResponse<ArticleResponse>)> {
+    let article = revision_service::restore_revision(
+        &state.db,
+        &state.content_filter,
+        &state.config.editorial,
+        article_id,
+        revision_number,
+        &auth_user,
+        &if_match,
+    )
+    .await?;
+
+    Ok((ETag(article.version), ApiResponse::success_with_message(article, "已恢复到指定修订")))
+}
@@ -0,0 +1,25 @@
+use crate::errors::Result;
+use crate::extractors::{OptionalAuthUser, Pagination, PaginationLinks};
+use crate::models::{ArticleSearchHit, SearchQuery};
+use crate::response::ApiResponse;
+use crate::services::{search_service, PagedResult};
+use crate::AppState;
+use axum::extract::{Query, State};
+
+/// 全文搜索文章（可选认证，私有文章只对作者可见）
+pub async fn search_articles(
+    State(state): State<AppState>,
+    Query(query): Query<SearchQuery>,
+    pagination: Pagination,
+    optional_user: OptionalAuthUser,
+) -> Result<(PaginationLinks, ApiResponse<PagedResult<Vec<ArticleSearchHit>>>)> {
+    let result = search_service::search_articles(
+        &state.db,
+        &query.q,
+        &pagination,
+        optional_user.user_id(),
+    )
+    .await?;
+
+    Ok((pagination.links(result.pagination.total), ApiResponse::success(result)))
+}
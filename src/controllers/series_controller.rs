@@ -0,0 +1,95 @@
+use crate::errors::Result;
+use crate::extractors::{AuthUser, OptionalAuthUser};
+use crate::models::{
+    ArticleResponse, AssignSeriesRequest, CreateSeriesRequest, ReorderSeriesRequest,
+    SeriesDetailResponse, SeriesResponse, UpdateSeriesRequest,
+};
+use crate::response::ApiResponse;
+use crate::services::series_service;
+use crate::AppState;
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use uuid::Uuid;
+
+/// 获取作者的系列列表
+pub async fn list_user_series(
+    State(state): State<AppState>,
+    Path(user_id): Path<Uuid>,
+) -> Result<ApiResponse<Vec<SeriesResponse>>> {
+    let series = series_service::list_user_series(&state.db, user_id).await?;
+
+    Ok(ApiResponse::success(series))
+}
+
+/// 获取系列详情（可选认证，只列出当前用户可读的文章）
+pub async fn get_series(
+    State(state): State<AppState>,
+    Path(series_id): Path<Uuid>,
+    optional_user: OptionalAuthUser,
+) -> Result<ApiResponse<SeriesDetailResponse>> {
+    let series =
+        series_service::get_series(&state.db, series_id, optional_user.user_id()).await?;
+
+    Ok(ApiResponse::success(series))
+}
+
+/// 创建系列（需要认证）
+pub async fn create_series(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Json(payload): Json<CreateSeriesRequest>,
+) -> Result<ApiResponse<SeriesResponse>> {
+    let series = series_service::create_series(&state.db, auth_user.user_id, payload).await?;
+
+    Ok(ApiResponse::success_with_message(series, "系列创建成功"))
+}
+
+/// 更新系列（需要认证，作者本人或管理员）
+pub async fn update_series(
+    State(state): State<AppState>,
+    Path(series_id): Path<Uuid>,
+    auth_user: AuthUser,
+    Json(payload): Json<UpdateSeriesRequest>,
+) -> Result<ApiResponse<SeriesResponse>> {
+    let series = series_service::update_series(&state.db, series_id, &auth_user, payload).await?;
+
+    Ok(ApiResponse::success_with_message(series, "系列更新成功"))
+}
+
+/// 删除系列（需要认证，作者本人或管理员）
+pub async fn delete_series(
+    State(state): State<AppState>,
+    Path(series_id): Path<Uuid>,
+    auth_user: AuthUser,
+) -> Result<ApiResponse<()>> {
+    series_service::delete_series(&state.db, series_id, &auth_user).await?;
+
+    Ok(ApiResponse::success_with_message((), "系列已删除"))
+}
+
+/// 调整系列中文章的顺序（需要认证，作者本人或管理员）
+pub async fn reorder_series(
+    State(state): State<AppState>,
+    Path(series_id): Path<Uuid>,
+    auth_user: AuthUser,
+    Json(payload): Json<ReorderSeriesRequest>,
+) -> Result<ApiResponse<SeriesDetailResponse>> {
+    let series = series_service::reorder_series(&state.db, series_id, &auth_user, payload).await?;
+
+    Ok(ApiResponse::success_with_message(series, "顺序已更新"))
+}
+
+/// 设置文章所属系列（需要认证，作者本人或管理员）
+pub async fn assign_article_series(
+    State(state): State<AppState>,
+    Path(article_id): Path<Uuid>,
+    auth_user: AuthUser,
+    Json(payload): Json<AssignSeriesRequest>,
+) -> Result<ApiResponse<ArticleResponse>> {
+    let article =
+        series_service::assign_article_series(&state.db, article_id, &auth_user, payload).await?;
+
+    Ok(ApiResponse::success_with_message(article, "系列设置成功"))
+}
@@ -0,0 +1,47 @@
+use crate::errors::Result;
+use crate::extractors::AuthUser;
+use crate::models::{CreateShareLinkRequest, ShareLinkResponse};
+use crate::response::ApiResponse;
+use crate::services::share_service;
+use crate::AppState;
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use uuid::Uuid;
+
+/// 创建分享链接（需要认证，作者或管理员）
+pub async fn create_share_link(
+    State(state): State<AppState>,
+    Path(article_id): Path<Uuid>,
+    auth_user: AuthUser,
+    Json(payload): Json<CreateShareLinkRequest>,
+) -> Result<ApiResponse<ShareLinkResponse>> {
+    let link =
+        share_service::create_share_link(&state.db, &state.config, article_id, &auth_user, payload)
+            .await?;
+
+    Ok(ApiResponse::success_with_message(link, "分享链接已创建"))
+}
+
+/// 获取文章的分享链接列表（需要认证，作者或管理员）
+pub async fn list_share_links(
+    State(state): State<AppState>,
+    Path(article_id): Path<Uuid>,
+    auth_user: AuthUser,
+) -> Result<ApiResponse<Vec<ShareLinkResponse>>> {
+    let links = share_service::list_share_links(&state.db, article_id, &auth_user).await?;
+
+    Ok(ApiResponse::success(links))
+}
+
+/// 撤销分享链接（需要认证，作者或管理员，幂等）
+pub async fn revoke_share_link(
+    State(state): State<AppState>,
+    Path((article_id, share_id)): Path<(Uuid, Uuid)>,
+    auth_user: AuthUser,
+) -> Result<ApiResponse<()>> {
+    share_service::revoke_share_link(&state.db, article_id, share_id, &auth_user).await?;
+
+    Ok(ApiResponse::success_with_message((), "分享链接已撤销"))
+}
@@ -0,0 +1,56 @@
+use crate::errors::{AppError, Result};
+use crate::AppState;
+use axum::{
+    extract::{Path, State},
+    http::{header, HeaderValue},
+    response::{IntoResponse, Response},
+};
+
+/// 站点地图（URL 较多时为站点地图索引）
+pub async fn sitemap(State(state): State<AppState>) -> Result<Response> {
+    let sitemap = state.sitemap.get(&state.db).await?;
+
+    xml_response(sitemap.root().to_string(), sitemap.generated_at)
+}
+
+/// 站点地图分片（`sitemap-N.xml`，仅在 URL 超过单文件上限时存在）
+pub async fn sitemap_page(
+    State(state): State<AppState>,
+    Path(file): Path<String>,
+) -> Result<Response> {
+    let number: usize = file
+        .strip_prefix("sitemap-")
+        .and_then(|rest| rest.strip_suffix(".xml"))
+        .and_then(|n| n.parse().ok())
+        .ok_or(AppError::NotFound)?;
+
+    let sitemap = state.sitemap.get(&state.db).await?;
+    if sitemap.index.is_none() {
+        return Err(AppError::NotFound);
+    }
+    let page = sitemap.page(number).ok_or(AppError::NotFound)?;
+
+    xml_response(page.to_string(), sitemap.generated_at)
+}
+
+fn xml_response(body: String, generated_at: chrono::DateTime<chrono::Utc>) -> Result<Response> {
+    let last_modified = generated_at.format("%a, %d %b %Y %H:%M:%S GMT").to_string();
+
+    let mut response = body.into_response();
+    let headers = response.headers_mut();
+    headers.insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static("application/xml; charset=utf-8"),
+    );
+    headers.insert(
+        header::LAST_MODIFIED,
+        HeaderValue::from_str(&last_modified)
+            .map_err(|e| AppError::Internal(anyhow::anyhow!("非法的响应头: {}", e)))?,
+    );
+    headers.insert(
+        header::CACHE_CONTROL,
+        HeaderValue::from_static("public, max-age=3600"),
+    );
+
+    Ok(response)
+}
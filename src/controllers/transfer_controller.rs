@@ -0,0 +1,109 @@
+use crate::controllers::attachment_controller::multipart_error;
+use crate::errors::{AppError, Result};
+use crate::extractors::AuthUser;
+use crate::models::{ExportQuery, ImportQuery, ImportReport, TransferFormat};
+use crate::response::ApiResponse;
+use crate::services::{transfer_service, ImportFile};
+use crate::AppState;
+use axum::{
+    body::Body,
+    extract::{Multipart, Query, State},
+    http::{header, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+};
+
+/// 批量导入文章（需要认证，导入的文章归属当前用户）
+///
+/// 请求体为 `multipart/form-data`，可包含多个名为 `file` 的字段；
+/// 格式由 `?format=` 指定，缺省时按文件扩展名推断（`.jsonl`、`.csv`、`.md`）。
+/// `?dry_run=true` 时只校验不写入；存在任何错误时不写入并返回 422 和逐行错误
+pub async fn import_articles(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Query(query): Query<ImportQuery>,
+    mut multipart: Multipart,
+) -> Result<ApiResponse<ImportReport>> {
+    let max_size = state.config.import.max_file_size;
+
+    let mut files = Vec::new();
+    while let Some(mut field) = multipart.next_field().await.map_err(multipart_error)? {
+        if field.name() != Some("file") {
+            continue;
+        }
+        let filename = field.file_name().map(str::to_string);
+        let format = query
+            .format
+            .or_else(|| filename.as_deref().and_then(TransferFormat::from_filename))
+            .ok_or_else(|| {
+                AppError::Validation(format!(
+                    "无法识别文件 {} 的格式，请使用 format 参数指定（jsonl、csv、markdown）",
+                    filename.as_deref().unwrap_or("")
+                ))
+            })?;
+
+        // 边读边检查大小，超限立即拒绝
+        let mut data = Vec::new();
+        while let Some(chunk) = field.chunk().await.map_err(multipart_error)? {
+            if (data.len() + chunk.len()) as u64 > max_size {
+                return Err(AppError::PayloadTooLarge(format!(
+                    "单个导入文件不能超过 {} 字节",
+                    max_size
+                )));
+            }
+            data.extend_from_slice(&chunk);
+        }
+        let content = String::from_utf8(data).map_err(|_| {
+            AppError::Validation(format!(
+                "文件 {} 不是有效的 UTF-8 文本",
+                filename.as_deref().unwrap_or("")
+            ))
+        })?;
+
+        files.push(ImportFile { filename, format, content });
+    }
+    if files.is_empty() {
+        return Err(AppError::Validation("缺少 file 字段".to_string()));
+    }
+
+    let report = transfer_service::import_articles(
+        &state.db,
+        &state.config.import,
+        &state.content_filter,
+        &state.config.editorial,
+        &auth_user,
+        files,
+        query.dry_run,
+    )
+    .await?;
+
+    Ok(if !report.errors.is_empty() {
+        ApiResponse::error_with_data(StatusCode::UNPROCESSABLE_ENTITY, "导入数据校验失败", report)
+    } else if report.dry_run {
+        ApiResponse::success_with_message(report, "校验通过")
+    } else {
+        ApiResponse::success_with_message(report, "导入成功")
+    })
+}
+
+/// 流式导出文章（需要认证，管理员导出全部文章，其他用户导出自己的文章）
+pub async fn export_articles(
+    State(state): State<AppState>,
+    auth_user: AuthUser,
+    Query(query): Query<ExportQuery>,
+) -> Response {
+    let format = query.format;
+    let stream = transfer_service::export_articles(state.db.clone(), &auth_user, format);
+    let disposition = format!(
+        "attachment; filename=\"articles-{}.{}\"",
+        chrono::Utc::now().format("%Y%m%d%H%M%S"),
+        format.extension()
+    );
+
+    let mut response = Body::from_stream(stream).into_response();
+    let headers = response.headers_mut();
+    headers.insert(header::CONTENT_TYPE, HeaderValue::from_static(format.content_type()));
+    if let Ok(value) = HeaderValue::from_str(&disposition) {
+        headers.insert(header::CONTENT_DISPOSITION, value);
+    }
+    response
+}
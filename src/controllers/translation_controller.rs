@@ -0,0 +1,56 @@
+use crate::errors::Result;
+use crate::extractors::{AuthUser, OptionalAuthUser};
+use crate::models::{TranslationResponse, UpsertTranslationRequest};
+use crate::response::ApiResponse;
+use crate::services::translation_service;
+use crate::AppState;
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use uuid::Uuid;
+
+/// 获取文章的译文列表（可选认证，作者和管理员可以看到未发布的译文）
+pub async fn list_translations(
+    State(state): State<AppState>,
+    Path(article_id): Path<Uuid>,
+    optional_user: OptionalAuthUser,
+) -> Result<ApiResponse<Vec<TranslationResponse>>> {
+    let translations =
+        translation_service::list_translations(&state.db, article_id, optional_user.user())
+            .await?;
+
+    Ok(ApiResponse::success(translations))
+}
+
+/// 创建或更新译文（需要认证，作者或管理员）
+pub async fn upsert_translation(
+    State(state): State<AppState>,
+    Path((article_id, language)): Path<(Uuid, String)>,
+    auth_user: AuthUser,
+    Json(payload): Json<UpsertTranslationRequest>,
+) -> Result<ApiResponse<TranslationResponse>> {
+    let translation = translation_service::upsert_translation(
+        &state.db,
+        &state.content_filter,
+        &state.config.editorial,
+        article_id,
+        &language,
+        &auth_user,
+        payload,
+    )
+    .await?;
+
+    Ok(ApiResponse::success_with_message(translation, "译文已保存"))
+}
+
+/// 删除译文（需要认证，作者或管理员）
+pub async fn delete_translation(
+    State(state): State<AppState>,
+    Path((article_id, language)): Path<(Uuid, String)>,
+    auth_user: AuthUser,
+) -> Result<ApiResponse<()>> {
+    translation_service::delete_translation(&state.db, article_id, &language, &auth_user).await?;
+
+    Ok(ApiResponse::success_with_message((), "译文已删除"))
+}
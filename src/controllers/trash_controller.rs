@@ -0,0 +1,64 @@
+use crate::errors::Result;
+use crate::extractors::{AuthUser, Pagination, PaginationLinks};
+use crate::models::{ArticleResponse, TrashedArticle, TrashedUser, UserResponse};
+use crate::response::ApiResponse;
+use crate::services::{trash_service, PagedResult};
+use crate::AppState;
+use axum::extract::{Path, State};
+use uuid::Uuid;
+
+/// 获取回收站中的文章（需要认证，管理员查看全部，其他用户查看自己的）
+pub async fn list_trashed_articles(
+    State(state): State<AppState>,
+    pagination: Pagination,
+    auth_user: AuthUser,
+) -> Result<(PaginationLinks, ApiResponse<PagedResult<Vec<TrashedArticle>>>)> {
+    let result = trash_service::list_trashed_articles(
+        &state.db,
+        state.config.trash.retention,
+        &auth_user,
+        &pagination,
+    )
+    .await?;
+
+    Ok((pagination.links(result.pagination.total), ApiResponse::success(result)))
+}
+
+/// 从回收站恢复文章（需要认证，作者或管理员）
+pub async fn restore_article(
+    State(state): State<AppState>,
+    Path(article_id): Path<Uuid>,
+    auth_user: AuthUser,
+) -> Result<ApiResponse<ArticleResponse>> {
+    let article = trash_service::restore_article(&state.db, article_id, &auth_user).await?;
+
+    Ok(ApiResponse::success_with_message(article, "文章已恢复"))
+}
+
+/// 获取回收站中的用户（仅管理员）
+pub async fn list_trashed_users(
+    State(state): State<AppState>,
+    pagination: Pagination,
+    auth_user: AuthUser,
+) -> Result<(PaginationLinks, ApiResponse<PagedResult<Vec<TrashedUser>>>)> {
+    let result = trash_service::list_trashed_users(
+        &state.db,
+        state.config.trash.retention,
+        &auth_user,
+        &pagination,
+    )
+    .await?;
+
+    Ok((pagination.links(result.pagination.total), ApiResponse::success(result)))
+}
+
+/// 从回收站恢复用户（仅管理员）
+pub async fn restore_user(
+    State(state): State<AppState>,
+    Path(user_id): Path<Uuid>,
+    auth_user: AuthUser,
+) -> Result<ApiResponse<UserResponse>> {
+    let user = trash_service::restore_user(&state.db, user_id, &auth_user).await?;
+
+    Ok(ApiResponse::success_with_message(user, "用户已恢复"))
+}
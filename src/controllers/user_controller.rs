@@ -1,53 +1,76 @@
 use crate::errors::{AppError, Result};
-use crate::extractors::{AuthUser, Pagination};
-use crate::models::UpdateUserRequest;
-use crate::response::ApiResponse;
-use crate::services::user_service;
+use crate::extractors::{
+    AuthUser, CursorQuery, IfMatch, IfNoneMatch, ListQuery, Pagination, PaginationLinks,
+};
+use crate::models::{UpdateUserRequest, UserField, UserResponse};
+use crate::response::{ApiResponse, ETag};
+use crate::services::{user_service, ListPage};
 use crate::AppState;
 use axum::{
-    extract::{Path, Query, State},
+    extract::{Path, State},
+    response::{IntoResponse, Response},
     Json,
 };
 use uuid::Uuid;
 
-/// 获取用户列表（带分页）
+/// 获取用户列表（带分页，支持过滤和排序）
+///
+/// 带 `after` 或 `limit` 参数时使用游标分页，否则使用页码分页
 pub async fn list_users(
     State(state): State<AppState>,
-    Query(pagination): Query<Pagination>,
-) -> Result<ApiResponse<serde_json::Value>> {
-    let result = user_service::list_users(&state.db, pagination).await?;
+    pagination: Pagination,
+    CursorQuery(cursor): CursorQuery,
+    list_query: ListQuery<UserField>,
+) -> Result<(PaginationLinks, ApiResponse<ListPage<Vec<UserResponse>>>)> {
+    if let Some(cursor) = cursor {
+        let result = user_service::list_users_after_cursor(&state.db, &cursor, list_query).await?;
+        let links = cursor.links(result.cursor.next_cursor.as_deref());
+        return Ok((links, ApiResponse::success(ListPage::Cursor(result))));
+    }
 
-    Ok(ApiResponse::success(serde_json::json!({
-        "list": result.list,
-        "pagination": result.pagination,
-    })))
+    let result = user_service::list_users(&state.db, &pagination, list_query).await?;
+
+    Ok((
+        pagination.links(result.pagination.total),
+        ApiResponse::success(ListPage::Paged(result)),
+    ))
 }
 
 /// 根据 ID 获取用户
+///
+/// 响应带 `ETag`；`If-None-Match` 命中当前版本时返回 304
 pub async fn get_user(
     State(state): State<AppState>,
     Path(user_id): Path<Uuid>,
-) -> Result<ApiResponse<crate::models::UserResponse>> {
+    if_none_match: IfNoneMatch,
+) -> Result<Response> {
     let user = user_service::get_user_by_id(&state.db, user_id).await?;
+    let etag = ETag(user.version);
+    if if_none_match.matches(user.version) {
+        return Ok(etag.not_modified());
+    }
 
-    Ok(ApiResponse::success(user))
+    Ok((etag, ApiResponse::success(user)).into_response())
 }
 
 /// 更新用户信息
+///
+/// 必须携带 `If-Match`（缺少时返回 428，版本不一致时返回 412）
 pub async fn update_user(
     State(state): State<AppState>,
     Path(user_id): Path<Uuid>,
     auth_user: AuthUser,
+    if_match: IfMatch,
     Json(payload): Json<UpdateUserRequest>,
-) -> Result<ApiResponse<crate::models::UserResponse>> {
+) -> Result<(ETag, ApiResponse<crate::models::UserResponse>)> {
     // 只能更新自己的信息
     if auth_user.user_id != user_id {
         return Err(AppError::Forbidden);
     }
 
-    let user = user_service::update_user(&state.db, user_id, payload).await?;
+    let user = user_service::update_user(&state.db, user_id, payload, &if_match).await?;
 
-    Ok(ApiResponse::success_with_message(user, "更新成功"))
+    Ok((ETag(user.version), ApiResponse::success_with_message(user, "更新成功")))
 }
 
 /// 删除用户
@@ -63,5 +86,5 @@ pub async fn delete_user(
 
     user_service::delete_user(&state.db, user_id).await?;
 
-    Ok(ApiResponse::success_with_message((), "用户已删除"))
+    Ok(ApiResponse::success_with_message((), "用户已移入回收站"))
 }
@@ -11,9 +11,93 @@ pub struct Model {
     pub id: Uuid,
     pub title: String,
     pub content: String,
+    /// 正文的声明格式
+    pub content_format: ContentFormat,
+    /// 渲染并清洗后的 HTML 缓存（写入时生成，历史数据可能为空）
+    pub content_html: Option<String>,
+    /// 纯文本摘要（写入时生成，历史数据可能为空）
+    pub excerpt: Option<String>,
+    /// 字数（中日韩文字按字计，其他文字按词计）
+    pub word_count: Option<i32>,
+    /// 预计阅读时间（分钟）
+    pub reading_minutes: Option<i32>,
     pub user_id: Option<Uuid>,
     pub is_public: Option<bool>,
+    pub category_id: Option<Uuid>,
+    /// 所属系列（每篇文章最多属于一个系列）
+    pub series_id: Option<Uuid>,
+    /// 在系列中的位置（从 1 开始）
+    pub series_position: Option<i32>,
+    /// 累计浏览量（由浏览量统计任务批量累加）
+    pub view_count: i64,
     pub created_at: Option<DateTime<Utc>>,
+    /// 最近一次修改内容或属性的时间（浏览量变化不更新）
+    pub updated_at: Option<DateTime<Utc>>,
+    /// 版本号（乐观并发控制，每次更新加 1）
+    pub version: i32,
+    /// 被管理员隐藏的时间，非空时所有读取路径都不返回该文章
+    pub hidden_at: Option<DateTime<Utc>>,
+    /// 规范化正文的 SHA-256（重复内容检测）
+    pub content_hash: Option<String>,
+    /// 原文的语言（BCP 47 语言标签，例如 `zh`、`en`）
+    pub language: String,
+    /// 置顶位置（从 1 开始，由编辑维护）
+    pub pinned_position: Option<i32>,
+    /// 置顶到期时间，为空表示长期置顶
+    pub pinned_until: Option<DateTime<Utc>>,
+    pub pinned_at: Option<DateTime<Utc>>,
+    /// 设置置顶的编辑
+    pub pinned_by: Option<Uuid>,
+    /// 删除时间，非空时文章在回收站中，除回收站接口外都不返回
+    pub deleted_at: Option<DateTime<Utc>>,
+    /// 审核状态（未启用编辑审核流程时只在草稿和已发布之间切换）
+    pub review_status: ReviewStatus,
+}
+
+impl Model {
+    /// 置顶是否在 `now` 时有效
+    pub fn is_pinned_at(&self, now: DateTime<Utc>) -> bool {
+        self.pinned_position.is_some() && self.pinned_until.is_none_or(|until| until > now)
+    }
+}
+
+/// 文章正文格式
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(Some(20))")]
+#[serde(rename_all = "lowercase")]
+pub enum ContentFormat {
+    /// Markdown
+    #[default]
+    #[sea_orm(string_value = "markdown")]
+    Markdown,
+    /// 纯文本
+    #[sea_orm(string_value = "plain")]
+    Plain,
+}
+
+/// 文章审核状态
+///
+/// 草稿 → 待审核 → 已通过 → 已发布；待审核时编辑也可以退回修改，修改后重新提交
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(Some(20))")]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewStatus {
+    /// 草稿
+    #[default]
+    #[sea_orm(string_value = "draft")]
+    Draft,
+    /// 已提交，等待编辑审核
+    #[sea_orm(string_value = "submitted")]
+    Submitted,
+    /// 编辑要求修改
+    #[sea_orm(string_value = "changes_requested")]
+    ChangesRequested,
+    /// 编辑已通过，等待发布
+    #[sea_orm(string_value = "approved")]
+    Approved,
+    /// 已发布
+    #[sea_orm(string_value = "published")]
+    Published,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
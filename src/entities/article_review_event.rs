@@ -0,0 +1,26 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use super::article::ReviewStatus;
+
+/// 文章审核状态变更记录实体（只追加，不修改）
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "article_review_events")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub article_id: Uuid,
+    /// 执行变更的用户（作者或编辑，用户被彻底删除后为空）
+    pub actor_id: Option<Uuid>,
+    pub from_status: ReviewStatus,
+    pub to_status: ReviewStatus,
+    /// 审核意见或提交说明
+    pub comment: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,31 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use super::article::ContentFormat;
+
+/// 文章修订实体（不可变，每次写入文章都会追加一条）
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "article_revisions")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub article_id: Uuid,
+    /// 文章内递增的修订号，从 1 开始
+    pub revision_number: i32,
+    pub title: String,
+    pub content: String,
+    /// 正文格式
+    pub content_format: ContentFormat,
+    /// 产生该修订的用户（用户被彻底删除后为空）
+    pub author_id: Option<Uuid>,
+    /// 若该修订由恢复操作产生，记录被恢复的修订号
+    pub restored_from: Option<i32>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
@@ -0,0 +1,35 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use super::article::ContentFormat;
+
+/// 文章译文实体（每篇文章每种语言最多一个译文）
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "article_translations")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub article_id: Uuid,
+    /// BCP 47 语言标签（规范化后，例如 `en`、`zh-TW`）
+    pub language: String,
+    pub title: String,
+    /// 同一语言内唯一
+    pub slug: String,
+    pub content: String,
+    pub content_format: ContentFormat,
+    /// 渲染并清洗后的 HTML 缓存
+    pub content_html: Option<String>,
+    pub excerpt: Option<String>,
+    pub word_count: Option<i32>,
+    pub reading_minutes: Option<i32>,
+    /// 未发布的译文只在译文管理接口中可见
+    pub is_published: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
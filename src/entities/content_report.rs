@@ -0,0 +1,88 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// 内容举报实体（同一用户对同一内容只保留一条举报）
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "content_reports")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub target_type: ReportTarget,
+    pub target_id: Uuid,
+    /// 举报人（内容过滤器自动提交时为空）
+    pub reporter_id: Option<Uuid>,
+    pub reason: ReportReason,
+    pub details: Option<String>,
+    pub status: ReportStatus,
+    pub created_at: DateTime<Utc>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub resolved_by: Option<Uuid>,
+}
+
+/// 被举报的内容类型
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(Some(20))")]
+#[serde(rename_all = "lowercase")]
+pub enum ReportTarget {
+    #[sea_orm(string_value = "article")]
+    Article,
+    /// 评论（预留，目前只开放文章举报）
+    #[sea_orm(string_value = "comment")]
+    Comment,
+}
+
+/// 举报原因
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, EnumIter, DeriveActiveEnum, Serialize, Deserialize,
+)]
+#[sea_orm(rs_type = "String", db_type = "String(Some(20))")]
+#[serde(rename_all = "lowercase")]
+pub enum ReportReason {
+    /// 垃圾广告
+    #[sea_orm(string_value = "spam")]
+    Spam,
+    /// 辱骂、骚扰
+    #[sea_orm(string_value = "harassment")]
+    Harassment,
+    /// 仇恨言论
+    #[sea_orm(string_value = "hate")]
+    Hate,
+    /// 色情、暴力等不适内容
+    #[sea_orm(string_value = "explicit")]
+    Explicit,
+    /// 侵犯版权
+    #[sea_orm(string_value = "copyright")]
+    Copyright,
+    /// 违法内容
+    #[sea_orm(string_value = "illegal")]
+    Illegal,
+    /// 其他（必须填写说明）
+    #[sea_orm(string_value = "other")]
+    Other,
+    /// 内容过滤器自动提交（用户不能使用）
+    #[sea_orm(string_value = "automated")]
+    Automated,
+}
+
+/// 举报处理状态
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(Some(20))")]
+#[serde(rename_all = "lowercase")]
+pub enum ReportStatus {
+    /// 待处理
+    #[sea_orm(string_value = "open")]
+    Open,
+    /// 已处理（内容被隐藏、删除或作者被警告）
+    #[sea_orm(string_value = "resolved")]
+    Resolved,
+    /// 已驳回
+    #[sea_orm(string_value = "dismissed")]
+    Dismissed,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
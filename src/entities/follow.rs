@@ -0,0 +1,36 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// 关注关系实体（主键为 `(follower_id, followee_id)`）
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "user_follows")]
+pub struct Model {
+    /// 关注者
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub follower_id: Uuid,
+    /// 被关注的用户
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub followee_id: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+/// 两个关联都指向用户表，查询时用 `Relation::Xxx.def().rev()` 显式连接
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::user::Entity",
+        from = "Column::FollowerId",
+        to = "super::user::Column::Id"
+    )]
+    Follower,
+    #[sea_orm(
+        belongs_to = "super::user::Entity",
+        from = "Column::FolloweeId",
+        to = "super::user::Column::Id"
+    )]
+    Followee,
+}
+
+impl ActiveModelBehavior for ActiveModel {}
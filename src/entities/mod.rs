@@ -1,7 +1,39 @@
 // 实体模块导出
 pub mod user;
 pub mod article;
+pub mod category;
+pub mod comment;
+pub mod reaction;
+pub mod bookmark;
+pub mod article_revision;
+pub mod attachment;
+pub mod article_view_bucket;
+pub mod series;
+pub mod collection;
+pub mod collection_item;
+pub mod content_report;
+pub mod moderation_action;
+pub mod article_translation;
+pub mod article_share_link;
+pub mod follow;
+pub mod article_review_event;
 
 pub use user::Entity as User;
 pub use article::Entity as Article;
+pub use category::Entity as Category;
+pub use comment::Entity as Comment;
+pub use reaction::Entity as Reaction;
+pub use bookmark::Entity as Bookmark;
+pub use article_revision::Entity as ArticleRevision;
+pub use attachment::Entity as Attachment;
+pub use article_view_bucket::Entity as ArticleViewBucket;
+pub use series::Entity as Series;
+pub use collection::Entity as Collection;
+pub use collection_item::Entity as CollectionItem;
+pub use content_report::Entity as ContentReport;
+pub use moderation_action::Entity as ModerationAction;
+pub use article_translation::Entity as ArticleTranslation;
+pub use article_share_link::Entity as ArticleShareLink;
+pub use follow::Entity as Follow;
+pub use article_review_event::Entity as ArticleReviewEvent;
 
@@ -0,0 +1,49 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use super::content_report::ReportTarget;
+
+/// 管理操作记录实体（只追加，不修改）
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "moderation_actions")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub target_type: ReportTarget,
+    pub target_id: Uuid,
+    /// 内容作者（警告的对象）
+    pub target_user_id: Option<Uuid>,
+    /// 执行操作的管理员（管理员被彻底删除后为空）
+    pub moderator_id: Option<Uuid>,
+    pub action: ModerationActionKind,
+    pub note: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// 管理操作类型
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(Some(20))")]
+#[serde(rename_all = "lowercase")]
+pub enum ModerationActionKind {
+    /// 隐藏内容
+    #[sea_orm(string_value = "hide")]
+    Hide,
+    /// 恢复被隐藏的内容
+    #[sea_orm(string_value = "restore")]
+    Restore,
+    /// 删除内容
+    #[sea_orm(string_value = "delete")]
+    Delete,
+    /// 警告作者
+    #[sea_orm(string_value = "warn")]
+    Warn,
+    /// 驳回举报，不处理内容
+    #[sea_orm(string_value = "dismiss")]
+    Dismiss,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
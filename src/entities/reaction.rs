@@ -0,0 +1,57 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// 文章表情反应实体（主键为 `(user_id, article_id)`）
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "article_reactions")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub user_id: Uuid,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub article_id: Uuid,
+    pub reaction: ReactionKind,
+    pub created_at: DateTime<Utc>,
+}
+
+/// 可用的表情反应（固定集合）
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, EnumIter, DeriveActiveEnum, Serialize, Deserialize,
+)]
+#[sea_orm(rs_type = "String", db_type = "String(Some(20))")]
+#[serde(rename_all = "lowercase")]
+pub enum ReactionKind {
+    #[sea_orm(string_value = "like")]
+    Like,
+    #[sea_orm(string_value = "love")]
+    Love,
+    #[sea_orm(string_value = "laugh")]
+    Laugh,
+    #[sea_orm(string_value = "hooray")]
+    Hooray,
+    #[sea_orm(string_value = "confused")]
+    Confused,
+    #[sea_orm(string_value = "rocket")]
+    Rocket,
+}
+
+impl ReactionKind {
+    /// 对应的 emoji
+    pub fn emoji(&self) -> &'static str {
+        match self {
+            ReactionKind::Like => "👍",
+            ReactionKind::Love => "❤️",
+            ReactionKind::Laugh => "😄",
+            ReactionKind::Hooray => "🎉",
+            ReactionKind::Confused => "😕",
+            ReactionKind::Rocket => "🚀",
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
@@ -14,8 +14,30 @@ pub struct Model {
     #[sea_orm(unique)]
     pub email: String,
     pub password_hash: String,
+    pub role: UserRole,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// 版本号（乐观并发控制，每次更新加 1）
+    pub version: i32,
+    /// 删除时间，非空时用户在回收站中，不能登录，除回收站接口外都不返回
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+/// 用户角色
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(Some(20))")]
+#[serde(rename_all = "lowercase")]
+pub enum UserRole {
+    /// 普通用户
+    #[default]
+    #[sea_orm(string_value = "user")]
+    User,
+    /// 编辑
+    #[sea_orm(string_value = "editor")]
+    Editor,
+    /// 管理员
+    #[sea_orm(string_value = "admin")]
+    Admin,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -24,6 +24,18 @@ pub enum AppError {
     #[error("验证错误: {0}")]
     Validation(String),
 
+    #[error("请求体过大: {0}")]
+    PayloadTooLarge(String),
+
+    #[error("不支持的媒体类型: {0}")]
+    UnsupportedMediaType(String),
+
+    #[error("前置条件失败: {0}")]
+    PreconditionFailed(String),
+
+    #[error("缺少前置条件: {0}")]
+    PreconditionRequired(String),
+
     #[error("内部服务器错误: {0}")]
     Internal(#[from] anyhow::Error),
 
@@ -57,6 +69,26 @@ impl IntoResponse for AppError {
                 StatusCode::FORBIDDEN,
             ),
             AppError::Validation(msg) => (StatusCode::BAD_REQUEST, msg, StatusCode::BAD_REQUEST),
+            AppError::PayloadTooLarge(msg) => (
+                StatusCode::PAYLOAD_TOO_LARGE,
+                msg,
+                StatusCode::PAYLOAD_TOO_LARGE,
+            ),
+            AppError::UnsupportedMediaType(msg) => (
+                StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                msg,
+                StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            ),
+            AppError::PreconditionFailed(msg) => (
+                StatusCode::PRECONDITION_FAILED,
+                msg,
+                StatusCode::PRECONDITION_FAILED,
+            ),
+            AppError::PreconditionRequired(msg) => (
+                StatusCode::PRECONDITION_REQUIRED,
+                msg,
+                StatusCode::PRECONDITION_REQUIRED,
+            ),
             AppError::Internal(e) => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 e.to_string(),
@@ -74,5 +106,20 @@ impl IntoResponse for AppError {
     }
 }
 
+impl AppError {
+    /// 版本已过期（ETag 与当前版本不一致）
+    pub fn version_conflict() -> Self {
+        AppError::PreconditionFailed("资源已被修改，请重新获取后再更新".to_string())
+    }
+
+    /// 转换带版本条件的更新错误：没有命中任何行说明版本已被并发修改
+    pub fn from_versioned_update(e: sea_orm::DbErr) -> Self {
+        match e {
+            sea_orm::DbErr::RecordNotUpdated => AppError::version_conflict(),
+            e => AppError::Database(e),
+        }
+    }
+}
+
 /// Result 类型别名
 pub type Result<T> = std::result::Result<T, AppError>;
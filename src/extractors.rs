@@ -1,52 +1,751 @@
+use crate::entities::user::UserRole;
 use crate::errors::{AppError, Result};
-use axum::{async_trait, extract::FromRequestParts, http::request::Parts};
+use crate::config::Config;
+use axum::{
+    async_trait,
+    extract::{FromRef, FromRequestParts, OriginalUri, Query},
+    http::{header, request::Parts, HeaderValue},
+    response::{IntoResponseParts, ResponseParts},
+};
+use std::convert::Infallible;
+use chrono::{DateTime, Utc};
+use sea_orm::DatabaseConnection;
 use serde::Deserialize;
 use uuid::Uuid;
 
-/// 分页参数提取器
-#[derive(Debug, Deserialize)]
+/// 分页参数提取器（`?page=1&page_size=10`）
+///
+/// - 参数缺省时使用第 1 页和配置的缺省每页数量
+/// - `page`、`page_size` 必须是大于 0 的整数，否则返回 400（统一的 `ApiResponse` 格式）
+/// - `page` 不能超过 [`MAX_PAGE`]，避免计算偏移量时溢出
+/// - `page_size` 超过配置的上限时按上限处理
+///
+/// 同时记录请求路径和其余查询参数，用于生成 RFC 8288 `Link` 响应头
+#[derive(Debug, Clone)]
 pub struct Pagination {
+    /// 页码，从 1 开始
     pub page: u64,
     pub page_size: u64,
+    link_base: Option<LinkBase>,
 }
 
+/// 页码上限
+pub const MAX_PAGE: u64 = 1_000_000;
+
 impl Default for Pagination {
     fn default() -> Self {
         Self {
             page: 1,
             page_size: 10,
+            link_base: None,
         }
     }
 }
 
 impl Pagination {
     pub fn offset(&self) -> u64 {
-        let page = self.page;
-        let page_size = self.page_size;
-        (page.saturating_sub(1)) * page_size
+        self.page_index().saturating_mul(self.page_size)
     }
 
     pub fn limit(&self) -> u64 {
         self.page_size
     }
+
+    /// 从 0 开始的页码（用于 sea-orm 的 `fetch_page`）
+    pub fn page_index(&self) -> u64 {
+        self.page.saturating_sub(1)
+    }
+
+    /// 总页数
+    pub fn total_pages(&self, total: u64) -> u64 {
+        total.div_ceil(self.page_size.max(1))
+    }
+
+    /// 生成 `first`、`prev`、`next`、`last` 分页链接
+    pub fn links(&self, total: u64) -> PaginationLinks {
+        let Some(base) = &self.link_base else {
+            return PaginationLinks::default();
+        };
+        let last = self.total_pages(total).max(1);
+        let page_size = self.page_size.to_string();
+        let link = |page: u64, rel: &'static str| {
+            (
+                base.url(&[("page", &page.to_string()), ("page_size", &page_size)]),
+                rel,
+            )
+        };
+
+        let mut links = vec![link(1, "first")];
+        if self.page > 1 {
+            links.push(link((self.page - 1).min(last), "prev"));
+        }
+        if self.page < last {
+            links.push(link(self.page + 1, "next"));
+        }
+        links.push(link(last, "last"));
+
+        PaginationLinks(links)
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for Pagination
+where
+    Config: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self> {
+        let config = Config::from_ref(state).pagination;
+        let (base, pairs) = LinkBase::from_parts(parts, &["page", "page_size"])?;
+
+        let param = |name: &str| pairs.iter().rev().find(|(k, _)| k == name).map(|(_, v)| v);
+        let positive = |name: &str, value: &String| {
+            value
+                .trim()
+                .parse::<u64>()
+                .ok()
+                .filter(|v| *v > 0)
+                .ok_or_else(|| AppError::Validation(format!("{} 必须是大于 0 的整数", name)))
+        };
+
+        let page = match param("page") {
+            Some(value) => positive("page", value)?,
+            None => 1,
+        };
+        if page > MAX_PAGE {
+            return Err(AppError::Validation(format!("page 不能超过 {}", MAX_PAGE)));
+        }
+        let page_size = match param("page_size") {
+            Some(value) => positive("page_size", value)?.min(config.max_page_size),
+            None => config.default_page_size,
+        };
+
+        Ok(Pagination {
+            page,
+            page_size,
+            link_base: Some(base),
+        })
+    }
+}
+
+/// 生成分页链接所需的请求路径和其余查询参数
+#[derive(Debug, Clone)]
+struct LinkBase {
+    path: String,
+    query: Vec<(String, String)>,
+}
+
+impl LinkBase {
+    /// 从请求中解析，返回 `(去掉分页参数后的 LinkBase, 全部查询参数)`
+    fn from_parts(parts: &Parts, paging_params: &[&str]) -> Result<(Self, Vec<(String, String)>)> {
+        // 嵌套路由中 parts.uri 已去掉前缀，使用原始 URI 生成完整路径
+        let uri = parts
+            .extensions
+            .get::<OriginalUri>()
+            .map(|original| &original.0)
+            .unwrap_or(&parts.uri);
+        let Query(pairs) = Query::<Vec<(String, String)>>::try_from_uri(uri)
+            .map_err(|e| AppError::Validation(e.body_text()))?;
+
+        let base = LinkBase {
+            path: uri.path().to_string(),
+            query: pairs
+                .iter()
+                .filter(|(k, _)| !paging_params.contains(&k.as_str()))
+                .cloned()
+                .collect(),
+        };
+        Ok((base, pairs))
+    }
+
+    fn url(&self, paging: &[(&str, &str)]) -> String {
+        let pairs: Vec<(&str, &str)> = self
+            .query
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .chain(paging.iter().copied())
+            .collect();
+        let query = serde_urlencoded::to_string(pairs).unwrap_or_default();
+        format!("{}?{}", self.path, query)
+    }
+}
+
+/// RFC 8288 `Link` 响应头，作为响应的一部分返回：`Ok((links, ApiResponse::success(...)))`
+#[derive(Debug, Clone, Default)]
+pub struct PaginationLinks(Vec<(String, &'static str)>);
+
+impl IntoResponseParts for PaginationLinks {
+    type Error = Infallible;
+
+    fn into_response_parts(self, mut res: ResponseParts) -> std::result::Result<ResponseParts, Self::Error> {
+        if self.0.is_empty() {
+            return Ok(res);
+        }
+        let value = self
+            .0
+            .iter()
+            .map(|(url, rel)| format!("<{}>; rel=\"{}\"", url, rel))
+            .collect::<Vec<_>>()
+            .join(", ");
+        if let Ok(value) = HeaderValue::from_str(&value) {
+            res.headers_mut().insert(header::LINK, value);
+        }
+        Ok(res)
+    }
+}
+
+/// 游标分页缺省每页数量
+const DEFAULT_CURSOR_LIMIT: u64 = 20;
+/// 游标分页最大每页数量
+const MAX_CURSOR_LIMIT: u64 = 100;
+
+/// 游标（键集分页的位置），对调用方不透明
+///
+/// 记录上一页最后一条记录的 `(created_at, id)`，下一页从严格小于该键的位置开始，
+/// 因此翻页期间有新增或删除的数据时也不会重复或遗漏
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cursor {
+    pub created_at: DateTime<Utc>,
+    pub id: Uuid,
+}
+
+impl Cursor {
+    /// 编码为不透明字符串（时间戳微秒 + ID 的十六进制）
+    pub fn encode(&self) -> String {
+        let mut bytes = Vec::with_capacity(24);
+        bytes.extend_from_slice(&self.created_at.timestamp_micros().to_be_bytes());
+        bytes.extend_from_slice(self.id.as_bytes());
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    /// 从不透明字符串解码
+    pub fn decode(value: &str) -> Option<Self> {
+        // from_str_radix 接受 `+` 前缀，需要先确认全部是十六进制字符
+        if value.len() != 48 || !value.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        let bytes: Vec<u8> = (0..value.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&value[i..i + 2], 16))
+            .collect::<std::result::Result<_, _>>()
+            .ok()?;
+
+        let micros = i64::from_be_bytes(bytes[..8].try_into().ok()?);
+        Some(Cursor {
+            created_at: DateTime::from_timestamp_micros(micros)?,
+            id: Uuid::from_slice(&bytes[8..]).ok()?,
+        })
+    }
+}
+
+/// 游标分页参数
+#[derive(Debug, Clone)]
+pub struct CursorPagination {
+    /// 从该游标之后开始，为空表示第一页
+    pub after: Option<Cursor>,
+    pub limit: u64,
+    /// 是否同时返回总数（需要额外的 COUNT 查询，缺省不返回）
+    pub include_total: bool,
+    link_base: LinkBase,
+}
+
+impl CursorPagination {
+    /// 生成 `first`、`next` 分页链接
+    pub fn links(&self, next_cursor: Option<&str>) -> PaginationLinks {
+        let limit = self.limit.to_string();
+        let mut links = vec![(self.link_base.url(&[("limit", &limit)]), "first")];
+        if let Some(cursor) = next_cursor {
+            links.push((self.link_base.url(&[("after", cursor), ("limit", &limit)]), "next"));
+        }
+        PaginationLinks(links)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct CursorParams {
+    after: Option<String>,
+    limit: Option<u64>,
+    #[serde(default)]
+    include_total: bool,
+}
+
+/// 游标分页参数提取器：`?after=<cursor>&limit=20&include_total=true`
+///
+/// 查询参数中出现 `after` 或 `limit` 时为游标分页，否则为 `None`，由列表接口回退到页码分页
+#[derive(Debug, Clone)]
+pub struct CursorQuery(pub Option<CursorPagination>);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for CursorQuery
+where
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self> {
+        let Query(params) = Query::<CursorParams>::try_from_uri(&parts.uri)
+            .map_err(|e| AppError::Validation(e.body_text()))?;
+        if params.after.is_none() && params.limit.is_none() {
+            return Ok(CursorQuery(None));
+        }
+
+        CursorPagination::from_request_parts(parts, state)
+            .await
+            .map(|pagination| CursorQuery(Some(pagination)))
+    }
+}
+
+/// 只支持游标分页的接口直接提取 `CursorPagination`，参数缺省时为第一页
+#[async_trait]
+impl<S> FromRequestParts<S> for CursorPagination
+where
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self> {
+        let Query(params) = Query::<CursorParams>::try_from_uri(&parts.uri)
+            .map_err(|e| AppError::Validation(e.body_text()))?;
+        let (link_base, _) = LinkBase::from_parts(parts, &["after", "limit"])?;
+
+        let after = match params.after.as_deref().filter(|s| !s.is_empty()) {
+            Some(value) => Some(
+                Cursor::decode(value)
+                    .ok_or_else(|| AppError::Validation("无效的游标".to_string()))?,
+            ),
+            None => None,
+        };
+        let limit = params.limit.unwrap_or(DEFAULT_CURSOR_LIMIT);
+        if !(1..=MAX_CURSOR_LIMIT).contains(&limit) {
+            return Err(AppError::Validation(format!(
+                "limit 必须在 1-{} 之间",
+                MAX_CURSOR_LIMIT
+            )));
+        }
+
+        Ok(CursorPagination {
+            after,
+            limit,
+            include_total: params.include_total,
+            link_base,
+        })
+    }
+}
+
+/// 单次请求最多的排序字段数
+const MAX_SORT_FIELDS: usize = 3;
+/// 文本包含过滤的最大长度（字符数）
+const MAX_CONTAINS_LENGTH: usize = 100;
+
+/// 过滤方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterKind {
+    /// ID 等值匹配（值为 UUID）
+    Equals,
+    /// 时间晚于（含），值为 RFC 3339 时间
+    After,
+    /// 时间早于（不含），值为 RFC 3339 时间
+    Before,
+    /// 文本包含
+    Contains,
+}
+
+/// 解析后的过滤条件
+#[derive(Debug, Clone)]
+pub enum Filter<F> {
+    Equals(F, Uuid),
+    After(F, DateTime<Utc>),
+    Before(F, DateTime<Utc>),
+    Contains(F, String),
+}
+
+/// 排序字段
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sort<F> {
+    pub field: F,
+    pub descending: bool,
+}
+
+/// 列表资源的字段白名单
+///
+/// 每种资源声明自己允许过滤和排序的字段，查询参数中不在白名单内的字段无法访问数据库列
+pub trait ListField: Copy + Eq + Send + Sync + 'static {
+    /// 过滤参数白名单：`(查询参数名, 字段, 过滤方式)`
+    const FILTERS: &'static [(&'static str, Self, FilterKind)];
+    /// 排序字段白名单：`(字段名, 字段)`
+    const SORTS: &'static [(&'static str, Self)];
+    /// 未指定 `sort` 时的缺省排序
+    const DEFAULT_SORT: &'static [Sort<Self>];
+}
+
+/// 列表过滤与排序参数提取器
+///
+/// - 过滤：按资源白名单读取参数，如 `author_id=...&created_after=2024-01-01T00:00:00Z&title_contains=rust`
+/// - 排序：`sort=-created_at,title`，`-` 前缀表示倒序，字段必须在白名单内
+///
+/// 白名单以外的参数会被忽略（它们可能属于分页等其他提取器），非法的值或排序字段返回 400
+#[derive(Debug, Clone)]
+pub struct ListQuery<F: ListField> {
+    pub filters: Vec<Filter<F>>,
+    pub sort: Vec<Sort<F>>,
+}
+
+impl<F: ListField> Default for ListQuery<F> {
+    fn default() -> Self {
+        Self {
+            filters: Vec::new(),
+            sort: F::DEFAULT_SORT.to_vec(),
+        }
+    }
+}
+
+impl<F: ListField> ListQuery<F> {
+    /// 是否为缺省排序（游标分页只支持缺省排序）
+    pub fn is_default_sort(&self) -> bool {
+        self.sort == F::DEFAULT_SORT
+    }
+
+    /// 从查询参数解析
+    pub fn from_pairs(pairs: &[(String, String)]) -> Result<Self> {
+        let mut query = Self::default();
+
+        for (key, value) in pairs {
+            if key == "sort" {
+                query.sort = parse_sort(value)?;
+                continue;
+            }
+            let Some((_, field, kind)) = F::FILTERS.iter().find(|(name, _, _)| name == key) else {
+                continue;
+            };
+            let value = value.trim();
+            let invalid = || AppError::Validation(format!("参数 {} 的值无效: {}", key, value));
+            let filter = match kind {
+                FilterKind::Equals => Filter::Equals(*field, value.parse().map_err(|_| invalid())?),
+                FilterKind::After => Filter::After(*field, parse_time(value).ok_or_else(invalid)?),
+                FilterKind::Before => Filter::Before(*field, parse_time(value).ok_or_else(invalid)?),
+                FilterKind::Contains => {
+                    let length = value.chars().count();
+                    if length == 0 || length > MAX_CONTAINS_LENGTH {
+                        return Err(AppError::Validation(format!(
+                            "参数 {} 的长度必须在 1-{} 个字符之间",
+                            key, MAX_CONTAINS_LENGTH
+                        )));
+                    }
+                    Filter::Contains(*field, value.to_string())
+                }
+            };
+            query.filters.push(filter);
+        }
+
+        Ok(query)
+    }
+}
+
+fn parse_sort<F: ListField>(value: &str) -> Result<Vec<Sort<F>>> {
+    let mut sort: Vec<Sort<F>> = Vec::new();
+
+    for item in value.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+        let (name, descending) = match item.strip_prefix('-') {
+            Some(name) => (name, true),
+            None => (item.strip_prefix('+').unwrap_or(item), false),
+        };
+        let (_, field) = F::SORTS.iter().find(|(n, _)| *n == name).ok_or_else(|| {
+            let allowed: Vec<&str> = F::SORTS.iter().map(|(n, _)| *n).collect();
+            AppError::Validation(format!(
+                "不支持的排序字段: {}，可选: {}",
+                name,
+                allowed.join(", ")
+            ))
+        })?;
+        if sort.iter().any(|s| s.field == *field) {
+            return Err(AppError::Validation(format!("排序字段重复: {}", name)));
+        }
+        sort.push(Sort { field: *field, descending });
+    }
+
+    if sort.len() > MAX_SORT_FIELDS {
+        return Err(AppError::Validation(format!(
+            "排序字段不能超过 {} 个",
+            MAX_SORT_FIELDS
+        )));
+    }
+    if sort.is_empty() {
+        return Ok(F::DEFAULT_SORT.to_vec());
+    }
+
+    Ok(sort)
+}
+
+/// 解析时间：接受 RFC 3339 或 `YYYY-MM-DD`（按 UTC 零点）
+fn parse_time(value: &str) -> Option<DateTime<Utc>> {
+    if let Ok(time) = DateTime::parse_from_rfc3339(value) {
+        return Some(time.with_timezone(&Utc));
+    }
+    chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|time| time.and_utc())
+}
+
+#[async_trait]
+impl<S, F> FromRequestParts<S> for ListQuery<F>
+where
+    S: Send + Sync,
+    F: ListField,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self> {
+        let Query(pairs) = Query::<Vec<(String, String)>>::try_from_uri(&parts.uri)
+            .map_err(|e| AppError::Validation(e.body_text()))?;
+
+        Self::from_pairs(&pairs)
+    }
+}
+
+/// 条件请求头中的实体标签列表（`"1", W/"2"` 或 `*`）
+#[derive(Debug, Clone, Default)]
+struct EntityTags {
+    /// 是否为 `*`
+    any: bool,
+    /// (是否弱标签, 标签值)
+    tags: Vec<(bool, String)>,
+}
+
+impl EntityTags {
+    /// 解析请求头，请求头缺失时返回 None
+    fn parse(parts: &Parts, name: header::HeaderName) -> Result<Option<Self>> {
+        let mut values = parts.headers.get_all(&name).iter().peekable();
+        if values.peek().is_none() {
+            return Ok(None);
+        }
+
+        let invalid = || AppError::Validation(format!("{} 请求头格式无效", name));
+        let mut entity_tags = EntityTags::default();
+        for value in values {
+            let value = value.to_str().map_err(|_| invalid())?;
+            for tag in value.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+                if tag == "*" {
+                    entity_tags.any = true;
+                    continue;
+                }
+                let (weak, opaque) = match tag.strip_prefix("W/") {
+                    Some(rest) => (true, rest),
+                    None => (false, tag),
+                };
+                let opaque = opaque
+                    .strip_prefix('"')
+                    .and_then(|t| t.strip_suffix('"'))
+                    .ok_or_else(invalid)?;
+                entity_tags.tags.push((weak, opaque.to_string()));
+            }
+        }
+        Ok(Some(entity_tags))
+    }
+
+    /// 判断是否匹配指定的标签值；强比较时弱标签永远不匹配
+    fn matches(&self, opaque: &str, strong: bool) -> bool {
+        self.any
+            || self
+                .tags
+                .iter()
+                .any(|(weak, tag)| !(strong && *weak) && tag == opaque)
+    }
+}
+
+/// `If-Match` 请求头提取器，用于乐观并发控制
+///
+/// - 缺少请求头时返回 428，要求客户端先获取资源的 ETag
+/// - 使用强比较：弱标签（`W/"…"`）永远不匹配
+/// - `*` 匹配任意版本
+#[derive(Debug, Clone)]
+pub struct IfMatch(EntityTags);
+
+impl IfMatch {
+    /// 判断资源的当前版本是否满足前置条件
+    pub fn matches(&self, version: i32) -> bool {
+        self.0.matches(&version.to_string(), true)
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for IfMatch
+where
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self> {
+        EntityTags::parse(parts, header::IF_MATCH)?
+            .map(IfMatch)
+            .ok_or_else(|| {
+                AppError::PreconditionRequired(
+                    "缺少 If-Match 请求头，请先获取资源并带上其 ETag".to_string(),
+                )
+            })
+    }
+}
+
+/// `If-None-Match` 请求头提取器，用于条件 GET
+///
+/// 请求头缺失或格式无效时视为不带条件；使用弱比较，`*` 匹配任意版本
+#[derive(Debug, Clone, Default)]
+pub struct IfNoneMatch(Option<EntityTags>);
+
+impl IfNoneMatch {
+    /// 客户端缓存的版本是否仍是最新（此时应返回 304）
+    pub fn matches(&self, version: i32) -> bool {
+        self.matches_tag(&version.to_string())
+    }
+
+    /// 客户端缓存的实体标签是否仍与当前表示一致（弱比较）
+    pub fn matches_tag(&self, opaque: &str) -> bool {
+        self.0.as_ref().is_some_and(|tags| tags.matches(opaque, false))
+    }
+
+    /// 请求是否带有效的 `If-None-Match`（带时应忽略 `If-Modified-Since`）
+    pub fn is_present(&self) -> bool {
+        self.0.is_some()
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for IfNoneMatch
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> std::result::Result<Self, Infallible> {
+        Ok(IfNoneMatch(EntityTags::parse(parts, header::IF_NONE_MATCH).ok().flatten()))
+    }
+}
+
+/// 语言偏好提取器（`?lang=en` 优先，其次 `Accept-Language` 请求头）
+///
+/// 按偏好顺序保存规范化后的语言标签；`Accept-Language` 按 q 值排序，
+/// 忽略 `*`、`q=0` 和无效的标签，不会因为格式问题拒绝请求
+#[derive(Debug, Clone, Default)]
+pub struct PreferredLanguages(pub Vec<String>);
+
+impl PreferredLanguages {
+    /// 从可用语言中选出最符合偏好的一个
+    ///
+    /// 依次检查每个偏好语言：先找完全相同的标签，再找主语言相同的标签（`en-US` 可匹配 `en`，反之亦然）
+    pub fn best_match<'a>(&self, available: &[&'a str]) -> Option<&'a str> {
+        let primary = |tag: &str| tag.split('-').next().unwrap_or_default().to_string();
+
+        self.0.iter().find_map(|preferred| {
+            available
+                .iter()
+                .find(|tag| tag.eq_ignore_ascii_case(preferred))
+                .or_else(|| available.iter().find(|tag| primary(tag) == primary(preferred)))
+                .copied()
+        })
+    }
+
+    /// 解析 `Accept-Language` 请求头
+    fn parse_accept_language(value: &str) -> Vec<String> {
+        let mut weighted: Vec<(f32, String)> = value
+            .split(',')
+            .filter_map(|item| {
+                let mut parts = item.split(';');
+                let tag = crate::utils::normalize_language_tag(parts.next()?)?;
+                let quality = parts
+                    .find_map(|param| param.trim().strip_prefix("q="))
+                    .map_or(Some(1.0), |q| q.trim().parse::<f32>().ok())?;
+                (quality > 0.0).then_some((quality, tag))
+            })
+            .collect();
+        // 稳定排序，q 值相同时保持请求头中的顺序
+        weighted.sort_by(|a, b| b.0.total_cmp(&a.0));
+        weighted.into_iter().map(|(_, tag)| tag).collect()
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for PreferredLanguages
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> std::result::Result<Self, Infallible> {
+        let mut languages: Vec<String> = Query::<Vec<(String, String)>>::try_from_uri(&parts.uri)
+            .map(|Query(pairs)| pairs)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|(key, _)| key == "lang")
+            .flat_map(|(_, value)| {
+                value
+                    .split(',')
+                    .filter_map(crate::utils::normalize_language_tag)
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        if let Some(value) = parts
+            .headers
+            .get(header::ACCEPT_LANGUAGE)
+            .and_then(|value| value.to_str().ok())
+        {
+            languages.extend(Self::parse_accept_language(value));
+        }
+
+        Ok(PreferredLanguages(languages))
+    }
 }
 
 /// 自定义认证提取器
-/// 从请求头中提取 JWT token 并验证
+/// 从请求头中提取 JWT token 并验证；用户已被删除（在回收站中）时同样返回 401
 #[derive(Debug, Clone)]
 pub struct AuthUser {
     pub user_id: uuid::Uuid,
     pub username: String,
+    pub role: UserRole,
+}
+
+impl AuthUser {
+    /// 是否为管理员
+    pub fn is_admin(&self) -> bool {
+        self.role == UserRole::Admin
+    }
+
+    /// 要求管理员权限，否则返回 403
+    pub fn require_admin(&self) -> Result<()> {
+        if self.is_admin() {
+            Ok(())
+        } else {
+            Err(AppError::Forbidden)
+        }
+    }
+
+    /// 是否为编辑（管理员同样拥有编辑权限）
+    pub fn is_editor(&self) -> bool {
+        matches!(self.role, UserRole::Editor | UserRole::Admin)
+    }
+
+    /// 要求编辑权限，否则返回 403
+    pub fn require_editor(&self) -> Result<()> {
+        if self.is_editor() {
+            Ok(())
+        } else {
+            Err(AppError::Forbidden)
+        }
+    }
 }
 
 #[async_trait]
 impl<S> FromRequestParts<S> for AuthUser
 where
     S: Send + Sync,
+    DatabaseConnection: FromRef<S>,
 {
     type Rejection = AppError;
 
-    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self> {
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self> {
         // 从请求头获取 Authorization
         let auth_header = parts
             .headers
@@ -62,9 +761,16 @@ where
         // 验证 token
         let claims = crate::jwt::verify_token(token)?;
 
+        // 令牌在有效期内仍可能属于已删除的用户
+        let db = DatabaseConnection::from_ref(state);
+        if !crate::repositories::user_repository::is_active(&db, claims.sub).await? {
+            return Err(AppError::Unauthorized);
+        }
+
         Ok(AuthUser {
             user_id: claims.sub,
             username: claims.username,
+            role: claims.role,
         })
     }
 }
@@ -99,6 +805,7 @@ impl OptionalAuthUser {
 impl<S> FromRequestParts<S> for OptionalAuthUser
 where
     S: Send + Sync,
+    DatabaseConnection: FromRef<S>,
 {
     type Rejection = AppError;
 
@@ -109,3 +816,195 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_round_trip() {
+        let cursor = Cursor {
+            created_at: DateTime::from_timestamp_micros(1_700_000_000_123_456).unwrap(),
+            id: Uuid::new_v4(),
+        };
+        let encoded = cursor.encode();
+        assert_eq!(encoded.len(), 48);
+        assert_eq!(Cursor::decode(&encoded), Some(cursor));
+    }
+
+    #[test]
+    fn cursor_round_trip_before_epoch() {
+        let cursor = Cursor {
+            created_at: DateTime::from_timestamp_micros(-1).unwrap(),
+            id: Uuid::nil(),
+        };
+        assert_eq!(Cursor::decode(&cursor.encode()), Some(cursor));
+    }
+
+    #[test]
+    fn cursor_rejects_malformed_input() {
+        let valid = Cursor { created_at: Utc::now(), id: Uuid::new_v4() }.encode();
+
+        assert_eq!(Cursor::decode(""), None);
+        assert_eq!(Cursor::decode(&valid[..46]), None);
+        assert_eq!(Cursor::decode(&format!("{}00", valid)), None);
+        assert_eq!(Cursor::decode(&format!("zz{}", &valid[2..])), None);
+        assert_eq!(Cursor::decode(&format!("+f{}", &valid[2..])), None);
+        // 48 字节但含多字节字符
+        assert_eq!(Cursor::decode(&format!("é{}", &valid[2..])), None);
+    }
+
+    #[test]
+    fn cursor_rejects_out_of_range_timestamp() {
+        let encoded = format!("{:016x}{}", i64::MAX, "0".repeat(32));
+        assert_eq!(Cursor::decode(&encoded), None);
+    }
+
+    fn parts_with(name: header::HeaderName, values: &[&str]) -> Parts {
+        let mut builder = axum::http::Request::builder();
+        for value in values {
+            builder = builder.header(&name, *value);
+        }
+        builder.body(()).unwrap().into_parts().0
+    }
+
+    fn entity_tags(value: &str) -> EntityTags {
+        EntityTags::parse(&parts_with(header::IF_MATCH, &[value]), header::IF_MATCH)
+            .unwrap()
+            .unwrap()
+    }
+
+    #[test]
+    fn entity_tags_parse_lists_and_weak_tags() {
+        let tags = entity_tags(r#""1", W/"2" ,"3""#);
+        assert!(!tags.any);
+        assert_eq!(
+            tags.tags,
+            vec![(false, "1".to_string()), (true, "2".to_string()), (false, "3".to_string())]
+        );
+    }
+
+    #[test]
+    fn entity_tags_parse_multiple_headers_and_wildcard() {
+        let parts = parts_with(header::IF_NONE_MATCH, &[r#""1""#, "*"]);
+        let tags = EntityTags::parse(&parts, header::IF_NONE_MATCH).unwrap().unwrap();
+        assert!(tags.any);
+        assert_eq!(tags.tags, vec![(false, "1".to_string())]);
+    }
+
+    #[test]
+    fn entity_tags_missing_header_is_none() {
+        let parts = parts_with(header::IF_MATCH, &[]);
+        assert!(EntityTags::parse(&parts, header::IF_MATCH).unwrap().is_none());
+    }
+
+    #[test]
+    fn entity_tags_reject_unquoted_tags() {
+        for value in ["1", r#""1"#, r#"W/1"#, r#""1", 2"#] {
+            let parts = parts_with(header::IF_MATCH, &[value]);
+            assert!(
+                matches!(EntityTags::parse(&parts, header::IF_MATCH), Err(AppError::Validation(_))),
+                "{} 应该无效",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn entity_tags_strong_comparison_ignores_weak_tags() {
+        let tags = entity_tags(r#"W/"3", "4""#);
+        assert!(!tags.matches("3", true));
+        assert!(tags.matches("3", false));
+        assert!(tags.matches("4", true));
+        assert!(!tags.matches("5", false));
+        assert!(entity_tags("*").matches("5", true));
+    }
+
+    #[tokio::test]
+    async fn if_match_requires_header() {
+        let mut parts = parts_with(header::IF_MATCH, &[]);
+        let result = IfMatch::from_request_parts(&mut parts, &()).await;
+        assert!(matches!(result, Err(AppError::PreconditionRequired(_))));
+    }
+
+    #[tokio::test]
+    async fn if_match_uses_strong_comparison() {
+        let mut parts = parts_with(header::IF_MATCH, &[r#""7", W/"8""#]);
+        let if_match = IfMatch::from_request_parts(&mut parts, &()).await.unwrap();
+        assert!(if_match.matches(7));
+        assert!(!if_match.matches(8));
+    }
+
+    #[tokio::test]
+    async fn if_none_match_ignores_invalid_header() {
+        let mut parts = parts_with(header::IF_NONE_MATCH, &["not-a-tag"]);
+        let if_none_match = IfNoneMatch::from_request_parts(&mut parts, &()).await.unwrap();
+        assert!(!if_none_match.matches(1));
+
+        let mut parts = parts_with(header::IF_NONE_MATCH, &[r#"W/"abc""#]);
+        let if_none_match = IfNoneMatch::from_request_parts(&mut parts, &()).await.unwrap();
+        assert!(if_none_match.matches_tag("abc"));
+        assert!(!if_none_match.matches_tag("abd"));
+    }
+
+    fn languages(tags: &[&str]) -> PreferredLanguages {
+        PreferredLanguages(tags.iter().map(|tag| tag.to_string()).collect())
+    }
+
+    #[test]
+    fn best_match_prefers_exact_tag() {
+        let available = ["zh-CN", "en", "en-GB"];
+        assert_eq!(languages(&["en-GB"]).best_match(&available), Some("en-GB"));
+        assert_eq!(languages(&["zh-CN"]).best_match(&available), Some("zh-CN"));
+    }
+
+    #[test]
+    fn best_match_falls_back_to_primary_language() {
+        assert_eq!(languages(&["en-US"]).best_match(&["zh-CN", "en"]), Some("en"));
+        assert_eq!(languages(&["en"]).best_match(&["zh-CN", "en-GB"]), Some("en-GB"));
+    }
+
+    #[test]
+    fn best_match_follows_preference_order() {
+        // 靠前偏好的主语言匹配优先于靠后偏好的完全匹配
+        assert_eq!(languages(&["fr-CA", "en"]).best_match(&["en", "fr"]), Some("fr"));
+        assert_eq!(languages(&["de", "en"]).best_match(&["en", "fr"]), Some("en"));
+    }
+
+    #[test]
+    fn best_match_without_candidates() {
+        assert_eq!(languages(&["de"]).best_match(&["en", "fr"]), None);
+        assert_eq!(languages(&[]).best_match(&["en"]), None);
+        assert_eq!(languages(&["en"]).best_match(&[]), None);
+    }
+
+    #[test]
+    fn accept_language_sorted_by_quality() {
+        assert_eq!(
+            PreferredLanguages::parse_accept_language("en;q=0.5, zh-cn, ja;q=0.8, fr;q=0.8"),
+            vec!["zh-CN", "ja", "fr", "en"]
+        );
+    }
+
+    #[test]
+    fn accept_language_skips_wildcard_zero_quality_and_invalid_tags() {
+        assert_eq!(
+            PreferredLanguages::parse_accept_language("*, de;q=0, en;q=abc, 1x, fr; q=0.3"),
+            vec!["fr"]
+        );
+    }
+
+    #[tokio::test]
+    async fn lang_query_takes_precedence_over_accept_language() {
+        let mut parts = axum::http::Request::builder()
+            .uri("/api/articles?lang=ja,zh_tw")
+            .header(header::ACCEPT_LANGUAGE, "en-US,en;q=0.9")
+            .body(())
+            .unwrap()
+            .into_parts()
+            .0;
+        let PreferredLanguages(tags) =
+            PreferredLanguages::from_request_parts(&mut parts, &()).await.unwrap();
+        assert_eq!(tags, vec!["ja", "zh-TW", "en-US", "en"]);
+    }
+}
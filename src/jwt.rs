@@ -1,8 +1,9 @@
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, Utc};
 use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use crate::config::JwtConfig;
+use crate::entities::user::UserRole;
 use crate::errors::{AppError, Result};
 
 /// JWT Claims
@@ -10,17 +11,20 @@ use crate::errors::{AppError, Result};
 pub struct Claims {
     pub sub: Uuid, // 用户 ID
     pub username: String,
+    #[serde(default)]
+    pub role: UserRole, // 用户角色（旧 token 中没有该字段时视为普通用户）
     pub exp: i64,  // 过期时间
     pub iat: i64,  // 签发时间
 }
 
 impl Claims {
     /// 创建新的 Claims
-    pub fn new(user_id: Uuid, username: String, expiration_days: i64) -> Self {
+    pub fn new(user_id: Uuid, username: String, role: UserRole, expiration_days: i64) -> Self {
         let now = Utc::now();
         Claims {
             sub: user_id,
             username,
+            role,
             exp: (now + Duration::days(expiration_days)).timestamp(),
             iat: now.timestamp(),
         }
@@ -37,6 +41,60 @@ impl Claims {
     }
 }
 
+/// 分享链接令牌的 `typ`，与登录 token 区分
+const SHARE_TOKEN_TYPE: &str = "share";
+
+/// 文章分享链接令牌 Claims
+///
+/// 字段与登录 token 不同（没有 `sub` 和 `username`），两种令牌不能互相冒用
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ShareClaims {
+    pub typ: String,
+    pub sid: Uuid, // 分享链接 ID
+    pub aid: Uuid, // 文章 ID
+    pub exp: i64,
+    pub iat: i64,
+}
+
+impl ShareClaims {
+    /// 创建分享链接 Claims
+    pub fn new(share_id: Uuid, article_id: Uuid, expires_at: DateTime<Utc>) -> Self {
+        ShareClaims {
+            typ: SHARE_TOKEN_TYPE.to_string(),
+            sid: share_id,
+            aid: article_id,
+            exp: expires_at.timestamp(),
+            iat: Utc::now().timestamp(),
+        }
+    }
+
+    /// 生成分享链接令牌
+    pub fn to_token(&self, secret: &str) -> Result<String> {
+        encode(
+            &Header::default(),
+            self,
+            &EncodingKey::from_secret(secret.as_ref()),
+        )
+        .map_err(|e| AppError::Jwt(format!("生成分享令牌失败: {}", e)))
+    }
+}
+
+/// 验证分享链接令牌（签名、过期时间和令牌类型）
+pub fn verify_share_token(token: &str, secret: &str) -> Result<ShareClaims> {
+    let claims = decode::<ShareClaims>(
+        token,
+        &DecodingKey::from_secret(secret.as_ref()),
+        &Validation::default(),
+    )
+    .map_err(|e| AppError::Jwt(format!("验证分享令牌失败: {}", e)))?
+    .claims;
+
+    if claims.typ != SHARE_TOKEN_TYPE {
+        return Err(AppError::Jwt("不是分享令牌".to_string()));
+    }
+    Ok(claims)
+}
+
 /// 验证 token
 pub fn verify_token(token: &str) -> Result<Claims> {
     // 这里应该从配置中获取 secret，为了简化示例，使用硬编码
@@ -55,8 +113,13 @@ pub fn verify_token(token: &str) -> Result<Claims> {
 }
 
 /// 从配置生成 token
-pub fn generate_token(user_id: Uuid, username: String, config: &JwtConfig) -> Result<String> {
-    let claims = Claims::new(user_id, username, config.expiration_days);
+pub fn generate_token(
+    user_id: Uuid,
+    username: String,
+    role: UserRole,
+    config: &JwtConfig,
+) -> Result<String> {
+    let claims = Claims::new(user_id, username, role, config.expiration_days);
     claims.to_token(&config.secret)
 }
 
@@ -1,5 +1,6 @@
 // 库模块导出
 pub mod config;
+pub mod content_filter;
 pub mod controllers;
 pub mod database;
 pub mod entities;
@@ -9,21 +10,35 @@ pub mod jwt;
 pub mod logging;
 pub mod middleware;
 pub mod models;
+pub mod render;
 pub mod repositories;
 pub mod response;
 pub mod routes;
 pub mod services;
+pub mod sitemap;
+pub mod storage;
+pub mod trash;
 pub mod utils;
+pub mod view_counter;
 
 use crate::config::Config;
+use crate::content_filter::ContentFilterPipeline;
+use crate::sitemap::SitemapCache;
+use crate::storage::Storage;
+use crate::view_counter::ViewCounter;
 use axum::extract::FromRef;
 use sea_orm::DatabaseConnection;
+use std::sync::Arc;
 
 /// 应用状态（共享状态）
 #[derive(Clone)]
 pub struct AppState {
     pub db: DatabaseConnection,
     pub config: Config,
+    pub storage: Arc<dyn Storage>,
+    pub view_counter: Arc<ViewCounter>,
+    pub sitemap: Arc<SitemapCache>,
+    pub content_filter: Arc<ContentFilterPipeline>,
 }
 
 impl FromRef<AppState> for DatabaseConnection {
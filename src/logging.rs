@@ -28,7 +28,7 @@ impl tracing_subscriber::fmt::time::FormatTime for ChinaTimer {
 /// 
 /// # 使用示例
 /// 
-/// ```rust
+/// ```rust,ignore
 /// // 在 main 函数开始处调用
 /// init_logging();
 /// 
@@ -1,6 +1,10 @@
 use axum_demo::{
-    config::Config, database::create_connection, logging, routes::create_router, AppState,
+    config::Config, content_filter::create_content_filter, database::create_connection, logging,
+    routes::create_router, sitemap::SitemapCache, storage::create_storage, trash,
+    view_counter::ViewCounter, AppState,
 };
+use std::net::SocketAddr;
+use std::sync::Arc;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -15,10 +19,36 @@ async fn main() -> anyhow::Result<()> {
     let db = create_connection(&config.database).await?;
     tracing::info!("数据库连接创建成功");
 
+    // 创建文件存储后端
+    let storage = create_storage(&config.storage).await?;
+    tracing::info!("文件存储初始化成功: {:?}", config.storage.backend);
+
+    // 创建浏览量计数器并启动定期写入任务
+    let view_counter = Arc::new(ViewCounter::new(&config.views));
+    view_counter
+        .clone()
+        .spawn_flush_task(db.clone(), config.views.flush_interval);
+
+    // 创建站点地图缓存并启动定期生成任务
+    let sitemap = Arc::new(SitemapCache::new(config.server.public_url.clone()));
+    sitemap
+        .clone()
+        .spawn_refresh_task(db.clone(), config.sitemap.refresh_interval);
+
+    // 启动回收站定期清理任务
+    trash::spawn_purge_task(db.clone(), storage.clone(), config.trash.clone());
+
+    // 创建内容过滤管道
+    let content_filter = create_content_filter(&config.content_filter);
+
     // 创建应用状态
     let state = AppState {
-        db,
+        db: db.clone(),
         config: config.clone(),
+        storage,
+        view_counter: view_counter.clone(),
+        sitemap,
+        content_filter,
     };
 
     // 创建路由
@@ -35,7 +65,24 @@ async fn main() -> anyhow::Result<()> {
         config.server.port
     );
 
-    axum::serve(listener, app).await?;
+    // 需要客户端地址用于匿名访客的浏览量去重
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .with_graceful_shutdown(shutdown_signal())
+    .await?;
+
+    // 退出前写入内存中尚未落库的浏览量
+    view_counter.flush(&db).await;
+    tracing::info!("服务器已停止");
 
     Ok(())
 }
+
+/// 等待 Ctrl+C 信号
+async fn shutdown_signal() {
+    if let Err(e) = tokio::signal::ctrl_c().await {
+        tracing::error!("监听退出信号失败: {}", e);
+    }
+}
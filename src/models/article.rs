@@ -1,4 +1,7 @@
-use crate::entities::article::Model as ArticleEntity;
+use crate::entities::article::{ContentFormat, Model as ArticleEntity, ReviewStatus};
+use crate::extractors::{FilterKind, ListField, Sort};
+use crate::models::{ArticleEngagement, ArticleSeriesInfo, Localization};
+use crate::render::{content_stats, render_content};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -8,8 +11,49 @@ use uuid::Uuid;
 pub struct ArticleResponse {
     pub id: Uuid,
     pub title: String,
-    pub content: String,
+    /// 正文：`body_format` 为 `raw` 时是原文，为 `html` 时是渲染并清洗后的 HTML；
+    /// 列表接口默认不返回（`include_content=true` 时返回）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// 原文的声明格式
+    pub content_format: ContentFormat,
+    /// `content` 字段的实际格式
+    pub body_format: BodyFormat,
+    /// 纯文本摘要
+    pub excerpt: String,
+    /// 字数（中日韩文字按字计，其他文字按词计）
+    pub word_count: i32,
+    /// 预计阅读时间（分钟）
+    pub reading_minutes: i32,
+    pub category_id: Option<Uuid>,
+    pub view_count: i64,
     pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+    /// 版本号，与响应头中的 ETag 一致
+    pub version: i32,
+    /// 互动统计（表情反应、收藏）
+    pub engagement: ArticleEngagement,
+    /// 所在系列及上一篇、下一篇（仅获取单篇文章时返回）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub series: Option<ArticleSeriesInfo>,
+    /// `title`、`content` 的语言
+    pub language: String,
+    /// 原文的语言
+    pub source_language: String,
+    /// 可用的语言：原文和已发布的译文（获取文章和文章列表时返回）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub languages: Vec<String>,
+    /// 译文的 slug（返回译文时才有）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    /// 置顶位置（从 1 开始，仅置顶有效时返回）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pinned_position: Option<i32>,
+    /// 置顶到期时间（仅置顶有效且设置了到期时间时返回）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pinned_until: Option<DateTime<Utc>>,
+    /// 审核状态
+    pub review_status: ReviewStatus,
 }
 
 /// 创建文章请求
@@ -17,16 +61,176 @@ pub struct ArticleResponse {
 pub struct CreateArticleRequest {
     pub title: String,
     pub content: String,
+    /// 正文格式，创建时缺省为 Markdown，更新时缺省保持不变
+    pub content_format: Option<ContentFormat>,
     pub is_public: Option<bool>,
+    pub category_id: Option<Uuid>,
+    /// 原文的语言（BCP 47 语言标签），创建时缺省为 `zh`，更新时缺省保持不变
+    pub language: Option<String>,
 }
 
-impl From<ArticleEntity> for ArticleResponse {
-    fn from(article: ArticleEntity) -> Self {
+/// 文章列表查询参数（分页参数之外的过滤条件）
+#[derive(Debug, Default, Deserialize)]
+pub struct ArticleListQuery {
+    /// 按分类过滤
+    pub category_id: Option<Uuid>,
+    /// 是否包含子孙分类下的文章
+    #[serde(default)]
+    pub include_descendants: bool,
+}
+
+/// 文章列表可过滤、可排序的字段
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArticleField {
+    AuthorId,
+    Title,
+    CreatedAt,
+    UpdatedAt,
+    ViewCount,
+}
+
+impl ListField for ArticleField {
+    const FILTERS: &'static [(&'static str, Self, FilterKind)] = &[
+        ("author_id", ArticleField::AuthorId, FilterKind::Equals),
+        ("created_after", ArticleField::CreatedAt, FilterKind::After),
+        ("created_before", ArticleField::CreatedAt, FilterKind::Before),
+        ("updated_after", ArticleField::UpdatedAt, FilterKind::After),
+        ("title_contains", ArticleField::Title, FilterKind::Contains),
+    ];
+    const SORTS: &'static [(&'static str, Self)] = &[
+        ("created_at", ArticleField::CreatedAt),
+        ("updated_at", ArticleField::UpdatedAt),
+        ("title", ArticleField::Title),
+        ("view_count", ArticleField::ViewCount),
+    ];
+    const DEFAULT_SORT: &'static [Sort<Self>] = &[Sort {
+        field: ArticleField::CreatedAt,
+        descending: true,
+    }];
+}
+
+/// 正文返回格式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BodyFormat {
+    /// 原文
+    #[default]
+    Raw,
+    /// 渲染并清洗后的 HTML
+    Html,
+}
+
+/// 正文格式查询参数（`?format=raw|html&include_content=true`）
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+pub struct BodyFormatQuery {
+    #[serde(default)]
+    pub format: BodyFormat,
+    /// 列表接口是否返回完整正文（缺省只返回摘要）
+    #[serde(default)]
+    pub include_content: bool,
+}
+
+impl ArticleResponse {
+    /// 按指定的正文格式构建响应
+    ///
+    /// 优先使用写入时缓存的 HTML，缓存缺失时（历史数据）即时渲染
+    pub fn with_format(mut article: ArticleEntity, format: BodyFormat) -> Self {
+        let content = match format {
+            BodyFormat::Raw => article.content.clone(),
+            BodyFormat::Html => article
+                .content_html
+                .take()
+                .unwrap_or_else(|| render_content(&article.content, article.content_format)),
+        };
+
+        let mut response = ArticleResponse::summary(article);
+        response.content = Some(content);
+        response.body_format = format;
+        response
+    }
+
+    /// 构建不含正文的响应（列表接口使用）
+    ///
+    /// 摘要、字数和阅读时间优先使用写入时保存的值，缺失时（历史数据）即时计算
+    pub fn summary(article: ArticleEntity) -> Self {
+        let pinned = article.is_pinned_at(Utc::now());
+        let (excerpt, word_count, reading_minutes) =
+            match (article.excerpt, article.word_count, article.reading_minutes) {
+                (Some(excerpt), Some(word_count), Some(reading_minutes)) => {
+                    (excerpt, word_count, reading_minutes)
+                }
+                _ => {
+                    let stats = content_stats(&article.content, article.content_format);
+                    (stats.excerpt, stats.word_count, stats.reading_minutes)
+                }
+            };
+
         ArticleResponse {
             id: article.id,
             title: article.title,
-            content: article.content,
+            content: None,
+            content_format: article.content_format,
+            body_format: BodyFormat::Raw,
+            excerpt,
+            word_count,
+            reading_minutes,
+            category_id: article.category_id,
+            view_count: article.view_count,
             created_at: article.created_at,
+            updated_at: article.updated_at,
+            version: article.version,
+            engagement: ArticleEngagement::default(),
+            series: None,
+            language: article.language.clone(),
+            source_language: article.language,
+            languages: Vec::new(),
+            slug: None,
+            pinned_position: article.pinned_position.filter(|_| pinned),
+            pinned_until: article.pinned_until.filter(|_| pinned),
+            review_status: article.review_status,
         }
     }
+
+    /// 填充读取时选用的语言
+    pub fn localized(mut self, localization: Localization) -> Self {
+        self.language = localization.language;
+        self.languages = localization.languages;
+        self.slug = localization.slug;
+        self
+    }
+}
+
+impl From<ArticleEntity> for ArticleResponse {
+    fn from(article: ArticleEntity) -> Self {
+        ArticleResponse::with_format(article, BodyFormat::Raw)
+    }
+}
+
+/// 置顶文章请求
+#[derive(Debug, Deserialize)]
+pub struct PinArticleRequest {
+    /// 置顶位置（从 1 开始），缺省时排在已置顶文章之后；超出范围时排在最后
+    pub position: Option<u32>,
+    /// 置顶到期时间，缺省为长期置顶
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+/// 热门文章查询参数
+#[derive(Debug, Default, Deserialize)]
+pub struct TrendingQuery {
+    /// 统计时间窗口，如 `1h`、`24h`、`7d`，缺省为 `24h`
+    pub window: Option<String>,
+    /// 返回数量，缺省为 20，最大 100
+    pub limit: Option<u64>,
+}
+
+/// 热门文章
+#[derive(Debug, Serialize)]
+pub struct TrendingArticleResponse {
+    #[serde(flatten)]
+    pub article: ArticleResponse,
+    /// 热度得分（越近的浏览权重越高）
+    pub score: f64,
+    /// 时间窗口内的浏览量
+    pub window_views: i64,
 }
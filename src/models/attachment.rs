@@ -0,0 +1,31 @@
+use crate::entities::attachment::Model as AttachmentEntity;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+/// 附件响应
+#[derive(Debug, Serialize)]
+pub struct AttachmentResponse {
+    pub id: Uuid,
+    pub article_id: Uuid,
+    pub filename: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    /// 下载地址
+    pub url: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<AttachmentEntity> for AttachmentResponse {
+    fn from(attachment: AttachmentEntity) -> Self {
+        AttachmentResponse {
+            url: format!("/files/attachments/{}", attachment.id),
+            id: attachment.id,
+            article_id: attachment.article_id,
+            filename: attachment.filename,
+            content_type: attachment.content_type,
+            size_bytes: attachment.size_bytes,
+            created_at: attachment.created_at,
+        }
+    }
+}
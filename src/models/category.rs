@@ -0,0 +1,68 @@
+use crate::entities::category::Model as CategoryEntity;
+use crate::utils::deserialize_some;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// 分类响应
+#[derive(Debug, Serialize)]
+pub struct CategoryResponse {
+    pub id: Uuid,
+    pub parent_id: Option<Uuid>,
+    pub name: String,
+    pub slug: String,
+    pub depth: i32,
+    pub sort_order: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 分类树节点
+#[derive(Debug, Serialize)]
+pub struct CategoryTreeNode {
+    #[serde(flatten)]
+    pub category: CategoryResponse,
+    pub children: Vec<CategoryTreeNode>,
+}
+
+/// 创建分类请求
+#[derive(Debug, Deserialize)]
+pub struct CreateCategoryRequest {
+    pub name: String,
+    pub slug: String,
+    pub parent_id: Option<Uuid>,
+    pub sort_order: Option<i32>,
+}
+
+/// 更新分类请求
+///
+/// `parent_id` 缺省表示不修改，显式传 `null` 表示移动到根节点
+#[derive(Debug, Deserialize)]
+pub struct UpdateCategoryRequest {
+    pub name: Option<String>,
+    pub slug: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_some")]
+    pub parent_id: Option<Option<Uuid>>,
+    pub sort_order: Option<i32>,
+}
+
+/// 设置文章分类请求（`category_id` 为 `null` 表示移出分类）
+#[derive(Debug, Deserialize)]
+pub struct AssignCategoryRequest {
+    pub category_id: Option<Uuid>,
+}
+
+impl From<CategoryEntity> for CategoryResponse {
+    fn from(category: CategoryEntity) -> Self {
+        CategoryResponse {
+            id: category.id,
+            parent_id: category.parent_id,
+            name: category.name,
+            slug: category.slug,
+            depth: category.depth,
+            sort_order: category.sort_order,
+            created_at: category.created_at,
+            updated_at: category.updated_at,
+        }
+    }
+}
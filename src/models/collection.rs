@@ -0,0 +1,49 @@
+use crate::entities::collection::Model as CollectionEntity;
+use crate::utils::deserialize_some;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// 收藏夹响应
+#[derive(Debug, Serialize)]
+pub struct CollectionResponse {
+    pub id: Uuid,
+    pub owner_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub is_public: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 创建收藏夹请求（缺省为私有）
+#[derive(Debug, Deserialize)]
+pub struct CreateCollectionRequest {
+    pub name: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub is_public: bool,
+}
+
+/// 更新收藏夹请求（`description` 显式传 `null` 表示清空）
+#[derive(Debug, Deserialize)]
+pub struct UpdateCollectionRequest {
+    pub name: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_some")]
+    pub description: Option<Option<String>>,
+    pub is_public: Option<bool>,
+}
+
+impl From<CollectionEntity> for CollectionResponse {
+    fn from(collection: CollectionEntity) -> Self {
+        CollectionResponse {
+            id: collection.id,
+            owner_id: collection.user_id,
+            name: collection.name,
+            description: collection.description,
+            is_public: collection.is_public,
+            created_at: collection.created_at,
+            updated_at: collection.updated_at,
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crate::entities::comment::Model as CommentEntity;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// 评论响应（树形结构）
+///
+/// 已删除的评论保留在树中作为占位，`content` 为空、`user_id` 为 `None`
+#[derive(Debug, Serialize)]
+pub struct CommentResponse {
+    pub id: Uuid,
+    pub article_id: Uuid,
+    pub parent_id: Option<Uuid>,
+    pub user_id: Option<Uuid>,
+    pub content: String,
+    pub depth: i32,
+    pub is_deleted: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub children: Vec<CommentResponse>,
+}
+
+/// 发表评论请求
+#[derive(Debug, Deserialize)]
+pub struct CreateCommentRequest {
+    pub content: String,
+    /// 回复的评论 ID，缺省表示直接评论文章
+    pub parent_id: Option<Uuid>,
+}
+
+/// 编辑评论请求
+#[derive(Debug, Deserialize)]
+pub struct UpdateCommentRequest {
+    pub content: String,
+}
+
+impl From<CommentEntity> for CommentResponse {
+    fn from(comment: CommentEntity) -> Self {
+        let (user_id, content) = if comment.is_deleted {
+            (None, String::new())
+        } else {
+            (comment.user_id, comment.content)
+        };
+
+        CommentResponse {
+            id: comment.id,
+            article_id: comment.article_id,
+            parent_id: comment.parent_id,
+            user_id,
+            content,
+            depth: comment.depth,
+            is_deleted: comment.is_deleted,
+            created_at: comment.created_at,
+            updated_at: comment.updated_at,
+            children: Vec::new(),
+        }
+    }
+}
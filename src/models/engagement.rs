@@ -0,0 +1,28 @@
+use crate::entities::reaction::ReactionKind;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// 文章互动统计
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ArticleEngagement {
+    /// 各类表情反应的数量
+    pub reactions: BTreeMap<ReactionKind, u64>,
+    /// 收藏数
+    pub bookmarks: u64,
+    /// 当前用户的互动状态（仅登录时返回）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub viewer: Option<ViewerEngagement>,
+}
+
+/// 当前用户对文章的互动状态
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ViewerEngagement {
+    pub reaction: Option<ReactionKind>,
+    pub bookmarked: bool,
+}
+
+/// 设置表情反应请求
+#[derive(Debug, Deserialize)]
+pub struct SetReactionRequest {
+    pub reaction: ReactionKind,
+}
@@ -1,7 +1,35 @@
 // 模型模块导出
 pub mod user;
 pub mod article;
+pub mod category;
+pub mod comment;
+pub mod engagement;
+pub mod search;
+pub mod revision;
+pub mod attachment;
+pub mod transfer;
+pub mod series;
+pub mod collection;
+pub mod moderation;
+pub mod translation;
+pub mod share;
+pub mod trash;
+pub mod review;
 
 pub use user::*;
 pub use article::*;
+pub use category::*;
+pub use comment::*;
+pub use engagement::*;
+pub use search::*;
+pub use revision::*;
+pub use attachment::*;
+pub use transfer::*;
+pub use series::*;
+pub use collection::*;
+pub use moderation::*;
+pub use translation::*;
+pub use share::*;
+pub use trash::*;
+pub use review::*;
 
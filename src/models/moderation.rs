@@ -0,0 +1,78 @@
+use crate::entities::content_report::{ReportReason, ReportTarget};
+use crate::entities::moderation_action::{ModerationActionKind, Model as ModerationActionEntity};
+use crate::models::{ArticleResponse, CommentResponse};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+/// 举报内容请求（原因为 `other` 时必须填写说明）
+#[derive(Debug, Deserialize)]
+pub struct CreateReportRequest {
+    pub reason: ReportReason,
+    pub details: Option<String>,
+}
+
+/// 待处理队列中的一项（同一内容的所有待处理举报合并为一项）
+#[derive(Debug, Serialize)]
+pub struct ModerationQueueItem {
+    pub target_type: ReportTarget,
+    pub target_id: Uuid,
+    /// 待处理举报数
+    pub report_count: u64,
+    /// 各举报原因的数量
+    pub reasons: BTreeMap<ReportReason, u64>,
+    pub first_reported_at: DateTime<Utc>,
+    pub last_reported_at: DateTime<Utc>,
+    /// 被举报的文章摘要（文章已被隐藏或删除时为空）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub article: Option<ArticleResponse>,
+    /// 被举报的评论（评论已被删除时为空）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<CommentResponse>,
+}
+
+/// 执行管理操作的请求（警告作者时必须填写说明）
+#[derive(Debug, Deserialize)]
+pub struct ModerateContentRequest {
+    pub action: ModerationActionKind,
+    pub note: Option<String>,
+}
+
+/// 管理操作记录查询参数
+#[derive(Debug, Default, Deserialize)]
+pub struct ModerationActionQuery {
+    /// 只返回针对该内容的操作
+    pub target_id: Option<Uuid>,
+    /// 只返回针对该用户内容的操作（例如查看作者收到的警告）
+    pub user_id: Option<Uuid>,
+}
+
+/// 管理操作记录响应
+#[derive(Debug, Serialize)]
+pub struct ModerationActionResponse {
+    pub id: Uuid,
+    pub target_type: ReportTarget,
+    pub target_id: Uuid,
+    pub target_user_id: Option<Uuid>,
+    /// 执行操作的管理员（管理员被彻底删除后为空）
+    pub moderator_id: Option<Uuid>,
+    pub action: ModerationActionKind,
+    pub note: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ModerationActionEntity> for ModerationActionResponse {
+    fn from(action: ModerationActionEntity) -> Self {
+        ModerationActionResponse {
+            id: action.id,
+            target_type: action.target_type,
+            target_id: action.target_id,
+            target_user_id: action.target_user_id,
+            moderator_id: action.moderator_id,
+            action: action.action,
+            note: action.note,
+            created_at: action.created_at,
+        }
+    }
+}
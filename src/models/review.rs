@@ -0,0 +1,54 @@
+use crate::entities::article::ReviewStatus;
+use crate::entities::article_review_event::Model as ReviewEventEntity;
+use crate::models::ArticleResponse;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// 审核操作请求（提交、通过、退回修改）
+#[derive(Debug, Default, Deserialize)]
+pub struct ReviewActionRequest {
+    /// 提交说明或审核意见，退回修改时必填
+    pub comment: Option<String>,
+}
+
+/// 审核队列查询参数
+#[derive(Debug, Default, Deserialize)]
+pub struct ReviewQueueQuery {
+    /// 审核状态，缺省为 `submitted`（等待审核）
+    pub status: Option<ReviewStatus>,
+}
+
+/// 审核状态变更记录
+#[derive(Debug, Serialize)]
+pub struct ReviewEventResponse {
+    pub id: Uuid,
+    /// 执行变更的用户（用户被彻底删除后为空）
+    pub actor_id: Option<Uuid>,
+    pub from_status: ReviewStatus,
+    pub to_status: ReviewStatus,
+    pub comment: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ReviewEventEntity> for ReviewEventResponse {
+    fn from(event: ReviewEventEntity) -> Self {
+        ReviewEventResponse {
+            id: event.id,
+            actor_id: event.actor_id,
+            from_status: event.from_status,
+            to_status: event.to_status,
+            comment: event.comment,
+            created_at: event.created_at,
+        }
+    }
+}
+
+/// 文章审核详情：文章原文、当前状态和全部变更记录
+#[derive(Debug, Serialize)]
+pub struct ArticleReviewResponse {
+    pub article: ArticleResponse,
+    pub author_id: Option<Uuid>,
+    pub status: ReviewStatus,
+    pub events: Vec<ReviewEventResponse>,
+}
@@ -0,0 +1,96 @@
+use crate::entities::article::ContentFormat;
+use crate::entities::article_revision::Model as RevisionEntity;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// 修订摘要（列表使用，不含正文）
+#[derive(Debug, Serialize)]
+pub struct RevisionSummary {
+    pub revision_number: i32,
+    pub title: String,
+    /// 产生该修订的用户（用户被彻底删除后为空）
+    pub author_id: Option<Uuid>,
+    pub restored_from: Option<i32>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// 修订详情
+#[derive(Debug, Serialize)]
+pub struct RevisionResponse {
+    pub article_id: Uuid,
+    pub revision_number: i32,
+    pub title: String,
+    pub content: String,
+    pub content_format: ContentFormat,
+    /// 产生该修订的用户（用户被彻底删除后为空）
+    pub author_id: Option<Uuid>,
+    pub restored_from: Option<i32>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// 修订对比查询参数
+#[derive(Debug, Deserialize)]
+pub struct RevisionDiffQuery {
+    pub from: i32,
+    pub to: i32,
+}
+
+/// 修订对比结果
+#[derive(Debug, Serialize)]
+pub struct RevisionDiffResponse {
+    pub from: i32,
+    pub to: i32,
+    /// 标题变化（标题未修改时为 `None`）
+    pub title: Option<TitleChange>,
+    /// 逐行差异
+    pub changes: Vec<DiffLine>,
+    /// unified diff 格式文本
+    pub unified: String,
+}
+
+/// 标题变化
+#[derive(Debug, Serialize)]
+pub struct TitleChange {
+    pub old: String,
+    pub new: String,
+}
+
+/// 单行差异
+#[derive(Debug, Serialize)]
+pub struct DiffLine {
+    /// `equal` / `insert` / `delete`
+    pub tag: &'static str,
+    /// 旧版本中的行号（从 1 开始）
+    pub old_line: Option<usize>,
+    /// 新版本中的行号（从 1 开始）
+    pub new_line: Option<usize>,
+    pub content: String,
+}
+
+impl From<RevisionEntity> for RevisionSummary {
+    fn from(revision: RevisionEntity) -> Self {
+        RevisionSummary {
+            revision_number: revision.revision_number,
+            title: revision.title,
+            author_id: revision.author_id,
+            restored_from: revision.restored_from,
+            created_at: revision.created_at,
+        }
+    }
+}
+
+impl From<RevisionEntity> for RevisionResponse {
+    fn from(revision: RevisionEntity) -> Self {
+        RevisionResponse {
+            article_id: revision.article_id,
+            revision_number: revision.revision_number,
+            title: revision.title,
+            content: revision.content,
+            content_format: revision.content_format,
+            author_id: revision.author_id,
+            restored_from: revision.restored_from,
+            created_at: revision.created_at,
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// 搜索查询参数
+#[derive(Debug, Deserialize)]
+pub struct SearchQuery {
+    /// 搜索关键词，多个关键词用空格分隔
+    pub q: String,
+}
+
+/// 文章搜索结果
+///
+/// `title_highlight` 和 `snippet` 为已转义的 HTML 片段，命中部分使用 `<mark>` 包裹
+#[derive(Debug, Serialize)]
+pub struct ArticleSearchHit {
+    pub id: Uuid,
+    pub title: String,
+    pub title_highlight: String,
+    pub snippet: String,
+    pub user_id: Option<Uuid>,
+    pub category_id: Option<Uuid>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub relevance: f64,
+}
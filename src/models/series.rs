@@ -0,0 +1,97 @@
+use crate::entities::series::Model as SeriesEntity;
+use crate::utils::deserialize_some;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// 系列响应
+#[derive(Debug, Serialize)]
+pub struct SeriesResponse {
+    pub id: Uuid,
+    pub author_id: Uuid,
+    pub title: String,
+    pub description: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 系列详情（包含当前用户可读的成员文章）
+#[derive(Debug, Serialize)]
+pub struct SeriesDetailResponse {
+    #[serde(flatten)]
+    pub series: SeriesResponse,
+    pub articles: Vec<SeriesArticleItem>,
+}
+
+/// 系列中的一篇文章
+#[derive(Debug, Serialize)]
+pub struct SeriesArticleItem {
+    pub id: Uuid,
+    pub title: String,
+    pub position: i32,
+}
+
+/// 文章所在系列的信息（仅获取单篇文章时返回）
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ArticleSeriesInfo {
+    pub id: Uuid,
+    pub title: String,
+    pub position: i32,
+    /// 系列中当前用户可读的文章数
+    pub total: usize,
+    /// 上一篇（跳过当前用户不可读的文章）
+    pub prev: Option<SeriesNeighbor>,
+    /// 下一篇（跳过当前用户不可读的文章）
+    pub next: Option<SeriesNeighbor>,
+}
+
+/// 系列中相邻的文章
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SeriesNeighbor {
+    pub id: Uuid,
+    pub title: String,
+    pub url: String,
+}
+
+/// 创建系列请求
+#[derive(Debug, Deserialize)]
+pub struct CreateSeriesRequest {
+    pub title: String,
+    pub description: Option<String>,
+}
+
+/// 更新系列请求（`description` 显式传 `null` 表示清空）
+#[derive(Debug, Deserialize)]
+pub struct UpdateSeriesRequest {
+    pub title: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_some")]
+    pub description: Option<Option<String>>,
+}
+
+/// 调整系列文章顺序请求（必须包含系列中的全部文章）
+#[derive(Debug, Deserialize)]
+pub struct ReorderSeriesRequest {
+    pub article_ids: Vec<Uuid>,
+}
+
+/// 设置文章所属系列请求
+///
+/// `series_id` 为 `null` 表示移出系列；`position` 从 1 开始，缺省时追加到末尾
+#[derive(Debug, Deserialize)]
+pub struct AssignSeriesRequest {
+    pub series_id: Option<Uuid>,
+    pub position: Option<u32>,
+}
+
+impl From<SeriesEntity> for SeriesResponse {
+    fn from(series: SeriesEntity) -> Self {
+        SeriesResponse {
+            id: series.id,
+            author_id: series.user_id,
+            title: series.title,
+            description: series.description,
+            created_at: series.created_at,
+            updated_at: series.updated_at,
+        }
+    }
+}
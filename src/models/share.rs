@@ -0,0 +1,58 @@
+use crate::entities::article_share_link::Model as ShareLinkEntity;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// 创建分享链接请求
+#[derive(Debug, Default, Deserialize)]
+pub struct CreateShareLinkRequest {
+    /// 有效期（小时），缺省和上限由配置决定
+    pub expires_in_hours: Option<u64>,
+    /// 备注（例如审稿人姓名）
+    pub label: Option<String>,
+}
+
+/// 分享链接响应
+#[derive(Debug, Serialize)]
+pub struct ShareLinkResponse {
+    pub id: Uuid,
+    pub article_id: Uuid,
+    pub label: Option<String>,
+    pub expires_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    /// 未撤销且未过期
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub use_count: i32,
+    /// 分享令牌（仅创建时返回）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    /// 带令牌的文章地址（仅创建时返回）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// 读取文章时的分享令牌参数（`?share=...`）
+#[derive(Debug, Default, Deserialize)]
+pub struct ShareQuery {
+    pub share: Option<String>,
+}
+
+impl From<ShareLinkEntity> for ShareLinkResponse {
+    fn from(link: ShareLinkEntity) -> Self {
+        ShareLinkResponse {
+            id: link.id,
+            article_id: link.article_id,
+            is_active: link.is_active_at(Utc::now()),
+            label: link.label,
+            expires_at: link.expires_at,
+            revoked_at: link.revoked_at,
+            created_at: link.created_at,
+            last_used_at: link.last_used_at,
+            use_count: link.use_count,
+            token: None,
+            url: None,
+        }
+    }
+}
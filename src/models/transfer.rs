@@ -0,0 +1,131 @@
+use crate::entities::article::{ContentFormat, Model as ArticleEntity};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// 文章导入导出格式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TransferFormat {
+    /// JSON Lines，每行一篇文章
+    #[default]
+    Jsonl,
+    /// CSV，首行为表头
+    Csv,
+    /// Markdown，每篇文章以 YAML front matter 开头
+    Markdown,
+}
+
+impl TransferFormat {
+    /// 根据文件扩展名推断格式
+    pub fn from_filename(filename: &str) -> Option<Self> {
+        let (_, extension) = filename.rsplit_once('.')?;
+        match extension.to_ascii_lowercase().as_str() {
+            "jsonl" | "ndjson" => Some(TransferFormat::Jsonl),
+            "csv" => Some(TransferFormat::Csv),
+            "md" | "markdown" => Some(TransferFormat::Markdown),
+            _ => None,
+        }
+    }
+
+    pub fn content_type(self) -> &'static str {
+        match self {
+            TransferFormat::Jsonl => "application/x-ndjson; charset=utf-8",
+            TransferFormat::Csv => "text/csv; charset=utf-8",
+            TransferFormat::Markdown => "text/markdown; charset=utf-8",
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            TransferFormat::Jsonl => "jsonl",
+            TransferFormat::Csv => "csv",
+            TransferFormat::Markdown => "md",
+        }
+    }
+}
+
+/// 导入参数
+#[derive(Debug, Default, Deserialize)]
+pub struct ImportQuery {
+    /// 文件格式，缺省时按文件扩展名推断
+    pub format: Option<TransferFormat>,
+    /// 只校验不写入
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// 导出参数
+#[derive(Debug, Default, Deserialize)]
+pub struct ExportQuery {
+    #[serde(default)]
+    pub format: TransferFormat,
+}
+
+/// 导入导出的单篇文章
+///
+/// 导出时包含全部字段；导入时忽略 `id` 和 `author_id`（总是创建归属当前用户的新文章），
+/// `created_at`、`updated_at` 缺省为导入时间
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArticleRecord {
+    #[serde(default)]
+    pub id: Option<Uuid>,
+    pub title: String,
+    #[serde(default)]
+    pub content: String,
+    #[serde(default)]
+    pub content_format: Option<ContentFormat>,
+    #[serde(default)]
+    pub is_public: Option<bool>,
+    #[serde(default)]
+    pub category_id: Option<Uuid>,
+    #[serde(default)]
+    pub author_id: Option<Uuid>,
+    #[serde(default)]
+    pub created_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<ArticleEntity> for ArticleRecord {
+    fn from(article: ArticleEntity) -> Self {
+        Self {
+            id: Some(article.id),
+            title: article.title,
+            content: article.content,
+            content_format: Some(article.content_format),
+            is_public: article.is_public,
+            category_id: article.category_id,
+            author_id: article.user_id,
+            created_at: article.created_at,
+            updated_at: article.updated_at,
+        }
+    }
+}
+
+/// 导入失败的一行
+#[derive(Debug, Serialize)]
+pub struct ImportRowError {
+    /// 上传的文件名
+    pub file: Option<String>,
+    /// 所在行号（从 1 开始；Markdown 为该篇文章 front matter 的起始行）
+    pub line: usize,
+    pub message: String,
+}
+
+/// 导入结果
+#[derive(Debug, Serialize)]
+pub struct ImportReport {
+    pub dry_run: bool,
+    /// 解析出的文章数
+    pub total: usize,
+    /// 通过校验的文章数
+    pub valid: usize,
+    /// 通过校验但需要管理员审核的文章数
+    pub flagged: usize,
+    /// 实际写入的文章数（试运行或存在错误时为 0）
+    pub imported: usize,
+    /// 新文章的 ID（与输入顺序一致）
+    pub ids: Vec<Uuid>,
+    pub errors: Vec<ImportRowError>,
+}
@@ -0,0 +1,61 @@
+use crate::entities::article::ContentFormat;
+use crate::entities::article_translation::Model as TranslationEntity;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// 译文响应
+#[derive(Debug, Serialize)]
+pub struct TranslationResponse {
+    pub id: Uuid,
+    pub article_id: Uuid,
+    pub language: String,
+    pub title: String,
+    pub slug: String,
+    pub content: String,
+    pub content_format: ContentFormat,
+    pub is_published: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 创建或更新译文请求
+///
+/// `slug` 缺省时由标题生成（标题不含拉丁字母和数字时必须填写）；
+/// `content_format` 缺省时与原文相同，`is_published` 缺省为未发布
+#[derive(Debug, Deserialize)]
+pub struct UpsertTranslationRequest {
+    pub title: String,
+    pub slug: Option<String>,
+    pub content: String,
+    pub content_format: Option<ContentFormat>,
+    pub is_published: Option<bool>,
+}
+
+/// 读取文章时选用的语言
+#[derive(Debug, Clone)]
+pub struct Localization {
+    /// 返回的标题和正文的语言
+    pub language: String,
+    /// 原文和已发布译文的语言（原文在前）
+    pub languages: Vec<String>,
+    /// 返回译文时为译文的 slug
+    pub slug: Option<String>,
+}
+
+impl From<TranslationEntity> for TranslationResponse {
+    fn from(translation: TranslationEntity) -> Self {
+        TranslationResponse {
+            id: translation.id,
+            article_id: translation.article_id,
+            language: translation.language,
+            title: translation.title,
+            slug: translation.slug,
+            content: translation.content,
+            content_format: translation.content_format,
+            is_published: translation.is_published,
+            created_at: translation.created_at,
+            updated_at: translation.updated_at,
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use crate::models::{ArticleResponse, UserResponse};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// 回收站中的文章
+#[derive(Debug, Serialize)]
+pub struct TrashedArticle {
+    #[serde(flatten)]
+    pub article: ArticleResponse,
+    pub deleted_at: DateTime<Utc>,
+    /// 预计彻底删除的时间
+    pub purge_at: DateTime<Utc>,
+}
+
+/// 回收站中的用户
+#[derive(Debug, Serialize)]
+pub struct TrashedUser {
+    #[serde(flatten)]
+    pub user: UserResponse,
+    pub deleted_at: DateTime<Utc>,
+    /// 预计彻底删除的时间
+    pub purge_at: DateTime<Utc>,
+}
@@ -1,7 +1,8 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
-use crate::entities::user::Model as UserEntity;
+use crate::entities::user::{Model as UserEntity, UserRole};
+use crate::extractors::{FilterKind, ListField, Sort};
 
 /// 创建用户请求
 #[derive(Debug, Deserialize)]
@@ -18,14 +19,40 @@ pub struct UpdateUserRequest {
     pub email: Option<String>,
 }
 
+/// 用户列表可过滤、可排序的字段（不包含邮箱等敏感字段）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserField {
+    Username,
+    CreatedAt,
+}
+
+impl ListField for UserField {
+    const FILTERS: &'static [(&'static str, Self, FilterKind)] = &[
+        ("username_contains", UserField::Username, FilterKind::Contains),
+        ("created_after", UserField::CreatedAt, FilterKind::After),
+        ("created_before", UserField::CreatedAt, FilterKind::Before),
+    ];
+    const SORTS: &'static [(&'static str, Self)] = &[
+        ("created_at", UserField::CreatedAt),
+        ("username", UserField::Username),
+    ];
+    const DEFAULT_SORT: &'static [Sort<Self>] = &[Sort {
+        field: UserField::CreatedAt,
+        descending: true,
+    }];
+}
+
 /// 用户响应（不包含敏感信息）
 #[derive(Debug, Serialize)]
 pub struct UserResponse {
     pub id: Uuid,
     pub username: String,
     pub email: String,
+    pub role: UserRole,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// 版本号，与响应头中的 ETag 一致
+    pub version: i32,
 }
 
 impl From<UserEntity> for UserResponse {
@@ -34,8 +61,10 @@ impl From<UserEntity> for UserResponse {
             id: user.id,
             username: user.username,
             email: user.email,
+            role: user.role,
             created_at: user.created_at,
             updated_at: user.updated_at,
+            version: user.version,
         }
     }
 }
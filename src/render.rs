@@ -0,0 +1,203 @@
+use crate::entities::article::ContentFormat;
+use crate::utils::escape_html;
+use pulldown_cmark::{html, Event, Options, Parser, TagEnd};
+use std::sync::OnceLock;
+
+/// 白名单 HTML 清洗器（基于 ammonia 默认白名单）
+///
+/// - 只保留安全的标签和属性，去掉 `<script>`、事件处理属性、`javascript:` 链接等
+/// - 所有链接统一加上 `rel="noopener noreferrer nofollow"`
+fn sanitizer() -> &'static ammonia::Builder<'static> {
+    static SANITIZER: OnceLock<ammonia::Builder<'static>> = OnceLock::new();
+    SANITIZER.get_or_init(|| {
+        let mut builder = ammonia::Builder::default();
+        builder.link_rel(Some("noopener noreferrer nofollow"));
+        builder
+    })
+}
+
+/// 将文章正文渲染为清洗后的 HTML
+pub fn render_content(content: &str, format: ContentFormat) -> String {
+    match format {
+        ContentFormat::Markdown => render_markdown(content),
+        ContentFormat::Plain => render_plain(content),
+    }
+}
+
+/// 渲染 Markdown（支持表格、删除线、脚注）并清洗
+pub fn render_markdown(markdown: &str) -> String {
+    let options = Options::ENABLE_TABLES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_FOOTNOTES;
+    let parser = Parser::new_ext(markdown, options);
+
+    let mut unsafe_html = String::with_capacity(markdown.len() * 3 / 2);
+    html::push_html(&mut unsafe_html, parser);
+
+    sanitizer().clean(&unsafe_html).to_string()
+}
+
+/// 渲染纯文本：转义后按空行分段，段内换行转为 `<br>`
+pub fn render_plain(text: &str) -> String {
+    text.replace("\r\n", "\n")
+        .split("\n\n")
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .map(|p| format!("<p>{}</p>", escape_html(p).replace('\n', "<br>")))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// 摘要最大字符数（不含省略号）
+const EXCERPT_CHARS: usize = 200;
+/// 中日韩文字的阅读速度（字/分钟）
+const CJK_CHARS_PER_MINUTE: f64 = 300.0;
+/// 其他文字的阅读速度（词/分钟）
+const WORDS_PER_MINUTE: f64 = 200.0;
+
+/// 正文统计信息
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContentStats {
+    /// 纯文本摘要（空白合并为一个空格，超长时截断并加省略号）
+    pub excerpt: String,
+    /// 字数：中日韩文字每个字计 1，其他文字按连续的字母数字计为一个词
+    pub word_count: i32,
+    /// 预计阅读时间（分钟，有内容时至少为 1）
+    pub reading_minutes: i32,
+}
+
+/// 计算正文的摘要、字数和预计阅读时间
+pub fn content_stats(content: &str, format: ContentFormat) -> ContentStats {
+    let text = match format {
+        ContentFormat::Markdown => markdown_to_text(content),
+        ContentFormat::Plain => content.to_string(),
+    };
+
+    let mut cjk_chars = 0usize;
+    let mut words = 0usize;
+    let mut in_word = false;
+    for c in text.chars() {
+        if is_cjk(c) {
+            cjk_chars += 1;
+            in_word = false;
+        } else if c.is_alphanumeric() || (in_word && (c == '\'' || c == '’')) {
+            if !in_word {
+                words += 1;
+                in_word = true;
+            }
+        } else {
+            in_word = false;
+        }
+    }
+
+    let minutes = cjk_chars as f64 / CJK_CHARS_PER_MINUTE + words as f64 / WORDS_PER_MINUTE;
+    let word_count = cjk_chars + words;
+
+    ContentStats {
+        excerpt: excerpt(&text),
+        word_count: word_count.min(i32::MAX as usize) as i32,
+        reading_minutes: if word_count == 0 { 0 } else { (minutes.ceil() as i32).max(1) },
+    }
+}
+
+/// 提取 Markdown 中的可读文本（忽略 HTML 和格式标记）
+fn markdown_to_text(markdown: &str) -> String {
+    let mut text = String::with_capacity(markdown.len());
+    for event in Parser::new_ext(markdown, Options::ENABLE_TABLES | Options::ENABLE_FOOTNOTES) {
+        match event {
+            Event::Text(t) | Event::Code(t) => text.push_str(&t),
+            Event::SoftBreak
+            | Event::HardBreak
+            | Event::End(TagEnd::Paragraph | TagEnd::Heading(_) | TagEnd::Item | TagEnd::TableCell)
+            | Event::End(TagEnd::CodeBlock) => text.push(' '),
+            _ => {}
+        }
+    }
+    text
+}
+
+/// 合并空白并截断为摘要
+fn excerpt(text: &str) -> String {
+    let collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    match collapsed.char_indices().nth(EXCERPT_CHARS) {
+        Some((end, _)) => format!("{}…", collapsed[..end].trim_end()),
+        None => collapsed,
+    }
+}
+
+/// 是否为中日韩文字（汉字、假名、谚文，不含标点）
+fn is_cjk(c: char) -> bool {
+    matches!(c,
+        '\u{3040}'..='\u{30FF}'      // 平假名、片假名
+        | '\u{3400}'..='\u{4DBF}'    // 汉字扩展 A
+        | '\u{4E00}'..='\u{9FFF}'    // 基本汉字
+        | '\u{AC00}'..='\u{D7AF}'    // 谚文音节
+        | '\u{F900}'..='\u{FAFF}'    // 兼容汉字
+        | '\u{20000}'..='\u{2FA1F}'  // 汉字扩展 B 及以后
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plain(content: &str) -> ContentStats {
+        content_stats(content, ContentFormat::Plain)
+    }
+
+    #[test]
+    fn cjk_characters_count_individually() {
+        assert_eq!(plain("你好世界").word_count, 4);
+        assert_eq!(plain("こんにちは").word_count, 5);
+        assert_eq!(plain("안녕하세요").word_count, 5);
+    }
+
+    #[test]
+    fn cjk_punctuation_is_not_counted() {
+        assert_eq!(plain("你好，世界！「引用」。").word_count, 6);
+    }
+
+    #[test]
+    fn mixed_cjk_and_latin_text() {
+        // 汉字紧挨着英文单词时，单词仍单独计数
+        assert_eq!(plain("使用Rust编写web服务").word_count, 8);
+        assert_eq!(plain("Rust 1.80 发布了").word_count, 6);
+    }
+
+    #[test]
+    fn apostrophes_inside_words_do_not_split() {
+        assert_eq!(plain("don't stop, it’s fine").word_count, 4);
+        assert_eq!(plain("'quoted'").word_count, 1);
+    }
+
+    #[test]
+    fn markdown_markup_is_not_counted() {
+        let stats = content_stats(
+            "# 标题\n\n**加粗** and [link](https://example.com)\n\n<div>html</div>",
+            ContentFormat::Markdown,
+        );
+        assert_eq!(stats.word_count, 6);
+        assert_eq!(stats.excerpt, "标题 加粗 and link");
+    }
+
+    #[test]
+    fn reading_minutes_combines_both_rates() {
+        assert_eq!(plain("").reading_minutes, 0);
+        assert_eq!(plain("字").reading_minutes, 1);
+        assert_eq!(plain(&"字".repeat(600)).reading_minutes, 2);
+        assert_eq!(plain(&"word ".repeat(400)).reading_minutes, 2);
+        // 300 字 + 200 词，各需 1 分钟
+        let mixed = format!("{}{}", "字".repeat(300), " word".repeat(200));
+        assert_eq!(plain(&mixed).reading_minutes, 2);
+    }
+
+    #[test]
+    fn excerpt_collapses_whitespace_and_truncates_by_chars() {
+        assert_eq!(plain("  a \n\n b\tc  ").excerpt, "a b c");
+
+        let long = "字".repeat(EXCERPT_CHARS + 10);
+        let excerpt = plain(&long).excerpt;
+        assert_eq!(excerpt.chars().count(), EXCERPT_CHARS + 1);
+        assert!(excerpt.ends_with('…'));
+    }
+}
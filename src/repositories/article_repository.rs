@@ -1,19 +1,46 @@
-use sea_orm::{DatabaseConnection, EntityTrait, QueryFilter, ColumnTrait, Set, ActiveModelTrait, QueryOrder, PaginatorTrait};
+use sea_orm::{DatabaseConnection, EntityTrait, QueryFilter, ColumnTrait, Set, ActiveModelTrait, QueryOrder, QuerySelect, PaginatorTrait, TransactionTrait};
 use uuid::Uuid;
 use crate::entities::article::{Entity as Article, Model};
+use crate::entities::article::Column;
 use crate::errors::{AppError, Result};
+use crate::extractors::{CursorPagination, ListQuery};
+use crate::models::ArticleField;
+use crate::repositories::{apply_list_query, fetch_after_cursor};
 
-/// 根据 ID 查找文章
+/// 根据 ID 查找文章（被隐藏和已删除的文章视为不存在）
 pub async fn find_by_id(
     db: &DatabaseConnection,
     id: Uuid,
 ) -> Result<Option<Model>> {
-    Article::find_by_id(id)
+    listed()
+        .filter(Column::Id.eq(id))
+        .one(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 根据 ID 查找文章，包含被隐藏的文章（仅供内容审核使用，已删除的文章仍视为不存在）
+pub async fn find_by_id_including_hidden(
+    db: &DatabaseConnection,
+    id: Uuid,
+) -> Result<Option<Model>> {
+    existing()
+        .filter(Column::Id.eq(id))
         .one(db)
         .await
         .map_err(AppError::Database)
 }
 
+/// 未删除的文章查询（包含被隐藏的文章）
+fn existing() -> sea_orm::Select<Article> {
+    Article::find().filter(Column::DeletedAt.is_null())
+}
+
+/// 未被隐藏且未删除的文章查询，所有读取路径都以此为起点
+fn listed() -> sea_orm::Select<Article> {
+    existing().filter(Column::HiddenAt.is_null())
+}
+
 /// 创建文章
 pub async fn create(
     db: &DatabaseConnection,
@@ -24,25 +51,110 @@ pub async fn create(
         .map_err(AppError::Database)
 }
 
-/// 更新文章
+/// 更新文章（自动刷新 `updated_at` 并递增版本号）
+///
+/// 只有数据库中的版本号仍等于 `article` 读取时的版本号才会更新，否则返回 412
 pub async fn update(
     db: &DatabaseConnection,
     id: Uuid,
+    article: crate::entities::article::ActiveModel,
+) -> Result<Model> {
+    versioned_update(db, id, article).await
+}
+
+/// 带版本条件更新文章
+async fn versioned_update<C: sea_orm::ConnectionTrait>(
+    db: &C,
+    id: Uuid,
     mut article: crate::entities::article::ActiveModel,
 ) -> Result<Model> {
+    let current = match article.version {
+        sea_orm::ActiveValue::Set(v) | sea_orm::ActiveValue::Unchanged(v) => v,
+        sea_orm::ActiveValue::NotSet => {
+            return Err(AppError::Internal(anyhow::anyhow!("更新文章缺少版本号")));
+        }
+    };
     article.id = Set(id);
-    let updated = article.update(db)
+    article.updated_at = Set(Some(chrono::Utc::now()));
+    article.version = Set(current + 1);
+
+    Article::update(article)
+        .filter(Column::Version.eq(current))
+        .exec(db)
+        .await
+        .map_err(AppError::from_versioned_update)
+}
+
+/// 创建文章并记录首个修订（同一事务）
+pub async fn create_with_revision(
+    db: &DatabaseConnection,
+    article: crate::entities::article::ActiveModel,
+    revision: crate::entities::article_revision::ActiveModel,
+) -> Result<Model> {
+    let txn = db.begin().await.map_err(AppError::Database)?;
+
+    let created = article.insert(&txn)
         .await
         .map_err(AppError::Database)?;
+    crate::repositories::revision_repository::append(&txn, revision).await?;
+
+    txn.commit().await.map_err(AppError::Database)?;
+    Ok(created)
+}
+
+/// 批量创建文章并为每篇记录首个修订（同一事务，任一失败全部回滚）
+pub async fn create_many_with_revisions(
+    db: &DatabaseConnection,
+    articles: Vec<(
+        crate::entities::article::ActiveModel,
+        crate::entities::article_revision::ActiveModel,
+    )>,
+) -> Result<Vec<Uuid>> {
+    let txn = db.begin().await.map_err(AppError::Database)?;
+
+    let mut ids = Vec::with_capacity(articles.len());
+    for (article, revision) in articles {
+        let created = article.insert(&txn)
+            .await
+            .map_err(AppError::Database)?;
+        crate::repositories::revision_repository::append(&txn, revision).await?;
+        ids.push(created.id);
+    }
+
+    txn.commit().await.map_err(AppError::Database)?;
+    Ok(ids)
+}
+
+/// 更新文章并追加修订（同一事务）
+///
+/// `baseline` 为更新前的内容，文章还没有任何修订时先把它记为第 1 个修订
+pub async fn update_with_revision(
+    db: &DatabaseConnection,
+    id: Uuid,
+    article: crate::entities::article::ActiveModel,
+    baseline: Option<crate::entities::article_revision::ActiveModel>,
+    revision: crate::entities::article_revision::ActiveModel,
+) -> Result<Model> {
+    let txn = db.begin().await.map_err(AppError::Database)?;
+
+    if let Some(baseline) = baseline {
+        crate::repositories::revision_repository::append_baseline(&txn, baseline).await?;
+    }
+    let updated = versioned_update(&txn, id, article).await?;
+    crate::repositories::revision_repository::append(&txn, revision).await?;
+
+    txn.commit().await.map_err(AppError::Database)?;
     Ok(updated)
 }
 
-/// 删除文章
+/// 删除文章（软删除，放入回收站，同时取消置顶）
 pub async fn delete(
     db: &DatabaseConnection,
     id: Uuid,
+    now: chrono::DateTime<chrono::Utc>,
 ) -> Result<()> {
-    let result = Article::delete_by_id(id)
+    let result = soft_delete(Column::Id.eq(id))
+        .col_expr(Column::DeletedAt, sea_orm::sea_query::Expr::value(Some(now)))
         .exec(db)
         .await
         .map_err(AppError::Database)?;
@@ -54,40 +166,656 @@ pub async fn delete(
     Ok(())
 }
 
+/// 把匹配 `condition` 的未删除文章放入回收站的更新语句（调用方设置 `deleted_at`）
+pub(crate) fn soft_delete(
+    condition: impl sea_orm::sea_query::IntoCondition,
+) -> sea_orm::UpdateMany<Article> {
+    use sea_orm::sea_query::Expr;
+
+    Article::update_many()
+        .col_expr(Column::PinnedPosition, Expr::value(Option::<i32>::None))
+        .col_expr(Column::PinnedUntil, Expr::value(Option::<chrono::DateTime<chrono::Utc>>::None))
+        .col_expr(Column::PinnedAt, Expr::value(Option::<chrono::DateTime<chrono::Utc>>::None))
+        .col_expr(Column::PinnedBy, Expr::value(Option::<Uuid>::None))
+        .filter(condition)
+        .filter(Column::DeletedAt.is_null())
+}
+
+/// 根据 ID 查找回收站中的文章
+pub async fn find_deleted_by_id(db: &DatabaseConnection, id: Uuid) -> Result<Option<Model>> {
+    Article::find_by_id(id)
+        .filter(Column::DeletedAt.is_not_null())
+        .one(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 分页查询回收站中的文章（按删除时间倒序），`author_id` 为空时查询全部
+pub async fn find_deleted_with_pagination(
+    db: &DatabaseConnection,
+    author_id: Option<Uuid>,
+    offset: u64,
+    limit: u64,
+) -> Result<(Vec<Model>, u64)> {
+    let mut query = Article::find().filter(Column::DeletedAt.is_not_null());
+    if let Some(author_id) = author_id {
+        query = query.filter(Column::UserId.eq(author_id));
+    }
+
+    let paginator = query
+        .order_by_desc(Column::DeletedAt)
+        .order_by_desc(Column::Id)
+        .paginate(db, limit);
+
+    let total = paginator.num_items().await.map_err(AppError::Database)?;
+
+    let page = offset.checked_div(limit).unwrap_or(0);
+    let articles = paginator.fetch_page(page).await.map_err(AppError::Database)?;
+
+    Ok((articles, total))
+}
+
+/// 分页查询处于指定审核状态的文章（按更新时间正序，先提交的排在前面）
+pub async fn find_by_review_status_with_pagination(
+    db: &DatabaseConnection,
+    status: crate::entities::article::ReviewStatus,
+    offset: u64,
+    limit: u64,
+) -> Result<(Vec<Model>, u64)> {
+    let paginator = listed()
+        .filter(Column::ReviewStatus.eq(status))
+        .order_by_asc(Column::UpdatedAt)
+        .order_by_asc(Column::Id)
+        .paginate(db, limit);
+
+    let total = paginator.num_items().await.map_err(AppError::Database)?;
+
+    let page = offset.checked_div(limit).unwrap_or(0);
+    let articles = paginator.fetch_page(page).await.map_err(AppError::Database)?;
+
+    Ok((articles, total))
+}
+
+/// 从回收站恢复文章
+pub async fn restore(db: &DatabaseConnection, id: Uuid) -> Result<()> {
+    Article::update_many()
+        .col_expr(
+            Column::DeletedAt,
+            sea_orm::sea_query::Expr::value(Option::<chrono::DateTime<chrono::Utc>>::None),
+        )
+        .filter(Column::Id.eq(id))
+        .exec(db)
+        .await
+        .map_err(AppError::Database)?;
+
+    Ok(())
+}
+
+/// 查询删除时间早于 `before` 的文章 ID（用于彻底删除，跳过 `exclude` 中本轮已失败的文章）
+pub async fn find_deleted_before(
+    db: &DatabaseConnection,
+    before: chrono::DateTime<chrono::Utc>,
+    exclude: &[Uuid],
+    limit: u64,
+) -> Result<Vec<Uuid>> {
+    Article::find()
+        .select_only()
+        .column(Column::Id)
+        .filter(Column::DeletedAt.lt(before))
+        .filter(Column::Id.is_not_in(exclude.iter().copied()))
+        .order_by_asc(Column::DeletedAt)
+        .limit(limit)
+        .into_tuple()
+        .all(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 查询作者的全部文章 ID（包含已删除的，用于彻底删除用户）
+pub async fn find_ids_by_author(db: &DatabaseConnection, author_id: Uuid) -> Result<Vec<Uuid>> {
+    Article::find()
+        .select_only()
+        .column(Column::Id)
+        .filter(Column::UserId.eq(author_id))
+        .into_tuple()
+        .all(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 彻底删除文章（修订、附件记录等由外键级联删除）
+///
+/// 评论在同一事务中按层级从深到浅先行删除，不依赖 InnoDB 级联删除评论的顺序
+pub async fn purge(db: &DatabaseConnection, id: Uuid) -> Result<()> {
+    use crate::entities::comment::Column as CommentColumn;
+    use crate::entities::Comment;
+
+    let txn = db.begin().await.map_err(AppError::Database)?;
+
+    let max_depth: Option<i32> = Comment::find()
+        .select_only()
+        .column_as(CommentColumn::Depth.max(), "max_depth")
+        .filter(CommentColumn::ArticleId.eq(id))
+        .into_tuple::<Option<i32>>()
+        .one(&txn)
+        .await
+        .map_err(AppError::Database)?
+        .flatten();
+    for depth in (0..=max_depth.unwrap_or(-1)).rev() {
+        Comment::delete_many()
+            .filter(CommentColumn::ArticleId.eq(id))
+            .filter(CommentColumn::Depth.eq(depth))
+            .exec(&txn)
+            .await
+            .map_err(AppError::Database)?;
+    }
+    Article::delete_by_id(id)
+        .exec(&txn)
+        .await
+        .map_err(AppError::Database)?;
+
+    txn.commit().await.map_err(AppError::Database)?;
+    Ok(())
+}
+
+/// 文章分类过滤条件
+#[derive(Debug, Clone)]
+pub enum CategoryFilter {
+    /// 仅匹配指定分类
+    Exact(Uuid),
+    /// 匹配物化路径前缀下的所有分类（包含子孙分类），在同一条 SQL 中通过子查询完成
+    Subtree(String),
+}
+
 /// 分页查询文章列表（根据用户ID和是否公开）
+///
+/// 缺省排序时置顶有效的文章按置顶位置排在最前
 pub async fn find_all_with_pagination(
     db: &DatabaseConnection,
     user_id: Option<Uuid>,
     is_public_only: bool,
+    category: Option<CategoryFilter>,
+    list_query: &ListQuery<ArticleField>,
     offset: u64,
     limit: u64,
 ) -> Result<(Vec<Model>, u64)> {
-    let mut query = Article::find();
+    let mut query = visible_articles(user_id, is_public_only, category);
+    if list_query.is_default_sort() {
+        query = pinned_first(query, chrono::Utc::now());
+    }
     
+    let paginator = apply_list_query(query, list_query, Column::Id, article_column)
+        .paginate(db, limit);
+    
+    let total = paginator.num_items().await
+        .map_err(AppError::Database)?;
+    
+    let page = offset.checked_div(limit).unwrap_or(0);
+    let articles = paginator.fetch_page(page)
+        .await
+        .map_err(AppError::Database)?;
+    
+    Ok((articles, total))
+}
+
+/// 游标分页查询文章列表（按创建时间倒序），返回 `(文章, 是否还有下一页, 总数)`
+pub async fn find_after_cursor(
+    db: &DatabaseConnection,
+    user_id: Option<Uuid>,
+    is_public_only: bool,
+    category: Option<CategoryFilter>,
+    list_query: &ListQuery<ArticleField>,
+    pagination: &CursorPagination,
+) -> Result<(Vec<Model>, bool, Option<u64>)> {
+    let query = visible_articles(user_id, is_public_only, category);
+    let query = apply_list_query(query, list_query, Column::Id, article_column);
+
+    fetch_after_cursor(db, query, pagination, Column::CreatedAt, Column::Id).await
+}
+
+/// 游标分页查询用户关注的作者发布的公开文章（按创建时间倒序），返回 `(文章, 是否还有下一页, 总数)`
+///
+/// 关注列表以子查询的形式交给数据库，关注数千人时也不需要把作者 ID 全部取回再拼接 `IN (...)`
+pub async fn find_feed_after_cursor(
+    db: &DatabaseConnection,
+    follower_id: Uuid,
+    pagination: &CursorPagination,
+) -> Result<(Vec<Model>, bool, Option<u64>)> {
+    use crate::entities::follow;
+    use sea_orm::sea_query::Query;
+
+    let followees = Query::select()
+        .column(follow::Column::FolloweeId)
+        .from(follow::Entity)
+        .and_where(follow::Column::FollowerId.eq(follower_id))
+        .to_owned();
+
+    let query = listed()
+        .filter(Column::IsPublic.eq(true))
+        .filter(Column::UserId.in_subquery(followees))
+        .order_by_desc(Column::CreatedAt)
+        .order_by_desc(Column::Id);
+
+    fetch_after_cursor(db, query, pagination, Column::CreatedAt, Column::Id).await
+}
+
+/// 当前用户可见的文章查询（公开文章 + 自己的文章），附带分类过滤
+fn visible_articles(
+    user_id: Option<Uuid>,
+    is_public_only: bool,
+    category: Option<CategoryFilter>,
+) -> sea_orm::Select<Article> {
+    let query = filter_category(listed(), category);
+
     if is_public_only {
         // 只查询公开文章
-        query = query.filter(crate::entities::article::Column::IsPublic.eq(true));
+        query.filter(Column::IsPublic.eq(true))
     } else if let Some(uid) = user_id {
         // 查询用户的所有文章或公开文章
-        query = query.filter(
+        query.filter(
             sea_orm::Condition::any()
-                .add(crate::entities::article::Column::UserId.eq(uid))
-                .add(crate::entities::article::Column::IsPublic.eq(true))
-        );
+                .add(Column::UserId.eq(uid))
+                .add(Column::IsPublic.eq(true))
+        )
+    } else {
+        query
     }
-    
-    let paginator = query
+}
+
+/// 置顶在 `now` 时有效的条件（有置顶位置且未过期）
+fn pin_active(now: chrono::DateTime<chrono::Utc>) -> sea_orm::Condition {
+    sea_orm::Condition::all()
+        .add(Column::PinnedPosition.is_not_null())
+        .add(
+            sea_orm::Condition::any()
+                .add(Column::PinnedUntil.is_null())
+                .add(Column::PinnedUntil.gt(now)),
+        )
+}
+
+/// 置顶有效的文章排在最前，彼此之间按置顶位置排序；其余文章保持原有排序
+fn pinned_first(
+    query: sea_orm::Select<Article>,
+    now: chrono::DateTime<chrono::Utc>,
+) -> sea_orm::Select<Article> {
+    use sea_orm::sea_query::{Expr, SimpleExpr};
+
+    // CASE WHEN 置顶有效 THEN `then` ELSE `otherwise` END
+    let when_pinned = |then: SimpleExpr, otherwise: i32| {
+        SimpleExpr::Case(Box::new(
+            Expr::case(pin_active(now), then).finally(Expr::val(otherwise)),
+        ))
+    };
+
+    query
+        .order_by(when_pinned(Expr::val(0).into(), 1), sea_orm::Order::Asc)
+        .order_by(
+            when_pinned(Expr::col((Article, Column::PinnedPosition)).into(), 0),
+            sea_orm::Order::Asc,
+        )
+}
+
+/// 查询置顶有效的公开文章（按置顶位置排序）
+pub async fn find_pinned(
+    db: &DatabaseConnection,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Result<Vec<Model>> {
+    listed()
+        .filter(Column::IsPublic.eq(true))
+        .filter(pin_active(now))
+        .order_by_asc(Column::PinnedPosition)
+        .all(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 查询置顶有效的文章 ID（按置顶位置排序，包含私有和被隐藏的文章，用于调整置顶顺序）
+pub async fn find_pinned_ids(
+    db: &DatabaseConnection,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Result<Vec<Uuid>> {
+    existing()
+        .select_only()
+        .column(Column::Id)
+        .filter(pin_active(now))
+        .order_by_asc(Column::PinnedPosition)
+        .order_by_asc(Column::PinnedAt)
+        .into_tuple()
+        .all(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 新置顶的文章及置顶信息
+pub struct NewPin {
+    pub article_id: Uuid,
+    pub pinned_until: Option<chrono::DateTime<chrono::Utc>>,
+    pub pinned_at: chrono::DateTime<chrono::Utc>,
+    pub pinned_by: Uuid,
+}
+
+/// 按 `ordered` 的顺序重写置顶位置（同一事务）
+///
+/// 不在 `ordered` 中的文章（取消置顶的、已过期的）清除置顶；`pin` 为新置顶或调整置顶的文章
+pub async fn set_pins(
+    db: &DatabaseConnection,
+    ordered: &[Uuid],
+    pin: Option<NewPin>,
+) -> Result<()> {
+    use sea_orm::sea_query::Expr;
+
+    let txn = db.begin().await.map_err(AppError::Database)?;
+
+    Article::update_many()
+        .col_expr(Column::PinnedPosition, Expr::value(Option::<i32>::None))
+        .col_expr(Column::PinnedUntil, Expr::value(Option::<chrono::DateTime<chrono::Utc>>::None))
+        .col_expr(Column::PinnedAt, Expr::value(Option::<chrono::DateTime<chrono::Utc>>::None))
+        .col_expr(Column::PinnedBy, Expr::value(Option::<Uuid>::None))
+        .filter(Column::PinnedPosition.is_not_null())
+        .filter(Column::Id.is_not_in(ordered.iter().copied()))
+        .exec(&txn)
+        .await
+        .map_err(AppError::Database)?;
+
+    for (index, article_id) in ordered.iter().enumerate() {
+        Article::update_many()
+            .col_expr(Column::PinnedPosition, Expr::value(Some(index as i32 + 1)))
+            .filter(Column::Id.eq(*article_id))
+            .exec(&txn)
+            .await
+            .map_err(AppError::Database)?;
+    }
+
+    if let Some(pin) = pin {
+        Article::update_many()
+            .col_expr(Column::PinnedUntil, Expr::value(pin.pinned_until))
+            .col_expr(Column::PinnedAt, Expr::value(Some(pin.pinned_at)))
+            .col_expr(Column::PinnedBy, Expr::value(Some(pin.pinned_by)))
+            .filter(Column::Id.eq(pin.article_id))
+            .exec(&txn)
+            .await
+            .map_err(AppError::Database)?;
+    }
+
+    txn.commit().await.map_err(AppError::Database)
+}
+
+/// 查询最新的公开文章（用于订阅源），可按作者和分类过滤
+pub async fn find_public_recent(
+    db: &DatabaseConnection,
+    author_id: Option<Uuid>,
+    category: Option<CategoryFilter>,
+    limit: u64,
+) -> Result<Vec<Model>> {
+    let mut query = filter_category(listed(), category)
+        .filter(crate::entities::article::Column::IsPublic.eq(true));
+
+    if let Some(author_id) = author_id {
+        query = query.filter(crate::entities::article::Column::UserId.eq(author_id));
+    }
+
+    query
+        .order_by_desc(crate::entities::article::Column::CreatedAt)
+        .order_by_desc(crate::entities::article::Column::Id)
+        .limit(limit)
+        .all(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 查询所有公开文章的 `(ID, 作者 ID, 创建时间, 修改时间)`（用于站点地图）
+///
+/// 只选取少量列，按创建时间正序，避免一次性加载正文
+pub async fn find_public_timestamps(
+    db: &DatabaseConnection,
+) -> Result<Vec<PublicArticleTimestamps>> {
+    use crate::entities::article::Column;
+
+    listed()
+        .select_only()
+        .columns([Column::Id, Column::UserId, Column::CreatedAt, Column::UpdatedAt])
+        .filter(Column::IsPublic.eq(true))
+        .order_by_asc(Column::CreatedAt)
+        .order_by_asc(Column::Id)
+        .into_tuple()
+        .all(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 公开文章的 `(ID, 作者 ID, 创建时间, 修改时间)`
+pub type PublicArticleTimestamps = (
+    Uuid,
+    Option<Uuid>,
+    Option<chrono::DateTime<chrono::Utc>>,
+    Option<chrono::DateTime<chrono::Utc>>,
+);
+
+/// 文章列表字段对应的数据库列
+fn article_column(field: ArticleField) -> Column {
+    match field {
+        ArticleField::AuthorId => Column::UserId,
+        ArticleField::Title => Column::Title,
+        ArticleField::CreatedAt => Column::CreatedAt,
+        ArticleField::UpdatedAt => Column::UpdatedAt,
+        ArticleField::ViewCount => Column::ViewCount,
+    }
+}
+
+/// 追加分类过滤条件
+fn filter_category(
+    query: sea_orm::Select<Article>,
+    category: Option<CategoryFilter>,
+) -> sea_orm::Select<Article> {
+    match category {
+        Some(CategoryFilter::Exact(category_id)) => {
+            query.filter(crate::entities::article::Column::CategoryId.eq(category_id))
+        }
+        Some(CategoryFilter::Subtree(path)) => query.filter(
+            crate::entities::article::Column::CategoryId.in_subquery(
+                sea_orm::sea_query::Query::select()
+                    .column(crate::entities::category::Column::Id)
+                    .from(crate::entities::category::Entity)
+                    .and_where(crate::entities::category::Column::Path.starts_with(path))
+                    .to_owned(),
+            ),
+        ),
+        None => query,
+    }
+}
+
+
+/// 全文检索文章（MySQL FULLTEXT + ngram 解析器）
+///
+/// 按相关度倒序返回 `(文章 ID, 相关度)`，标题命中的权重是正文的两倍；
+/// 只包含公开文章以及 `user_id` 自己的文章
+pub async fn search(
+    db: &DatabaseConnection,
+    keyword: &str,
+    user_id: Option<Uuid>,
+    offset: u64,
+    limit: u64,
+) -> Result<(Vec<(Uuid, f64)>, u64)> {
+    let full_match = sea_orm::sea_query::Expr::cust_with_values(
+        "MATCH(`title`, `content`) AGAINST (? IN NATURAL LANGUAGE MODE)",
+        [keyword],
+    );
+    let relevance = sea_orm::sea_query::Expr::cust_with_values(
+        "MATCH(`title`) AGAINST (? IN NATURAL LANGUAGE MODE) * 2 + MATCH(`title`, `content`) AGAINST (? IN NATURAL LANGUAGE MODE)",
+        [keyword, keyword],
+    );
+
+    let mut visibility = sea_orm::Condition::any()
+        .add(crate::entities::article::Column::IsPublic.eq(true));
+    if let Some(uid) = user_id {
+        visibility = visibility.add(crate::entities::article::Column::UserId.eq(uid));
+    }
+
+    let paginator = listed()
+        .select_only()
+        .column(crate::entities::article::Column::Id)
+        .column_as(relevance.clone(), "relevance")
+        .filter(full_match)
+        .filter(visibility)
+        .order_by(relevance, sea_orm::Order::Desc)
         .order_by_desc(crate::entities::article::Column::CreatedAt)
+        .into_tuple::<(Uuid, f64)>()
         .paginate(db, limit);
-    
+
     let total = paginator.num_items().await
         .map_err(AppError::Database)?;
-    
-    let page = if limit > 0 { offset / limit } else { 0 };
-    let articles = paginator.fetch_page(page)
+
+    let page = offset.checked_div(limit).unwrap_or(0);
+    let hits = paginator.fetch_page(page)
         .await
         .map_err(AppError::Database)?;
-    
-    Ok((articles, total))
+
+    Ok((hits, total))
 }
 
+/// 根据 ID 批量查找文章（不保证顺序）
+pub async fn find_by_ids(
+    db: &DatabaseConnection,
+    ids: &[Uuid],
+) -> Result<Vec<Model>> {
+    if ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    listed()
+        .filter(crate::entities::article::Column::Id.is_in(ids.iter().copied()))
+        .all(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 按 ID 顺序分批读取文章（用于导出）
+///
+/// `author_id` 为空时读取全部文章；`after` 为上一批最后一篇文章的 ID
+pub async fn find_export_batch(
+    db: &DatabaseConnection,
+    author_id: Option<Uuid>,
+    after: Option<Uuid>,
+    limit: u64,
+) -> Result<Vec<Model>> {
+    let mut query = listed();
+    if let Some(author_id) = author_id {
+        query = query.filter(Column::UserId.eq(author_id));
+    }
+    if let Some(after) = after {
+        query = query.filter(Column::Id.gt(after));
+    }
+
+    query
+        .order_by_asc(Column::Id)
+        .limit(limit)
+        .all(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 查询正文指纹相同的文章的作者（用于重复内容检测，包含被隐藏的文章，不包含已删除的文章）
+///
+/// `exclude` 为正在更新的文章 ID
+pub async fn find_authors_by_content_hash(
+    db: &DatabaseConnection,
+    hash: &str,
+    exclude: Option<Uuid>,
+) -> Result<Vec<Option<Uuid>>> {
+    let mut query = existing()
+        .select_only()
+        .column(Column::UserId)
+        .filter(Column::ContentHash.eq(hash));
+    if let Some(id) = exclude {
+        query = query.filter(Column::Id.ne(id));
+    }
+
+    query
+        .distinct()
+        .limit(20)
+        .into_tuple()
+        .all(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::{comment, user, Comment, User};
+
+    /// 彻底删除带两层回复的文章（需要执行过全部迁移的 MySQL）：
+    /// `TEST_DATABASE_URL=mysql://... cargo test -- --ignored purge_article_with_nested_replies`
+    #[tokio::test]
+    #[ignore = "需要 TEST_DATABASE_URL 指向执行过全部迁移的 MySQL"]
+    async fn purge_article_with_nested_replies() {
+        let url = std::env::var("TEST_DATABASE_URL").expect("未设置 TEST_DATABASE_URL");
+        let db = sea_orm::Database::connect(url).await.unwrap();
+        let now = chrono::Utc::now();
+
+        let user_id = Uuid::new_v4();
+        user::ActiveModel {
+            id: Set(user_id),
+            username: Set(format!("purge-{}", user_id.simple())),
+            email: Set(format!("purge-{}@example.com", user_id.simple())),
+            password_hash: Set(String::new()),
+            role: Set(user::UserRole::User),
+            created_at: Set(now),
+            updated_at: Set(now),
+            version: Set(1),
+            deleted_at: Set(None),
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+
+        let article_id = Uuid::new_v4();
+        crate::entities::article::ActiveModel {
+            id: Set(article_id),
+            title: Set("purge".to_string()),
+            content: Set("purge".to_string()),
+            user_id: Set(Some(user_id)),
+            created_at: Set(Some(now)),
+            updated_at: Set(Some(now)),
+            deleted_at: Set(Some(now)),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+
+        let mut parent_id = None;
+        for depth in 0..3 {
+            let id = Uuid::new_v4();
+            comment::ActiveModel {
+                id: Set(id),
+                article_id: Set(article_id),
+                user_id: Set(Some(user_id)),
+                parent_id: Set(parent_id),
+                depth: Set(depth),
+                content: Set(format!("depth {}", depth)),
+                is_deleted: Set(false),
+                content_hash: Set(None),
+                created_at: Set(now),
+                updated_at: Set(now),
+            }
+            .insert(&db)
+            .await
+            .unwrap();
+            parent_id = Some(id);
+        }
+
+        purge(&db, article_id).await.unwrap();
+
+        let remaining = Comment::find()
+            .filter(comment::Column::ArticleId.eq(article_id))
+            .count(&db)
+            .await
+            .unwrap();
+        assert_eq!(remaining, 0);
+        assert!(Article::find_by_id(article_id).one(&db).await.unwrap().is_none());
+
+        User::delete_by_id(user_id).exec(&db).await.unwrap();
+    }
+}
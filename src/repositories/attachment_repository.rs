@@ -0,0 +1,89 @@
+use crate::entities::attachment::{Column, Entity as Attachment, Model};
+use crate::errors::{AppError, Result};
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, QuerySelect, TransactionTrait,
+};
+use uuid::Uuid;
+
+/// 根据 ID 查找附件
+pub async fn find_by_id(db: &DatabaseConnection, id: Uuid) -> Result<Option<Model>> {
+    Attachment::find_by_id(id)
+        .one(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 查询文章的所有附件（按上传时间升序）
+pub async fn find_by_article(db: &DatabaseConnection, article_id: Uuid) -> Result<Vec<Model>> {
+    Attachment::find()
+        .filter(Column::ArticleId.eq(article_id))
+        .order_by_asc(Column::CreatedAt)
+        .all(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 统计用户已使用的附件空间（字节）
+pub async fn total_size_by_user<C: ConnectionTrait>(conn: &C, user_id: Uuid) -> Result<u64> {
+    // MySQL 的 SUM 返回 DECIMAL，转换为整数以便解码
+    let total: Option<i64> = Attachment::find()
+        .select_only()
+        .column_as(
+            Expr::cust("CAST(COALESCE(SUM(`size_bytes`), 0) AS SIGNED)"),
+            "total",
+        )
+        .filter(Column::UserId.eq(user_id))
+        .into_tuple()
+        .one(conn)
+        .await
+        .map_err(AppError::Database)?;
+
+    Ok(total.unwrap_or(0).max(0) as u64)
+}
+
+/// 在配额内创建附件记录
+///
+/// 事务中先锁定上传者的用户行，使同一用户的并发上传依次检查配额；
+/// 加上本附件后超出 `quota` 时不写入并返回 413
+pub async fn create_within_quota(
+    db: &DatabaseConnection,
+    attachment: crate::entities::attachment::ActiveModel,
+    user_id: Uuid,
+    quota: u64,
+) -> Result<Model> {
+    let size = attachment.size_bytes.clone().take().unwrap_or(0).max(0) as u64;
+    let txn = db.begin().await.map_err(AppError::Database)?;
+
+    crate::entities::User::find_by_id(user_id)
+        .lock_exclusive()
+        .one(&txn)
+        .await
+        .map_err(AppError::Database)?;
+    let used = total_size_by_user(&txn, user_id).await?;
+    if used + size > quota {
+        return Err(AppError::PayloadTooLarge(format!(
+            "附件空间不足：已使用 {} 字节，配额 {} 字节",
+            used, quota
+        )));
+    }
+    let created = attachment.insert(&txn).await.map_err(AppError::Database)?;
+
+    txn.commit().await.map_err(AppError::Database)?;
+    Ok(created)
+}
+
+/// 删除附件记录
+pub async fn delete(db: &DatabaseConnection, id: Uuid) -> Result<()> {
+    let result = Attachment::delete_by_id(id)
+        .exec(db)
+        .await
+        .map_err(AppError::Database)?;
+
+    if result.rows_affected == 0 {
+        return Err(AppError::NotFound);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,112 @@
+use crate::entities::article::{self, Model as ArticleModel};
+use crate::entities::bookmark::{Column, Entity as Bookmark, Model};
+use crate::errors::{AppError, Result};
+use sea_orm::sea_query::{Expr, OnConflict};
+use sea_orm::{
+    ColumnTrait, Condition, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
+    QueryOrder, QuerySelect,
+};
+use uuid::Uuid;
+
+/// 收藏文章（已收藏时不做任何修改，幂等）
+pub async fn insert_ignore(
+    db: &DatabaseConnection,
+    bookmark: crate::entities::bookmark::ActiveModel,
+) -> Result<()> {
+    Bookmark::insert(bookmark)
+        .on_conflict(
+            OnConflict::columns([Column::UserId, Column::ArticleId])
+                .do_nothing()
+                .to_owned(),
+        )
+        .exec_without_returning(db)
+        .await
+        .map_err(AppError::Database)?;
+
+    Ok(())
+}
+
+/// 取消收藏（不存在时不报错）
+pub async fn delete(db: &DatabaseConnection, user_id: Uuid, article_id: Uuid) -> Result<()> {
+    Bookmark::delete_many()
+        .filter(Column::UserId.eq(user_id))
+        .filter(Column::ArticleId.eq(article_id))
+        .exec(db)
+        .await
+        .map_err(AppError::Database)?;
+
+    Ok(())
+}
+
+/// 批量统计多篇文章的收藏数量
+pub async fn count_by_articles(
+    db: &DatabaseConnection,
+    article_ids: &[Uuid],
+) -> Result<Vec<(Uuid, i64)>> {
+    if article_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    Bookmark::find()
+        .select_only()
+        .column(Column::ArticleId)
+        .column_as(Expr::col(Column::UserId).count(), "count")
+        .filter(Column::ArticleId.is_in(article_ids.iter().copied()))
+        .group_by(Column::ArticleId)
+        .into_tuple()
+        .all(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 查询用户在多篇文章上的收藏记录
+pub async fn find_by_user_and_articles(
+    db: &DatabaseConnection,
+    user_id: Uuid,
+    article_ids: &[Uuid],
+) -> Result<Vec<Model>> {
+    if article_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    Bookmark::find()
+        .filter(Column::UserId.eq(user_id))
+        .filter(Column::ArticleId.is_in(article_ids.iter().copied()))
+        .all(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 分页查询用户收藏的文章（按收藏时间倒序，只返回该用户可读的文章）
+pub async fn find_articles_by_user_with_pagination(
+    db: &DatabaseConnection,
+    user_id: Uuid,
+    offset: u64,
+    limit: u64,
+) -> Result<(Vec<ArticleModel>, u64)> {
+    let paginator = Bookmark::find()
+        .find_also_related(article::Entity)
+        .filter(Column::UserId.eq(user_id))
+        .filter(article::Column::HiddenAt.is_null())
+        .filter(article::Column::DeletedAt.is_null())
+        .filter(
+            Condition::any()
+                .add(article::Column::IsPublic.eq(true))
+                .add(article::Column::UserId.eq(user_id)),
+        )
+        .order_by_desc(Column::CreatedAt)
+        .paginate(db, limit);
+
+    let total = paginator.num_items().await.map_err(AppError::Database)?;
+
+    let page = offset.checked_div(limit).unwrap_or(0);
+    let articles = paginator
+        .fetch_page(page)
+        .await
+        .map_err(AppError::Database)?
+        .into_iter()
+        .filter_map(|(_, article)| article)
+        .collect();
+
+    Ok((articles, total))
+}
@@ -0,0 +1,132 @@
+use crate::entities::category::{Column, Entity as Category, Model};
+use crate::errors::{AppError, Result};
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
+    QueryOrder, Set, TransactionTrait,
+};
+use uuid::Uuid;
+
+/// 根据 ID 查找分类
+pub async fn find_by_id(db: &DatabaseConnection, id: Uuid) -> Result<Option<Model>> {
+    Category::find_by_id(id)
+        .one(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 根据 slug 查找分类
+pub async fn find_by_slug(db: &DatabaseConnection, slug: &str) -> Result<Option<Model>> {
+    Category::find()
+        .filter(Column::Slug.eq(slug))
+        .one(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 查询所有分类（按层级、排序值、名称排序）
+pub async fn find_all(db: &DatabaseConnection) -> Result<Vec<Model>> {
+    Category::find()
+        .order_by_asc(Column::Depth)
+        .order_by_asc(Column::SortOrder)
+        .order_by_asc(Column::Name)
+        .all(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 查询某个路径下的整棵子树（包含自身）
+pub async fn find_subtree(db: &DatabaseConnection, path: &str) -> Result<Vec<Model>> {
+    Category::find()
+        .filter(Column::Path.starts_with(path))
+        .order_by_asc(Column::Depth)
+        .order_by_asc(Column::SortOrder)
+        .all(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 统计直接子分类数量
+pub async fn count_children(db: &DatabaseConnection, id: Uuid) -> Result<u64> {
+    Category::find()
+        .filter(Column::ParentId.eq(id))
+        .count(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 创建分类
+pub async fn create(
+    db: &DatabaseConnection,
+    category: crate::entities::category::ActiveModel,
+) -> Result<Model> {
+    category.insert(db).await.map_err(AppError::Database)
+}
+
+/// 更新分类
+pub async fn update(
+    db: &DatabaseConnection,
+    id: Uuid,
+    mut category: crate::entities::category::ActiveModel,
+) -> Result<Model> {
+    category.id = Set(id);
+    category.update(db).await.map_err(AppError::Database)
+}
+
+/// 移动分类（连同整棵子树）
+///
+/// 在同一个事务中更新节点自身的父分类，并把子树中所有节点的
+/// `path` 前缀从 `old_path` 替换为 `new_path`、`depth` 加上 `depth_delta`
+pub async fn move_subtree(
+    db: &DatabaseConnection,
+    id: Uuid,
+    new_parent_id: Option<Uuid>,
+    old_path: &str,
+    new_path: &str,
+    depth_delta: i32,
+) -> Result<()> {
+    let txn = db.begin().await.map_err(AppError::Database)?;
+
+    Category::update_many()
+        .col_expr(Column::ParentId, Expr::value(new_parent_id))
+        .filter(Column::Id.eq(id))
+        .exec(&txn)
+        .await
+        .map_err(AppError::Database)?;
+
+    // SUBSTRING 的下标从 1 开始，跳过旧前缀后拼接新前缀
+    let old_len = old_path.chars().count() as i32;
+    Category::update_many()
+        .col_expr(
+            Column::Path,
+            Expr::cust_with_values(
+                "CONCAT(?, SUBSTRING(`path`, ?))",
+                [
+                    sea_orm::Value::from(new_path.to_string()),
+                    sea_orm::Value::from(old_len + 1),
+                ],
+            ),
+        )
+        .col_expr(Column::Depth, Expr::col(Column::Depth).add(depth_delta))
+        .col_expr(Column::UpdatedAt, Expr::value(chrono::Utc::now()))
+        .filter(Column::Path.starts_with(old_path))
+        .exec(&txn)
+        .await
+        .map_err(AppError::Database)?;
+
+    txn.commit().await.map_err(AppError::Database)
+}
+
+/// 删除分类
+pub async fn delete(db: &DatabaseConnection, id: Uuid) -> Result<()> {
+    let result = Category::delete_by_id(id)
+        .exec(db)
+        .await
+        .map_err(AppError::Database)?;
+
+    if result.rows_affected == 0 {
+        return Err(AppError::NotFound);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,137 @@
+use crate::entities::article::{self, Model as ArticleModel};
+use crate::entities::collection::{Column, Entity as Collection, Model};
+use crate::entities::collection_item::{self, Entity as CollectionItem};
+use crate::errors::{AppError, Result};
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, PaginatorTrait,
+    QueryFilter, QueryOrder, Set,
+};
+use uuid::Uuid;
+
+/// 根据 ID 查找收藏夹
+pub async fn find_by_id(db: &DatabaseConnection, id: Uuid) -> Result<Option<Model>> {
+    Collection::find_by_id(id)
+        .one(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 查找用户的收藏夹（按创建时间倒序），`public_only` 为 true 时只返回公开的收藏夹
+pub async fn find_by_user(
+    db: &DatabaseConnection,
+    user_id: Uuid,
+    public_only: bool,
+) -> Result<Vec<Model>> {
+    let mut query = Collection::find().filter(Column::UserId.eq(user_id));
+    if public_only {
+        query = query.filter(Column::IsPublic.eq(true));
+    }
+
+    query
+        .order_by_desc(Column::CreatedAt)
+        .all(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 创建收藏夹
+pub async fn create(
+    db: &DatabaseConnection,
+    collection: crate::entities::collection::ActiveModel,
+) -> Result<Model> {
+    collection.insert(db).await.map_err(AppError::Database)
+}
+
+/// 更新收藏夹
+pub async fn update(
+    db: &DatabaseConnection,
+    id: Uuid,
+    mut collection: crate::entities::collection::ActiveModel,
+) -> Result<Model> {
+    collection.id = Set(id);
+    collection.update(db).await.map_err(AppError::Database)
+}
+
+/// 删除收藏夹（收录记录随外键级联删除）
+pub async fn delete(db: &DatabaseConnection, id: Uuid) -> Result<()> {
+    let result = Collection::delete_by_id(id)
+        .exec(db)
+        .await
+        .map_err(AppError::Database)?;
+
+    if result.rows_affected == 0 {
+        return Err(AppError::NotFound);
+    }
+
+    Ok(())
+}
+
+/// 收录文章（已收录时不做任何修改，幂等）
+pub async fn insert_item_ignore(
+    db: &DatabaseConnection,
+    item: collection_item::ActiveModel,
+) -> Result<()> {
+    CollectionItem::insert(item)
+        .on_conflict(
+            OnConflict::columns([
+                collection_item::Column::CollectionId,
+                collection_item::Column::ArticleId,
+            ])
+            .do_nothing()
+            .to_owned(),
+        )
+        .exec_without_returning(db)
+        .await
+        .map_err(AppError::Database)?;
+
+    Ok(())
+}
+
+/// 移出文章（不存在时不报错）
+pub async fn delete_item(db: &DatabaseConnection, collection_id: Uuid, article_id: Uuid) -> Result<()> {
+    CollectionItem::delete_many()
+        .filter(collection_item::Column::CollectionId.eq(collection_id))
+        .filter(collection_item::Column::ArticleId.eq(article_id))
+        .exec(db)
+        .await
+        .map_err(AppError::Database)?;
+
+    Ok(())
+}
+
+/// 分页查询收藏夹中的文章（按收录时间倒序，只返回 `viewer` 可读的文章）
+pub async fn find_articles_with_pagination(
+    db: &DatabaseConnection,
+    collection_id: Uuid,
+    viewer: Option<Uuid>,
+    offset: u64,
+    limit: u64,
+) -> Result<(Vec<ArticleModel>, u64)> {
+    let mut visibility = Condition::any().add(article::Column::IsPublic.eq(true));
+    if let Some(user_id) = viewer {
+        visibility = visibility.add(article::Column::UserId.eq(user_id));
+    }
+
+    let paginator = CollectionItem::find()
+        .find_also_related(article::Entity)
+        .filter(collection_item::Column::CollectionId.eq(collection_id))
+        .filter(article::Column::HiddenAt.is_null())
+        .filter(article::Column::DeletedAt.is_null())
+        .filter(visibility)
+        .order_by_desc(collection_item::Column::CreatedAt)
+        .paginate(db, limit);
+
+    let total = paginator.num_items().await.map_err(AppError::Database)?;
+
+    let page = offset.checked_div(limit).unwrap_or(0);
+    let articles = paginator
+        .fetch_page(page)
+        .await
+        .map_err(AppError::Database)?
+        .into_iter()
+        .filter_map(|(_, article)| article)
+        .collect();
+
+    Ok((articles, total))
+}
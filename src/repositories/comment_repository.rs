@@ -0,0 +1,78 @@
+use crate::entities::comment::{Column, Entity as Comment, Model};
+use crate::errors::{AppError, Result};
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
+    QueryOrder, Set,
+};
+use uuid::Uuid;
+
+/// 根据 ID 查找评论
+pub async fn find_by_id(db: &DatabaseConnection, id: Uuid) -> Result<Option<Model>> {
+    Comment::find_by_id(id)
+        .one(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 查询文章下的所有评论（按创建时间升序）
+pub async fn find_by_article(db: &DatabaseConnection, article_id: Uuid) -> Result<Vec<Model>> {
+    Comment::find()
+        .filter(Column::ArticleId.eq(article_id))
+        .order_by_asc(Column::CreatedAt)
+        .all(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 根据 ID 批量查找评论（不保证顺序）
+pub async fn find_by_ids(db: &DatabaseConnection, ids: &[Uuid]) -> Result<Vec<Model>> {
+    if ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    Comment::find()
+        .filter(Column::Id.is_in(ids.iter().copied()))
+        .all(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 用户在 `since` 之后是否发表过正文指纹相同的评论（`exclude` 为正在编辑的评论 ID）
+pub async fn exists_recent_by_content_hash(
+    db: &DatabaseConnection,
+    user_id: Uuid,
+    hash: &str,
+    since: DateTime<Utc>,
+    exclude: Option<Uuid>,
+) -> Result<bool> {
+    let mut query = Comment::find()
+        .filter(Column::UserId.eq(user_id))
+        .filter(Column::ContentHash.eq(hash))
+        .filter(Column::IsDeleted.eq(false))
+        .filter(Column::CreatedAt.gte(since));
+    if let Some(id) = exclude {
+        query = query.filter(Column::Id.ne(id));
+    }
+
+    let count = query.count(db).await.map_err(AppError::Database)?;
+    Ok(count > 0)
+}
+
+/// 创建评论
+pub async fn create(
+    db: &DatabaseConnection,
+    comment: crate::entities::comment::ActiveModel,
+) -> Result<Model> {
+    comment.insert(db).await.map_err(AppError::Database)
+}
+
+/// 更新评论
+pub async fn update(
+    db: &DatabaseConnection,
+    id: Uuid,
+    mut comment: crate::entities::comment::ActiveModel,
+) -> Result<Model> {
+    comment.id = Set(id);
+    comment.update(db).await.map_err(AppError::Database)
+}
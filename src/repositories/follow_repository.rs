@@ -0,0 +1,84 @@
+use crate::entities::follow::{ActiveModel, Column, Entity as Follow, Relation};
+use crate::entities::user::{self, Entity as User, Model as UserModel};
+use crate::errors::{AppError, Result};
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{
+    ColumnTrait, DatabaseConnection, EntityTrait, JoinType, PaginatorTrait, QueryFilter,
+    QueryOrder, QuerySelect, RelationTrait,
+};
+use uuid::Uuid;
+
+/// 关注用户（已关注时不做任何修改，幂等）
+pub async fn insert_ignore(db: &DatabaseConnection, follow: ActiveModel) -> Result<()> {
+    Follow::insert(follow)
+        .on_conflict(
+            OnConflict::columns([Column::FollowerId, Column::FolloweeId])
+                .do_nothing()
+                .to_owned(),
+        )
+        .exec_without_returning(db)
+        .await
+        .map_err(AppError::Database)?;
+
+    Ok(())
+}
+
+/// 取消关注（不存在时不报错）
+pub async fn delete(db: &DatabaseConnection, follower_id: Uuid, followee_id: Uuid) -> Result<()> {
+    Follow::delete_many()
+        .filter(Column::FollowerId.eq(follower_id))
+        .filter(Column::FolloweeId.eq(followee_id))
+        .exec(db)
+        .await
+        .map_err(AppError::Database)?;
+
+    Ok(())
+}
+
+/// 分页查询用户的关注者（按关注时间倒序，不包含已删除的用户）
+pub async fn find_followers_with_pagination(
+    db: &DatabaseConnection,
+    user_id: Uuid,
+    offset: u64,
+    limit: u64,
+) -> Result<(Vec<UserModel>, u64)> {
+    let query = User::find()
+        .join(JoinType::InnerJoin, Relation::Follower.def().rev())
+        .filter(Column::FolloweeId.eq(user_id));
+
+    paginate_users(db, query, offset, limit).await
+}
+
+/// 分页查询用户关注的人（按关注时间倒序，不包含已删除的用户）
+pub async fn find_following_with_pagination(
+    db: &DatabaseConnection,
+    user_id: Uuid,
+    offset: u64,
+    limit: u64,
+) -> Result<(Vec<UserModel>, u64)> {
+    let query = User::find()
+        .join(JoinType::InnerJoin, Relation::Followee.def().rev())
+        .filter(Column::FollowerId.eq(user_id));
+
+    paginate_users(db, query, offset, limit).await
+}
+
+async fn paginate_users(
+    db: &DatabaseConnection,
+    query: sea_orm::Select<User>,
+    offset: u64,
+    limit: u64,
+) -> Result<(Vec<UserModel>, u64)> {
+    let paginator = query
+        .filter(user::Column::DeletedAt.is_null())
+        .order_by_desc(Column::CreatedAt)
+        .order_by_desc(user::Column::Id)
+        .paginate(db, limit);
+
+    let total = paginator.num_items().await.map_err(AppError::Database)?;
+
+    let page = offset.checked_div(limit).unwrap_or(0);
+    let users = paginator.fetch_page(page).await.map_err(AppError::Database)?;
+
+    Ok((users, total))
+}
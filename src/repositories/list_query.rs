@@ -0,0 +1,100 @@
+use crate::errors::{AppError, Result};
+use crate::extractors::{Cursor, CursorPagination, Filter, ListField, ListQuery};
+use sea_orm::sea_query::{Expr, LikeExpr};
+use sea_orm::{
+    ColumnTrait, Condition, DatabaseConnection, EntityTrait, Order, PaginatorTrait, QueryFilter,
+    QueryOrder, QuerySelect, Select,
+};
+
+/// 将列表过滤与排序条件应用到查询上
+///
+/// `column` 把资源字段映射到数据库列；最后追加 `id` 作为排序的决胜字段，保证分页顺序稳定
+pub(crate) fn apply_list_query<E, F>(
+    mut select: Select<E>,
+    query: &ListQuery<F>,
+    id_column: E::Column,
+    column: impl Fn(F) -> E::Column,
+) -> Select<E>
+where
+    E: EntityTrait,
+    F: ListField,
+{
+    for filter in &query.filters {
+        select = match filter {
+            Filter::Equals(field, value) => select.filter(column(*field).eq(*value)),
+            Filter::After(field, value) => select.filter(column(*field).gte(*value)),
+            Filter::Before(field, value) => select.filter(column(*field).lt(*value)),
+            Filter::Contains(field, value) => select.filter(
+                Expr::col(column(*field).as_column_ref())
+                    .like(LikeExpr::new(like_pattern(value)).escape('\\')),
+            ),
+        };
+    }
+
+    for sort in &query.sort {
+        let order = if sort.descending { Order::Desc } else { Order::Asc };
+        select = select.order_by(column(sort.field), order);
+    }
+
+    select.order_by(id_column, Order::Desc)
+}
+
+/// 键集分页查询（按 `(created_at, id)` 倒序）
+///
+/// `select` 需已按 `created_at DESC, id DESC` 排序；多取一条用于判断是否还有下一页，
+/// 返回 `(本页数据, 是否还有下一页, 总数)`，总数只在 `include_total` 时查询。
+/// `created_at` 为 NULL 的记录不会出现在游标分页结果中
+pub(crate) async fn fetch_after_cursor<E>(
+    db: &DatabaseConnection,
+    select: Select<E>,
+    pagination: &CursorPagination,
+    created_at_column: E::Column,
+    id_column: E::Column,
+) -> Result<(Vec<E::Model>, bool, Option<u64>)>
+where
+    E: EntityTrait,
+    E::Model: Sync,
+{
+    let total = if pagination.include_total {
+        Some(select.clone().count(db).await.map_err(AppError::Database)?)
+    } else {
+        None
+    };
+
+    let mut select = select;
+    if let Some(Cursor { created_at, id }) = pagination.after {
+        select = select.filter(
+            Condition::any()
+                .add(created_at_column.lt(created_at))
+                .add(
+                    Condition::all()
+                        .add(created_at_column.eq(created_at))
+                        .add(id_column.lt(id)),
+                ),
+        );
+    }
+
+    let mut rows = select
+        .limit(pagination.limit + 1)
+        .all(db)
+        .await
+        .map_err(AppError::Database)?;
+    let has_more = rows.len() as u64 > pagination.limit;
+    rows.truncate(pagination.limit as usize);
+
+    Ok((rows, has_more, total))
+}
+
+/// 构造 `%...%` 模式，转义用户输入中的通配符
+fn like_pattern(value: &str) -> String {
+    let mut pattern = String::with_capacity(value.len() + 2);
+    pattern.push('%');
+    for c in value.chars() {
+        if matches!(c, '%' | '_' | '\\') {
+            pattern.push('\\');
+        }
+        pattern.push(c);
+    }
+    pattern.push('%');
+    pattern
+}
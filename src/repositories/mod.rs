@@ -1,8 +1,40 @@
 // Repository 模块导出
 pub mod user_repository;
 pub mod article_repository;
+pub mod category_repository;
+pub mod comment_repository;
+pub mod reaction_repository;
+pub mod bookmark_repository;
+pub mod revision_repository;
+pub mod attachment_repository;
+pub mod view_repository;
+pub mod series_repository;
+pub mod collection_repository;
+pub mod moderation_repository;
+pub mod translation_repository;
+pub mod share_link_repository;
+pub mod follow_repository;
+pub mod review_repository;
+mod list_query;
 
 // 避免 glob re-export 冲突，使用模块路径访问
 pub use user_repository as user;
 pub use article_repository as article;
+pub use category_repository as category;
+pub use comment_repository as comment;
+pub use reaction_repository as reaction;
+pub use bookmark_repository as bookmark;
+pub use revision_repository as revision;
+pub use attachment_repository as attachment;
+pub use view_repository as view;
+pub use series_repository as series;
+pub use collection_repository as collection;
+pub use moderation_repository as moderation;
+pub use translation_repository as translation;
+pub use share_link_repository as share_link;
+pub use follow_repository as follow;
+pub use review_repository as review;
 
+
+// 仓储层内部共用的查询工具
+pub(crate) use list_query::{apply_list_query, fetch_after_cursor};
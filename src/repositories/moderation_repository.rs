@@ -0,0 +1,225 @@
+use crate::entities::{article, comment};
+use crate::entities::content_report::{self, ReportReason, ReportStatus, ReportTarget};
+use crate::entities::moderation_action::{self, ModerationActionKind};
+use crate::entities::{ContentReport, ModerationAction};
+use crate::errors::{AppError, Result};
+use crate::repositories::article_repository;
+use chrono::{DateTime, Utc};
+use sea_orm::sea_query::{Expr, OnConflict};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, FromQueryResult,
+    IntoActiveModel, Order, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect,
+    TransactionTrait,
+};
+use uuid::Uuid;
+
+/// 提交举报（同一用户对同一内容已举报过时不做任何修改，幂等；自动举报没有举报人，不受此限制）
+pub async fn insert_report_ignore(
+    db: &DatabaseConnection,
+    report: content_report::ActiveModel,
+) -> Result<()> {
+    ContentReport::insert(report)
+        .on_conflict(
+            OnConflict::columns([
+                content_report::Column::TargetType,
+                content_report::Column::TargetId,
+                content_report::Column::ReporterId,
+            ])
+            .do_nothing()
+            .to_owned(),
+        )
+        .exec_without_returning(db)
+        .await
+        .map_err(AppError::Database)?;
+
+    Ok(())
+}
+
+/// 内容是否已有待处理的自动举报
+pub async fn has_open_automated_report(
+    db: &DatabaseConnection,
+    target_type: ReportTarget,
+    target_id: Uuid,
+) -> Result<bool> {
+    use content_report::Column;
+
+    let count = ContentReport::find()
+        .filter(Column::TargetType.eq(target_type))
+        .filter(Column::TargetId.eq(target_id))
+        .filter(Column::Reason.eq(ReportReason::Automated))
+        .filter(Column::Status.eq(ReportStatus::Open))
+        .count(db)
+        .await
+        .map_err(AppError::Database)?;
+
+    Ok(count > 0)
+}
+
+/// 待处理队列中的一项（同一内容的所有待处理举报合并为一项）
+#[derive(Debug, FromQueryResult)]
+pub struct QueueEntry {
+    pub target_type: ReportTarget,
+    pub target_id: Uuid,
+    pub report_count: i64,
+    pub first_reported_at: DateTime<Utc>,
+    pub last_reported_at: DateTime<Utc>,
+}
+
+/// 分页查询待处理队列（举报数多的在前，同样多时先举报的在前）
+pub async fn find_queue_with_pagination(
+    db: &DatabaseConnection,
+    offset: u64,
+    limit: u64,
+) -> Result<(Vec<QueueEntry>, u64)> {
+    use content_report::Column;
+
+    let paginator = ContentReport::find()
+        .select_only()
+        .column(Column::TargetType)
+        .column(Column::TargetId)
+        .column_as(Expr::col(Column::Id).count(), "report_count")
+        .column_as(Expr::col(Column::CreatedAt).min(), "first_reported_at")
+        .column_as(Expr::col(Column::CreatedAt).max(), "last_reported_at")
+        .filter(Column::Status.eq(ReportStatus::Open))
+        .group_by(Column::TargetType)
+        .group_by(Column::TargetId)
+        .order_by(Expr::cust("`report_count`"), Order::Desc)
+        .order_by(Expr::cust("`first_reported_at`"), Order::Asc)
+        .into_model::<QueueEntry>()
+        .paginate(db, limit);
+
+    let total = paginator.num_items().await.map_err(AppError::Database)?;
+
+    let page = offset.checked_div(limit).unwrap_or(0);
+    let entries = paginator.fetch_page(page).await.map_err(AppError::Database)?;
+
+    Ok((entries, total))
+}
+
+/// 批量统计多个内容的待处理举报原因
+pub async fn count_open_reasons(
+    db: &DatabaseConnection,
+    target_type: ReportTarget,
+    target_ids: &[Uuid],
+) -> Result<Vec<(Uuid, ReportReason, i64)>> {
+    use content_report::Column;
+
+    if target_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    ContentReport::find()
+        .select_only()
+        .column(Column::TargetId)
+        .column(Column::Reason)
+        .column_as(Expr::col(Column::Id).count(), "count")
+        .filter(Column::TargetType.eq(target_type))
+        .filter(Column::TargetId.is_in(target_ids.iter().copied()))
+        .filter(Column::Status.eq(ReportStatus::Open))
+        .group_by(Column::TargetId)
+        .group_by(Column::Reason)
+        .into_tuple()
+        .all(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 执行管理操作（同一事务）
+///
+/// 按操作类型隐藏、恢复或删除文章（放入回收站），或删除评论（警告和驳回不修改内容），
+/// 记录操作，并把该内容所有待处理举报标记为 `report_status`
+pub async fn apply_action(
+    db: &DatabaseConnection,
+    action: moderation_action::Model,
+    report_status: Option<ReportStatus>,
+) -> Result<()> {
+    let now = action.created_at;
+
+    let txn = db.begin().await.map_err(AppError::Database)?;
+
+    match (action.target_type, action.action) {
+        (ReportTarget::Article, ModerationActionKind::Hide | ModerationActionKind::Restore) => {
+            let hidden_at = (action.action == ModerationActionKind::Hide).then_some(now);
+            article::Entity::update_many()
+                .col_expr(article::Column::HiddenAt, Expr::value(hidden_at))
+                .filter(article::Column::Id.eq(action.target_id))
+                .exec(&txn)
+                .await
+                .map_err(AppError::Database)?;
+        }
+        (ReportTarget::Article, ModerationActionKind::Delete) => {
+            article_repository::soft_delete(article::Column::Id.eq(action.target_id))
+                .col_expr(article::Column::DeletedAt, Expr::value(Some(now)))
+                .exec(&txn)
+                .await
+                .map_err(AppError::Database)?;
+        }
+        (ReportTarget::Comment, ModerationActionKind::Delete) => {
+            // 与作者删除评论相同：软删除，保留占位以维持评论树结构
+            comment::Entity::update_many()
+                .col_expr(comment::Column::Content, Expr::value(String::new()))
+                .col_expr(comment::Column::ContentHash, Expr::value(Option::<String>::None))
+                .col_expr(comment::Column::IsDeleted, Expr::value(true))
+                .col_expr(comment::Column::UpdatedAt, Expr::value(now))
+                .filter(comment::Column::Id.eq(action.target_id))
+                .exec(&txn)
+                .await
+                .map_err(AppError::Database)?;
+        }
+        _ => {}
+    }
+
+    if let Some(status) = report_status {
+        use content_report::Column;
+
+        ContentReport::update_many()
+            .col_expr(Column::Status, Expr::value(status))
+            .col_expr(Column::ResolvedAt, Expr::value(Some(now)))
+            .col_expr(Column::ResolvedBy, Expr::value(action.moderator_id))
+            .filter(Column::TargetType.eq(action.target_type))
+            .filter(Column::TargetId.eq(action.target_id))
+            .filter(Column::Status.eq(ReportStatus::Open))
+            .exec(&txn)
+            .await
+            .map_err(AppError::Database)?;
+    }
+
+    ModerationAction::insert(action.into_active_model().reset_all())
+        .exec_without_returning(&txn)
+        .await
+        .map_err(AppError::Database)?;
+
+    txn.commit().await.map_err(AppError::Database)?;
+    Ok(())
+}
+
+/// 分页查询管理操作记录（按时间倒序），可按内容或内容作者过滤
+pub async fn find_actions_with_pagination(
+    db: &DatabaseConnection,
+    target_id: Option<Uuid>,
+    target_user_id: Option<Uuid>,
+    offset: u64,
+    limit: u64,
+) -> Result<(Vec<moderation_action::Model>, u64)> {
+    use moderation_action::Column;
+
+    let mut query = ModerationAction::find();
+    if let Some(target_id) = target_id {
+        query = query.filter(Column::TargetId.eq(target_id));
+    }
+    if let Some(user_id) = target_user_id {
+        query = query.filter(Column::TargetUserId.eq(user_id));
+    }
+
+    let paginator = query
+        .order_by_desc(Column::CreatedAt)
+        .order_by_desc(Column::Id)
+        .paginate(db, limit);
+
+    let total = paginator.num_items().await.map_err(AppError::Database)?;
+
+    let page = offset.checked_div(limit).unwrap_or(0);
+    let actions = paginator.fetch_page(page).await.map_err(AppError::Database)?;
+
+    Ok((actions, total))
+}
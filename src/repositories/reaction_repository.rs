@@ -0,0 +1,76 @@
+use crate::entities::reaction::{Column, Entity as Reaction, Model, ReactionKind};
+use crate::errors::{AppError, Result};
+use sea_orm::sea_query::{Expr, OnConflict};
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QuerySelect};
+use uuid::Uuid;
+
+/// 设置用户对文章的反应（已存在则覆盖，幂等）
+pub async fn upsert(
+    db: &DatabaseConnection,
+    reaction: crate::entities::reaction::ActiveModel,
+) -> Result<()> {
+    Reaction::insert(reaction)
+        .on_conflict(
+            OnConflict::columns([Column::UserId, Column::ArticleId])
+                .update_columns([Column::Reaction, Column::CreatedAt])
+                .to_owned(),
+        )
+        .exec_without_returning(db)
+        .await
+        .map_err(AppError::Database)?;
+
+    Ok(())
+}
+
+/// 取消用户对文章的反应（不存在时不报错）
+pub async fn delete(db: &DatabaseConnection, user_id: Uuid, article_id: Uuid) -> Result<()> {
+    Reaction::delete_many()
+        .filter(Column::UserId.eq(user_id))
+        .filter(Column::ArticleId.eq(article_id))
+        .exec(db)
+        .await
+        .map_err(AppError::Database)?;
+
+    Ok(())
+}
+
+/// 批量统计多篇文章的各类反应数量
+pub async fn count_by_articles(
+    db: &DatabaseConnection,
+    article_ids: &[Uuid],
+) -> Result<Vec<(Uuid, ReactionKind, i64)>> {
+    if article_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    Reaction::find()
+        .select_only()
+        .column(Column::ArticleId)
+        .column(Column::Reaction)
+        .column_as(Expr::col(Column::UserId).count(), "count")
+        .filter(Column::ArticleId.is_in(article_ids.iter().copied()))
+        .group_by(Column::ArticleId)
+        .group_by(Column::Reaction)
+        .into_tuple()
+        .all(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 查询用户在多篇文章上的反应
+pub async fn find_by_user_and_articles(
+    db: &DatabaseConnection,
+    user_id: Uuid,
+    article_ids: &[Uuid],
+) -> Result<Vec<Model>> {
+    if article_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    Reaction::find()
+        .filter(Column::UserId.eq(user_id))
+        .filter(Column::ArticleId.is_in(article_ids.iter().copied()))
+        .all(db)
+        .await
+        .map_err(AppError::Database)
+}
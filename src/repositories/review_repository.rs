@@ -0,0 +1,67 @@
+use crate::entities::article::{self, Entity as Article, Model as ArticleModel, ReviewStatus};
+use crate::entities::article_review_event::{ActiveModel, Column, Entity as ArticleReviewEvent, Model};
+use crate::errors::{AppError, Result};
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder,
+    TransactionTrait,
+};
+use uuid::Uuid;
+
+/// 变更文章审核状态并追加记录（同一事务，递增文章版本号）
+///
+/// 只在文章当前状态仍为 `from` 时更新；并发变更导致状态不一致时返回 412。
+/// `is_public` 非空时同时修改公开状态（发布时使用）
+pub async fn transition(
+    db: &DatabaseConnection,
+    article_id: Uuid,
+    from: ReviewStatus,
+    to: ReviewStatus,
+    is_public: Option<bool>,
+    event: ActiveModel,
+) -> Result<ArticleModel> {
+    let txn = db.begin().await.map_err(AppError::Database)?;
+
+    let mut update = Article::update_many()
+        .col_expr(article::Column::ReviewStatus, Expr::value(to))
+        .col_expr(article::Column::Version, Expr::col(article::Column::Version).add(1))
+        .col_expr(article::Column::UpdatedAt, Expr::value(chrono::Utc::now()));
+    if let Some(is_public) = is_public {
+        update = update.col_expr(article::Column::IsPublic, Expr::value(is_public));
+    }
+    let result = update
+        .filter(article::Column::Id.eq(article_id))
+        .filter(article::Column::ReviewStatus.eq(from))
+        .filter(article::Column::DeletedAt.is_null())
+        .exec(&txn)
+        .await
+        .map_err(AppError::Database)?;
+    if result.rows_affected == 0 {
+        return Err(AppError::version_conflict());
+    }
+
+    event.insert(&txn).await.map_err(AppError::Database)?;
+    let updated = Article::find_by_id(article_id)
+        .one(&txn)
+        .await
+        .map_err(AppError::Database)?
+        .ok_or(AppError::NotFound)?;
+
+    txn.commit().await.map_err(AppError::Database)?;
+    Ok(updated)
+}
+
+/// 追加一条审核状态变更记录（状态已随文章更新写入时使用）
+pub async fn record(db: &DatabaseConnection, event: ActiveModel) -> Result<Model> {
+    event.insert(db).await.map_err(AppError::Database)
+}
+
+/// 查询文章的审核记录（按时间正序）
+pub async fn find_by_article(db: &DatabaseConnection, article_id: Uuid) -> Result<Vec<Model>> {
+    ArticleReviewEvent::find()
+        .filter(Column::ArticleId.eq(article_id))
+        .order_by_asc(Column::CreatedAt)
+        .all(db)
+        .await
+        .map_err(AppError::Database)
+}
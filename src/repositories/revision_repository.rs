@@ -0,0 +1,81 @@
+use crate::entities::article_revision::{Column, Entity as ArticleRevision, Model};
+use crate::errors::{AppError, Result};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, QuerySelect, Set,
+};
+use uuid::Uuid;
+
+/// 查询文章的全部修订（按修订号倒序）
+pub async fn find_by_article(db: &DatabaseConnection, article_id: Uuid) -> Result<Vec<Model>> {
+    ArticleRevision::find()
+        .filter(Column::ArticleId.eq(article_id))
+        .order_by_desc(Column::RevisionNumber)
+        .all(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 根据修订号查找修订
+pub async fn find_by_number(
+    db: &DatabaseConnection,
+    article_id: Uuid,
+    revision_number: i32,
+) -> Result<Option<Model>> {
+    ArticleRevision::find()
+        .filter(Column::ArticleId.eq(article_id))
+        .filter(Column::RevisionNumber.eq(revision_number))
+        .one(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 追加一条修订，修订号自动取当前最大值加一
+///
+/// 接受任意连接（包括事务），以便与文章写入放在同一个事务中；
+/// 并发写入时由 `(article_id, revision_number)` 唯一索引兜底
+pub async fn append<C: ConnectionTrait>(
+    conn: &C,
+    mut revision: crate::entities::article_revision::ActiveModel,
+) -> Result<Model> {
+    let article_id = revision
+        .article_id
+        .clone()
+        .take()
+        .ok_or_else(|| AppError::Internal(anyhow::anyhow!("修订缺少 article_id")))?;
+
+    let latest: Option<i32> = ArticleRevision::find()
+        .select_only()
+        .column_as(Column::RevisionNumber.max(), "max")
+        .filter(Column::ArticleId.eq(article_id))
+        .into_tuple::<Option<i32>>()
+        .one(conn)
+        .await
+        .map_err(AppError::Database)?
+        .flatten();
+
+    revision.revision_number = Set(latest.unwrap_or(0) + 1);
+    revision.insert(conn).await.map_err(AppError::Database)
+}
+
+/// 文章还没有任何修订时追加基线修订，已有修订时什么也不做
+pub async fn append_baseline<C: ConnectionTrait>(
+    conn: &C,
+    baseline: crate::entities::article_revision::ActiveModel,
+) -> Result<()> {
+    let article_id = baseline
+        .article_id
+        .clone()
+        .take()
+        .ok_or_else(|| AppError::Internal(anyhow::anyhow!("修订缺少 article_id")))?;
+
+    let existing = ArticleRevision::find()
+        .filter(Column::ArticleId.eq(article_id))
+        .one(conn)
+        .await
+        .map_err(AppError::Database)?;
+    if existing.is_none() {
+        append(conn, baseline).await?;
+    }
+    Ok(())
+}
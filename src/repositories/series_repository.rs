@@ -0,0 +1,141 @@
+use crate::entities::article;
+use crate::entities::series::{Column, Entity as Series, Model};
+use crate::errors::{AppError, Result};
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, FromQueryResult, QueryFilter,
+    QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+use uuid::Uuid;
+
+/// 根据 ID 查找系列
+pub async fn find_by_id(db: &DatabaseConnection, id: Uuid) -> Result<Option<Model>> {
+    Series::find_by_id(id)
+        .one(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 查找作者的全部系列（按创建时间倒序）
+pub async fn find_by_user(db: &DatabaseConnection, user_id: Uuid) -> Result<Vec<Model>> {
+    Series::find()
+        .filter(Column::UserId.eq(user_id))
+        .order_by_desc(Column::CreatedAt)
+        .all(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 创建系列
+pub async fn create(
+    db: &DatabaseConnection,
+    series: crate::entities::series::ActiveModel,
+) -> Result<Model> {
+    series.insert(db).await.map_err(AppError::Database)
+}
+
+/// 更新系列
+pub async fn update(
+    db: &DatabaseConnection,
+    id: Uuid,
+    mut series: crate::entities::series::ActiveModel,
+) -> Result<Model> {
+    series.id = Set(id);
+    series.update(db).await.map_err(AppError::Database)
+}
+
+/// 删除系列（成员文章移出系列，文章本身保留）
+pub async fn delete(db: &DatabaseConnection, id: Uuid) -> Result<()> {
+    let txn = db.begin().await.map_err(AppError::Database)?;
+
+    article::Entity::update_many()
+        .col_expr(article::Column::SeriesId, Expr::value(Option::<Uuid>::None))
+        .col_expr(article::Column::SeriesPosition, Expr::value(Option::<i32>::None))
+        .filter(article::Column::SeriesId.eq(id))
+        .exec(&txn)
+        .await
+        .map_err(AppError::Database)?;
+
+    let result = Series::delete_by_id(id)
+        .exec(&txn)
+        .await
+        .map_err(AppError::Database)?;
+    if result.rows_affected == 0 {
+        return Err(AppError::NotFound);
+    }
+
+    txn.commit().await.map_err(AppError::Database)
+}
+
+/// 系列成员（只包含排序和可见性判断需要的列）
+#[derive(Debug, Clone, FromQueryResult)]
+pub struct SeriesMember {
+    pub id: Uuid,
+    pub title: String,
+    pub series_position: Option<i32>,
+    pub user_id: Option<Uuid>,
+    pub is_public: Option<bool>,
+    pub hidden_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// 查询系列中的全部文章（按位置排序，不过滤可见性，包含被隐藏的文章，不包含已删除的文章）
+pub async fn find_members(db: &DatabaseConnection, series_id: Uuid) -> Result<Vec<SeriesMember>> {
+    article::Entity::find()
+        .select_only()
+        .column(article::Column::Id)
+        .column(article::Column::Title)
+        .column(article::Column::SeriesPosition)
+        .column(article::Column::UserId)
+        .column(article::Column::IsPublic)
+        .column(article::Column::HiddenAt)
+        .filter(article::Column::SeriesId.eq(series_id))
+        .filter(article::Column::DeletedAt.is_null())
+        .order_by_asc(article::Column::SeriesPosition)
+        .order_by_asc(article::Column::Id)
+        .into_model::<SeriesMember>()
+        .all(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 重写系列成员（同一事务）
+///
+/// `removed` 中的文章移出系列，`ordered` 中的文章依次设置为位置 1、2、3……
+pub async fn set_members(
+    db: &DatabaseConnection,
+    series_id: Uuid,
+    ordered: &[Uuid],
+    removed: &[Uuid],
+) -> Result<()> {
+    let txn = db.begin().await.map_err(AppError::Database)?;
+
+    if !removed.is_empty() {
+        article::Entity::update_many()
+            .col_expr(article::Column::SeriesId, Expr::value(Option::<Uuid>::None))
+            .col_expr(article::Column::SeriesPosition, Expr::value(Option::<i32>::None))
+            .filter(article::Column::Id.is_in(removed.iter().copied()))
+            .filter(article::Column::SeriesId.eq(series_id))
+            .exec(&txn)
+            .await
+            .map_err(AppError::Database)?;
+    }
+
+    for (index, article_id) in ordered.iter().enumerate() {
+        article::Entity::update_many()
+            .col_expr(article::Column::SeriesId, Expr::value(Some(series_id)))
+            .col_expr(article::Column::SeriesPosition, Expr::value(Some(index as i32 + 1)))
+            .filter(article::Column::Id.eq(*article_id))
+            .exec(&txn)
+            .await
+            .map_err(AppError::Database)?;
+    }
+
+    Series::update_many()
+        .col_expr(Column::UpdatedAt, Expr::value(chrono::Utc::now()))
+        .filter(Column::Id.eq(series_id))
+        .exec(&txn)
+        .await
+        .map_err(AppError::Database)?;
+
+    txn.commit().await.map_err(AppError::Database)
+}
@@ -0,0 +1,60 @@
+use crate::entities::article_share_link::{self, Column, Model};
+use crate::entities::ArticleShareLink;
+use crate::errors::{AppError, Result};
+use chrono::{DateTime, Utc};
+use sea_orm::sea_query::Expr;
+use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder};
+use uuid::Uuid;
+
+/// 创建分享链接
+pub async fn create(
+    db: &DatabaseConnection,
+    link: article_share_link::ActiveModel,
+) -> Result<Model> {
+    link.insert(db).await.map_err(AppError::Database)
+}
+
+/// 根据 ID 查找分享链接
+pub async fn find_by_id(db: &DatabaseConnection, id: Uuid) -> Result<Option<Model>> {
+    ArticleShareLink::find_by_id(id)
+        .one(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 查询文章的所有分享链接（按创建时间倒序）
+pub async fn find_by_article(db: &DatabaseConnection, article_id: Uuid) -> Result<Vec<Model>> {
+    ArticleShareLink::find()
+        .filter(Column::ArticleId.eq(article_id))
+        .order_by_desc(Column::CreatedAt)
+        .order_by_desc(Column::Id)
+        .all(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 撤销分享链接（已撤销的保留第一次撤销的时间，幂等）
+pub async fn revoke(db: &DatabaseConnection, id: Uuid, now: DateTime<Utc>) -> Result<()> {
+    ArticleShareLink::update_many()
+        .col_expr(Column::RevokedAt, Expr::value(Some(now)))
+        .filter(Column::Id.eq(id))
+        .filter(Column::RevokedAt.is_null())
+        .exec(db)
+        .await
+        .map_err(AppError::Database)?;
+
+    Ok(())
+}
+
+/// 记录一次使用（原子递增使用次数）
+pub async fn record_use(db: &DatabaseConnection, id: Uuid, now: DateTime<Utc>) -> Result<()> {
+    ArticleShareLink::update_many()
+        .col_expr(Column::UseCount, Expr::col(Column::UseCount).add(1))
+        .col_expr(Column::LastUsedAt, Expr::value(Some(now)))
+        .filter(Column::Id.eq(id))
+        .exec(db)
+        .await
+        .map_err(AppError::Database)?;
+
+    Ok(())
+}
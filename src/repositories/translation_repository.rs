@@ -0,0 +1,129 @@
+use crate::entities::article;
+use crate::entities::article_translation::{self, Column, Model};
+use crate::entities::ArticleTranslation;
+use crate::errors::{AppError, Result};
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, TransactionTrait,
+};
+use uuid::Uuid;
+
+/// 查询文章的所有译文（按语言排序）
+pub async fn find_by_article(
+    db: &DatabaseConnection,
+    article_id: Uuid,
+) -> Result<Vec<Model>> {
+    ArticleTranslation::find()
+        .filter(Column::ArticleId.eq(article_id))
+        .order_by_asc(Column::Language)
+        .all(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 查询文章指定语言的译文
+pub async fn find_by_article_and_language(
+    db: &DatabaseConnection,
+    article_id: Uuid,
+    language: &str,
+) -> Result<Option<Model>> {
+    ArticleTranslation::find()
+        .filter(Column::ArticleId.eq(article_id))
+        .filter(Column::Language.eq(language))
+        .one(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 根据语言和 slug 查找译文
+pub async fn find_by_slug(
+    db: &DatabaseConnection,
+    language: &str,
+    slug: &str,
+) -> Result<Option<Model>> {
+    ArticleTranslation::find()
+        .filter(Column::Language.eq(language))
+        .filter(Column::Slug.eq(slug))
+        .one(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 批量查询多篇文章已发布的译文（按语言排序）
+pub async fn find_published_by_articles(
+    db: &DatabaseConnection,
+    article_ids: &[Uuid],
+) -> Result<Vec<Model>> {
+    if article_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    ArticleTranslation::find()
+        .filter(Column::ArticleId.is_in(article_ids.iter().copied()))
+        .filter(Column::IsPublished.eq(true))
+        .order_by_asc(Column::Language)
+        .all(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 创建译文（同一事务递增文章版本号，使文章的 ETag 失效）
+pub async fn create(
+    db: &DatabaseConnection,
+    translation: article_translation::ActiveModel,
+) -> Result<Model> {
+    let txn = db.begin().await.map_err(AppError::Database)?;
+
+    let created = translation.insert(&txn).await.map_err(AppError::Database)?;
+    bump_article_version(&txn, created.article_id).await?;
+
+    txn.commit().await.map_err(AppError::Database)?;
+    Ok(created)
+}
+
+/// 更新译文（同一事务递增文章版本号）
+pub async fn update(
+    db: &DatabaseConnection,
+    translation: article_translation::ActiveModel,
+) -> Result<Model> {
+    let txn = db.begin().await.map_err(AppError::Database)?;
+
+    let updated = translation.update(&txn).await.map_err(AppError::Database)?;
+    bump_article_version(&txn, updated.article_id).await?;
+
+    txn.commit().await.map_err(AppError::Database)?;
+    Ok(updated)
+}
+
+/// 删除译文（同一事务递增文章版本号）
+pub async fn delete(
+    db: &DatabaseConnection,
+    article_id: Uuid,
+    language: &str,
+) -> Result<()> {
+    let txn = db.begin().await.map_err(AppError::Database)?;
+
+    ArticleTranslation::delete_many()
+        .filter(Column::ArticleId.eq(article_id))
+        .filter(Column::Language.eq(language))
+        .exec(&txn)
+        .await
+        .map_err(AppError::Database)?;
+    bump_article_version(&txn, article_id).await?;
+
+    txn.commit().await.map_err(AppError::Database)?;
+    Ok(())
+}
+
+/// 递增文章版本号（译文变化后同一文章的响应随之变化）
+async fn bump_article_version<C: ConnectionTrait>(db: &C, article_id: Uuid) -> Result<()> {
+    article::Entity::update_many()
+        .col_expr(article::Column::Version, Expr::col(article::Column::Version).add(1))
+        .filter(article::Column::Id.eq(article_id))
+        .exec(db)
+        .await
+        .map_err(AppError::Database)?;
+
+    Ok(())
+}
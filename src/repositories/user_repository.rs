@@ -1,14 +1,101 @@
 use crate::entities::user::{Entity as User, Model};
 use crate::errors::{AppError, Result};
+use crate::extractors::{CursorPagination, ListQuery};
+use crate::models::UserField;
+use crate::repositories::{apply_list_query, fetch_after_cursor};
+use sea_orm::sea_query::Expr;
 use sea_orm::{
     ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
-    QueryOrder, Set,
+    QueryOrder, QuerySelect, Set, TransactionTrait,
 };
 use uuid::Uuid;
 
+/// 未删除的用户查询，除回收站外所有读取路径都以此为起点
+fn active() -> sea_orm::Select<User> {
+    User::find().filter(crate::entities::user::Column::DeletedAt.is_null())
+}
+
+/// 用户是否存在且未被删除（认证时使用，只查询主键）
+pub async fn is_active(db: &DatabaseConnection, id: Uuid) -> Result<bool> {
+    let found: Option<Uuid> = active()
+        .select_only()
+        .column(crate::entities::user::Column::Id)
+        .filter(crate::entities::user::Column::Id.eq(id))
+        .into_tuple()
+        .one(db)
+        .await
+        .map_err(AppError::Database)?;
+
+    Ok(found.is_some())
+}
+
+/// 根据 ID 列表批量查找用户
+pub async fn find_by_ids(db: &DatabaseConnection, ids: &[Uuid]) -> Result<Vec<Model>> {
+    if ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    active()
+        .filter(crate::entities::user::Column::Id.is_in(ids.iter().copied()))
+        .all(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 游标分页查询用户列表（按创建时间倒序），返回 `(用户, 是否还有下一页, 总数)`
+pub async fn find_after_cursor(
+    db: &DatabaseConnection,
+    list_query: &ListQuery<UserField>,
+    pagination: &CursorPagination,
+) -> Result<(Vec<Model>, bool, Option<u64>)> {
+    use crate::entities::user::Column;
+
+    let query = apply_list_query(active(), list_query, Column::Id, user_column);
+
+    fetch_after_cursor(db, query, pagination, Column::CreatedAt, Column::Id).await
+}
+
+/// 用户列表字段对应的数据库列
+fn user_column(field: UserField) -> crate::entities::user::Column {
+    use crate::entities::user::Column;
+
+    match field {
+        UserField::Username => Column::Username,
+        UserField::CreatedAt => Column::CreatedAt,
+    }
+}
+
+/// 查询发表过公开文章的用户的 `(ID, 修改时间)`（用于站点地图）
+pub async fn find_public_authors(
+    db: &DatabaseConnection,
+) -> Result<Vec<(Uuid, chrono::DateTime<chrono::Utc>)>> {
+    use crate::entities::{article, user::Column};
+
+    active()
+        .select_only()
+        .columns([Column::Id, Column::UpdatedAt])
+        .filter(
+            Column::Id.in_subquery(
+                sea_orm::sea_query::Query::select()
+                    .column(article::Column::UserId)
+                    .from(article::Entity)
+                    .and_where(article::Column::IsPublic.eq(true))
+                    .and_where(article::Column::HiddenAt.is_null())
+                    .and_where(article::Column::DeletedAt.is_null())
+                    .to_owned(),
+            ),
+        )
+        .order_by_asc(Column::CreatedAt)
+        .into_tuple()
+        .all(db)
+        .await
+        .map_err(AppError::Database)
+}
+
 /// 根据 ID 查找用户
 pub async fn find_by_id(db: &DatabaseConnection, id: Uuid) -> Result<Option<Model>> {
-    User::find_by_id(id)
+    active()
+        .filter(crate::entities::user::Column::Id.eq(id))
         .one(db)
         .await
         .map_err(AppError::Database)
@@ -16,7 +103,7 @@ pub async fn find_by_id(db: &DatabaseConnection, id: Uuid) -> Result<Option<Mode
 
 /// 根据用户名查找用户
 pub async fn find_by_username(db: &DatabaseConnection, username: &str) -> Result<Option<Model>> {
-    User::find()
+    active()
         .filter(crate::entities::user::Column::Username.eq(username))
         .one(db)
         .await
@@ -25,14 +112,14 @@ pub async fn find_by_username(db: &DatabaseConnection, username: &str) -> Result
 
 /// 根据邮箱查找用户
 pub async fn find_by_email(db: &DatabaseConnection, email: &str) -> Result<Option<Model>> {
-    User::find()
+    active()
         .filter(crate::entities::user::Column::Email.eq(email))
         .one(db)
         .await
         .map_err(AppError::Database)
 }
 
-/// 检查用户名或邮箱是否已存在
+/// 检查用户名或邮箱是否已存在（包含回收站中的用户，彻底删除前用户名和邮箱仍被占用）
 pub async fn exists_by_username_or_email(
     db: &DatabaseConnection,
     username: &str,
@@ -59,39 +146,166 @@ pub async fn create(
     user.insert(db).await.map_err(AppError::Database)
 }
 
-/// 更新用户
+/// 更新用户（递增版本号）
+///
+/// 只有数据库中的版本号仍等于 `user` 读取时的版本号才会更新，否则返回 412
 pub async fn update(
     db: &DatabaseConnection,
     id: Uuid,
     mut user: crate::entities::user::ActiveModel,
 ) -> Result<Model> {
+    let current = match user.version {
+        sea_orm::ActiveValue::Set(v) | sea_orm::ActiveValue::Unchanged(v) => v,
+        sea_orm::ActiveValue::NotSet => {
+            return Err(AppError::Internal(anyhow::anyhow!("更新用户缺少版本号")));
+        }
+    };
     user.id = Set(id);
-    let updated = user.update(db).await.map_err(AppError::Database)?;
-    Ok(updated)
-}
+    user.version = Set(current + 1);
 
-/// 删除用户
-pub async fn delete(db: &DatabaseConnection, id: Uuid) -> Result<()> {
-    let result = User::delete_by_id(id)
+    User::update(user)
+        .filter(crate::entities::user::Column::Version.eq(current))
         .exec(db)
         .await
-        .map_err(AppError::Database)?;
+        .map_err(AppError::from_versioned_update)
+}
+
+/// 删除用户（软删除，同一事务把该用户的文章以相同的删除时间一起放入回收站）
+pub async fn delete(
+    db: &DatabaseConnection,
+    id: Uuid,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Result<()> {
+    use crate::entities::{article, user::Column};
+
+    let txn = db.begin().await.map_err(AppError::Database)?;
 
+    let result = User::update_many()
+        .col_expr(Column::DeletedAt, Expr::value(Some(now)))
+        .filter(Column::Id.eq(id))
+        .filter(Column::DeletedAt.is_null())
+        .exec(&txn)
+        .await
+        .map_err(AppError::Database)?;
     if result.rows_affected == 0 {
         return Err(AppError::NotFound);
     }
 
+    crate::repositories::article_repository::soft_delete(article::Column::UserId.eq(id))
+        .col_expr(article::Column::DeletedAt, Expr::value(Some(now)))
+        .exec(&txn)
+        .await
+        .map_err(AppError::Database)?;
+
+    txn.commit().await.map_err(AppError::Database)
+}
+
+/// 根据 ID 查找回收站中的用户
+pub async fn find_deleted_by_id(db: &DatabaseConnection, id: Uuid) -> Result<Option<Model>> {
+    use crate::entities::user::Column;
+
+    User::find_by_id(id)
+        .filter(Column::DeletedAt.is_not_null())
+        .one(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 分页查询回收站中的用户（按删除时间倒序）
+pub async fn find_deleted_with_pagination(
+    db: &DatabaseConnection,
+    offset: u64,
+    limit: u64,
+) -> Result<(Vec<Model>, u64)> {
+    use crate::entities::user::Column;
+
+    let paginator = User::find()
+        .filter(Column::DeletedAt.is_not_null())
+        .order_by_desc(Column::DeletedAt)
+        .order_by_desc(Column::Id)
+        .paginate(db, limit);
+
+    let total = paginator.num_items().await.map_err(AppError::Database)?;
+
+    let page = offset.checked_div(limit).unwrap_or(0);
+    let users = paginator.fetch_page(page).await.map_err(AppError::Database)?;
+
+    Ok((users, total))
+}
+
+/// 从回收站恢复用户（同一事务恢复随用户一起删除的文章，单独删除的文章仍留在回收站）
+pub async fn restore(
+    db: &DatabaseConnection,
+    id: Uuid,
+    deleted_at: chrono::DateTime<chrono::Utc>,
+) -> Result<()> {
+    use crate::entities::{article, user::Column};
+
+    let txn = db.begin().await.map_err(AppError::Database)?;
+
+    User::update_many()
+        .col_expr(Column::DeletedAt, Expr::value(Option::<chrono::DateTime<chrono::Utc>>::None))
+        .filter(Column::Id.eq(id))
+        .exec(&txn)
+        .await
+        .map_err(AppError::Database)?;
+
+    article::Entity::update_many()
+        .col_expr(
+            article::Column::DeletedAt,
+            Expr::value(Option::<chrono::DateTime<chrono::Utc>>::None),
+        )
+        .filter(article::Column::UserId.eq(id))
+        .filter(article::Column::DeletedAt.eq(deleted_at))
+        .exec(&txn)
+        .await
+        .map_err(AppError::Database)?;
+
+    txn.commit().await.map_err(AppError::Database)
+}
+
+/// 查询删除时间早于 `before` 的用户 ID（用于彻底删除，跳过 `exclude` 中本轮已失败的用户）
+pub async fn find_deleted_before(
+    db: &DatabaseConnection,
+    before: chrono::DateTime<chrono::Utc>,
+    exclude: &[Uuid],
+    limit: u64,
+) -> Result<Vec<Uuid>> {
+    use crate::entities::user::Column;
+
+    User::find()
+        .select_only()
+        .column(Column::Id)
+        .filter(Column::DeletedAt.lt(before))
+        .filter(Column::Id.is_not_in(exclude.iter().copied()))
+        .order_by_asc(Column::DeletedAt)
+        .limit(limit)
+        .into_tuple()
+        .all(db)
+        .await
+        .map_err(AppError::Database)
+}
+
+/// 彻底删除用户（调用方需先彻底删除其文章）
+pub async fn purge(db: &DatabaseConnection, id: Uuid) -> Result<()> {
+    User::delete_by_id(id)
+        .exec(db)
+        .await
+        .map_err(AppError::Database)?;
+
     Ok(())
 }
 
-/// 分页查询用户列表
+/// 分页查询用户列表（`page` 从 0 开始）
 pub async fn find_all_with_pagination(
     db: &DatabaseConnection,
+    list_query: &ListQuery<UserField>,
     page: u64,
     page_size: u64,
 ) -> Result<(Vec<Model>, u64)> {
-    let paginator = User::find()
-        .order_by_desc(crate::entities::user::Column::CreatedAt)
+    use crate::entities::user::Column;
+
+    let paginator = apply_list_query(active(), list_query, Column::Id, user_column)
         .paginate(db, page_size);
 
     let total = paginator.num_items().await.map_err(AppError::Database)?;
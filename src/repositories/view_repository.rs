@@ -0,0 +1,102 @@
+use crate::entities::article;
+use crate::entities::article_view_bucket::{ActiveModel, Column, Entity as ArticleViewBucket};
+use crate::errors::{AppError, Result};
+use chrono::{DateTime, Utc};
+use sea_orm::sea_query::{Expr, OnConflict, Query};
+use sea_orm::{
+    ColumnTrait, DatabaseConnection, EntityTrait, Order, QueryFilter, QueryOrder, QuerySelect,
+    Set, TransactionTrait,
+};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// 批量累加浏览量（同一事务）
+///
+/// `views` 中每一项为 `(文章 ID, 小时桶起始时间, 新增浏览量)`：
+/// 累加到 `articles.view_count`，同时写入按小时聚合的浏览量表
+pub async fn add_views(
+    db: &DatabaseConnection,
+    views: &[(Uuid, DateTime<Utc>, u64)],
+) -> Result<()> {
+    if views.is_empty() {
+        return Ok(());
+    }
+
+    let mut per_article: HashMap<Uuid, u64> = HashMap::new();
+    for (article_id, _, count) in views {
+        *per_article.entry(*article_id).or_default() += count;
+    }
+
+    let txn = db.begin().await.map_err(AppError::Database)?;
+
+    for (article_id, count) in per_article {
+        article::Entity::update_many()
+            .col_expr(
+                article::Column::ViewCount,
+                Expr::col(article::Column::ViewCount).add(count as i64),
+            )
+            .filter(article::Column::Id.eq(article_id))
+            .exec(&txn)
+            .await
+            .map_err(AppError::Database)?;
+    }
+
+    let buckets = views.iter().map(|(article_id, bucket_start, count)| ActiveModel {
+        article_id: Set(*article_id),
+        bucket_start: Set(*bucket_start),
+        views: Set(*count as i32),
+    });
+    ArticleViewBucket::insert_many(buckets)
+        .on_conflict(
+            OnConflict::columns([Column::ArticleId, Column::BucketStart])
+                .value(Column::Views, Expr::cust("`views` + VALUES(`views`)"))
+                .to_owned(),
+        )
+        .exec_without_returning(&txn)
+        .await
+        .map_err(AppError::Database)?;
+
+    txn.commit().await.map_err(AppError::Database)
+}
+
+/// 查询热门公开文章
+///
+/// 得分为时间窗口内每个小时桶的浏览量乘以 `exp(-decay * 距今秒数)` 之和，
+/// 返回 `(文章 ID, 得分, 窗口内浏览量)`，按得分倒序
+pub async fn find_trending(
+    db: &DatabaseConnection,
+    since: DateTime<Utc>,
+    decay_per_second: f64,
+    limit: u64,
+) -> Result<Vec<(Uuid, f64, i64)>> {
+    ArticleViewBucket::find()
+        .select_only()
+        .column(Column::ArticleId)
+        .column_as(
+            Expr::cust_with_values(
+                "SUM(`views` * EXP(-? * TIMESTAMPDIFF(SECOND, `bucket_start`, UTC_TIMESTAMP())))",
+                [decay_per_second],
+            ),
+            "score",
+        )
+        .column_as(Expr::cust("CAST(SUM(`views`) AS SIGNED)"), "window_views")
+        .filter(Column::BucketStart.gte(since))
+        .filter(
+            Column::ArticleId.in_subquery(
+                Query::select()
+                    .column(article::Column::Id)
+                    .from(article::Entity)
+                    .and_where(article::Column::IsPublic.eq(true))
+                    .and_where(article::Column::HiddenAt.is_null())
+                    .and_where(article::Column::DeletedAt.is_null())
+                    .to_owned(),
+            ),
+        )
+        .group_by(Column::ArticleId)
+        .order_by(Expr::cust("`score`"), Order::Desc)
+        .limit(limit)
+        .into_tuple()
+        .all(db)
+        .await
+        .map_err(AppError::Database)
+}
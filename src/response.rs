@@ -1,7 +1,7 @@
 use crate::{utils::deserialize_status_code, utils::serialize_status_code};
 use axum::{
-    http::StatusCode,
-    response::{IntoResponse, Response},
+    http::{header, HeaderValue, StatusCode},
+    response::{IntoResponse, IntoResponseParts, Response, ResponseParts},
     Json,
 };
 use chrono::Utc;
@@ -77,6 +77,16 @@ impl<T> ApiResponse<T> {
         }
     }
 
+    /// 创建失败响应（带数据，例如逐条的校验错误）
+    pub fn error_with_data(code: StatusCode, message: impl Into<String>, data: T) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            data: Some(data),
+            timestamp: Self::get_timestamp(),
+        }
+    }
+
     fn get_timestamp() -> String {
         let utc = Utc::now();
         let china_offset = FixedOffset::east_opt(8 * 3600).unwrap(); // UTC+8
@@ -95,6 +105,30 @@ where
     }
 }
 
+/// `ETag` 响应头，值为资源版本号（强标签，如 `"3"`）
+///
+/// 读取和写入的响应使用同一个标签，客户端可以直接带上它作为 `If-Match` 或 `If-None-Match`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ETag(pub i32);
+
+impl ETag {
+    /// 客户端缓存仍然有效时的 304 响应
+    pub fn not_modified(self) -> Response {
+        (StatusCode::NOT_MODIFIED, self, ()).into_response()
+    }
+}
+
+impl IntoResponseParts for ETag {
+    type Error = std::convert::Infallible;
+
+    fn into_response_parts(self, mut res: ResponseParts) -> Result<ResponseParts, Self::Error> {
+        if let Ok(value) = HeaderValue::from_str(&format!("\"{}\"", self.0)) {
+            res.headers_mut().insert(header::ETAG, value);
+        }
+        Ok(res)
+    }
+}
+
 /// 为 Result 类型提供便捷转换
 impl<T, E> From<Result<T, E>> for ApiResponse<T>
 where
@@ -1,15 +1,70 @@
-use axum::{routing::{get, post}, Router};
-use crate::controllers::article_controller;
+use axum::{extract::DefaultBodyLimit, routing::{get, post, put, delete}, Router};
+use crate::controllers::{
+    article_controller, engagement_controller, moderation_controller, review_controller,
+    series_controller, share_controller, transfer_controller, translation_controller,
+};
 use crate::AppState;
 
 /// 文章路由
 /// 
 /// 路由路径（相对于 /api/articles）：
-/// - GET /api/articles - 获取文章列表（可选认证，handler 中有 OptionalAuthUser）
+/// - GET /api/articles - 获取文章列表（可选认证，handler 中有 OptionalAuthUser，响应带 `Link` 头）
+///   列表项只返回摘要、字数和预计阅读时间，`include_content=true` 时才返回完整正文；
+///   支持 `category_id` 与 `include_descendants=true` 按分类（含子孙分类）过滤；
+///   支持 `author_id`、`created_after`、`created_before`、`updated_after`、`title_contains` 过滤，
+///   以及 `sort=-created_at,title`（可选字段：created_at、updated_at、title、view_count）；
+///   缺省排序时置顶有效的文章按置顶位置排在最前（游标分页不受置顶影响）；
+///   带 `after=<cursor>&limit=20` 时改为游标分页（响应中返回 `next_cursor`，`include_total=true` 时返回总数）；
+///   标题和摘要按 `?lang=en` 或 `Accept-Language` 选择已发布的译文，没有匹配时返回原文
 /// - GET /api/articles/simple - 获取文章列表（简单版本，可选认证）
-/// - GET /api/articles/:id - 获取指定文章（可选认证）
+/// - GET /api/articles/trending?window=24h - 热门文章（按时间衰减的浏览量排序，仅公开文章）
+/// - GET /api/articles/featured - 精选文章（置顶有效的公开文章，按置顶位置排序）
+/// - GET /api/articles/:id - 获取指定文章（可选认证，记录浏览量，响应带 `ETag`（版本号），支持 `If-None-Match` 返回 304；
+///   属于系列时返回 `series`，包含上一篇、下一篇；按 `?lang=` 或 `Accept-Language` 返回译文，
+///   `languages` 列出原文和已发布译文的语言，响应带 `Vary: Accept-Language`；
+///   带 `?share=<token>` 时凭分享令牌读取，无需登录，私有文章同样可读，令牌无效、过期或已撤销时 403）
+/// - GET /api/articles/:id/translations - 获取译文列表（可选认证，作者和管理员可以看到未发布的译文）
 /// - GET /api/articles/:id/simple - 获取指定文章（简单版本，可选认证）
 /// - POST /api/articles - 创建文章（需要认证，handler 中有 AuthUser）
+/// - POST /api/articles/import?format=jsonl&dry_run=true - 批量导入文章（需要认证，multipart/form-data，
+///   支持 JSON Lines、CSV、带 YAML front matter 的 Markdown；单个事务，返回逐行错误）
+/// - GET /api/articles/export?format=csv - 流式导出文章（需要认证，管理员导出全部，其他用户导出自己的）
+/// - PUT /api/articles/:id - 更新文章（需要认证，作者或管理员，自动记录修订；
+///   必须带 `If-Match`（读取文章时返回的 `ETag`），缺少时 428，版本不一致时 412）
+/// - GET /api/articles/:id/review - 获取审核状态和变更记录（需要认证，作者、编辑或管理员）
+/// - POST /api/articles/:id/review/submit - 提交审核（需要认证，作者或管理员，草稿或被退回的文章）
+/// - POST /api/articles/:id/review/approve - 审核通过（需要认证，编辑或管理员，不能审核自己的文章）
+/// - POST /api/articles/:id/review/request-changes - 退回修改（需要认证，编辑或管理员，必须填写 `comment`）
+/// - POST /api/articles/:id/review/publish - 发布已通过审核的文章（需要认证，作者、编辑或管理员）
+/// - PUT /api/articles/:id/translations/:lang - 创建或更新译文（需要认证，作者或管理员；
+///   `slug` 缺省时由标题生成，同一语言内唯一；语言不能与原文相同；
+///   启用编辑审核时，文章审核通过后才能发布译文）
+/// - DELETE /api/articles/:id/translations/:lang - 删除译文（需要认证，作者或管理员）
+/// - POST /api/articles/:id/shares - 创建分享链接（需要认证，作者或管理员；`expires_in_hours` 缺省 7 天，
+///   令牌和带令牌的地址只在创建时返回）
+/// - GET /api/articles/:id/shares - 获取分享链接列表及使用情况（需要认证，作者或管理员）
+/// - DELETE /api/articles/:id/shares/:share_id - 撤销分享链接（需要认证，作者或管理员，幂等）
+/// - PUT /api/articles/:id/pin - 置顶文章或调整位置、到期时间（需要认证，编辑或管理员，只能置顶公开文章；
+///   `position` 从 1 开始，缺省排在最后；`expires_at` 缺省为长期置顶）
+/// - DELETE /api/articles/:id/pin - 取消置顶（需要认证，编辑或管理员，幂等）
+/// - DELETE /api/articles/:id - 删除文章（需要认证，作者或管理员；放入回收站，保留期内可在 /api/trash 中恢复）
+/// - PUT /api/articles/:id/category - 设置文章分类（需要认证，作者或管理员）
+/// - PUT /api/articles/:id/series - 加入/移出系列，可指定位置（需要认证，作者或管理员，只能加入作者本人的系列）
+/// - PUT /api/articles/:id/reaction - 设置表情反应（需要认证，幂等）
+/// - DELETE /api/articles/:id/reaction - 取消表情反应（需要认证，幂等）
+/// - PUT /api/articles/:id/bookmark - 收藏文章（需要认证，幂等）
+/// - DELETE /api/articles/:id/bookmark - 取消收藏（需要认证，幂等）
+/// - POST /api/articles/:id/reports - 举报文章（需要认证，`reason` 为 spam、harassment、hate、explicit、
+///   copyright、illegal、other，`other` 时必须填写 `details`；同一用户重复举报幂等）
+/// 
+/// 创建、更新、导入文章和保存译文时经过内容过滤（词表、链接数、重复内容）：被拒绝时返回 400，
+/// 需要审核时照常写入并放入管理员的待处理队列
+/// 
+/// 启用编辑审核流程（`EDITORIAL_WORKFLOW_ENABLED=true`）时，文章不能在创建、更新或导入时直接公开，
+/// 需要经过 草稿 → 待审核 → 已通过 → 已发布；修改待审核或已通过文章的标题、正文后回到草稿，
+/// 已发布的文章作者需要先撤回（`is_public=false`）再修改。未启用时审核接口返回 404
+/// 
+/// 被管理员隐藏的文章不会出现在任何读取接口中（包括列表、搜索、订阅源、站点地图、收藏和系列）
 /// 
 /// 注意：认证由 handler 中的提取器控制，不需要中间件
 pub fn routes() -> Router<AppState> {
@@ -17,10 +72,40 @@ pub fn routes() -> Router<AppState> {
         // 可选认证的路由（handler 中有 OptionalAuthUser）
         .route("/", get(article_controller::list_articles))
         .route("/simple", get(article_controller::list_articles_simple))
+        .route("/trending", get(article_controller::trending_articles))
+        .route("/featured", get(article_controller::featured_articles))
         .route("/:id", get(article_controller::get_article))
         .route("/:id/simple", get(article_controller::get_article_simple))
+        .route("/:id/translations", get(translation_controller::list_translations))
         
         // 需要认证的路由（handler 中有 AuthUser）
         .route("/", post(article_controller::create_article))
+        // 导入接口取消全局请求体限制，由 handler 按配置的单文件上限边读边校验
+        .route(
+            "/import",
+            post(transfer_controller::import_articles).layer(DefaultBodyLimit::disable()),
+        )
+        .route("/export", get(transfer_controller::export_articles))
+        .route("/:id", put(article_controller::update_article))
+        .route("/:id", delete(article_controller::delete_article))
+        .route("/:id/translations/:lang", put(translation_controller::upsert_translation))
+        .route("/:id/translations/:lang", delete(translation_controller::delete_translation))
+        .route("/:id/shares", post(share_controller::create_share_link))
+        .route("/:id/shares", get(share_controller::list_share_links))
+        .route("/:id/shares/:share_id", delete(share_controller::revoke_share_link))
+        .route("/:id/review", get(review_controller::get_article_review))
+        .route("/:id/review/submit", post(review_controller::submit_article))
+        .route("/:id/review/approve", post(review_controller::approve_article))
+        .route("/:id/review/request-changes", post(review_controller::request_changes))
+        .route("/:id/review/publish", post(review_controller::publish_article))
+        .route("/:id/pin", put(article_controller::pin_article))
+        .route("/:id/pin", delete(article_controller::unpin_article))
+        .route("/:id/category", put(article_controller::assign_article_category))
+        .route("/:id/series", put(series_controller::assign_article_series))
+        .route("/:id/reaction", put(engagement_controller::set_reaction))
+        .route("/:id/reaction", delete(engagement_controller::remove_reaction))
+        .route("/:id/bookmark", put(engagement_controller::add_bookmark))
+        .route("/:id/bookmark", delete(engagement_controller::remove_bookmark))
+        .route("/:id/reports", post(moderation_controller::report_article))
 }
 
@@ -0,0 +1,26 @@
+use axum::{extract::DefaultBodyLimit, routing::{get, post, delete}, Router};
+use crate::controllers::attachment_controller;
+use crate::AppState;
+
+/// 文章附件路由
+/// 
+/// 路由路径（相对于 /api/articles/:id/attachments）：
+/// - GET /api/articles/:id/attachments - 附件列表（可选认证，handler 中有 OptionalAuthUser）
+/// - POST /api/articles/:id/attachments - 上传附件（需要认证，multipart/form-data）
+/// - DELETE /api/articles/:id/attachments/:attachment_id - 删除附件（需要认证）
+/// 
+/// 注意：认证由 handler 中的提取器控制，不需要中间件
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        // 可选认证的路由（handler 中有 OptionalAuthUser）
+        .route("/", get(attachment_controller::list_attachments))
+        
+        // 需要认证的路由（handler 中有 AuthUser）
+        // 上传接口取消全局请求体限制，由 handler 按配置的单文件上限边读边校验
+        .route(
+            "/",
+            post(attachment_controller::upload_attachment).layer(DefaultBodyLimit::disable()),
+        )
+        .route("/:attachment_id", delete(attachment_controller::delete_attachment))
+}
+
@@ -0,0 +1,26 @@
+use axum::{routing::{get, post, put, delete}, Router};
+use crate::controllers::category_controller;
+use crate::AppState;
+
+/// 分类路由
+/// 
+/// 路由路径（相对于 /api/categories）：
+/// - GET /api/categories - 获取完整分类树（不需要认证）
+/// - GET /api/categories/:id - 获取指定分类及其子分类（不需要认证）
+/// - POST /api/categories - 创建分类（需要管理员，handler 中有 AuthUser）
+/// - PUT /api/categories/:id - 更新/移动分类（需要管理员，handler 中有 AuthUser）
+/// - DELETE /api/categories/:id - 删除分类（需要管理员，handler 中有 AuthUser）
+/// 
+/// 注意：认证由 handler 中的提取器控制，不需要中间件
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        // 公开路由（handler 中没有认证参数）
+        .route("/", get(category_controller::list_categories))
+        .route("/:id", get(category_controller::get_category))
+        
+        // 需要管理员权限的路由（handler 中有 AuthUser，并校验角色）
+        .route("/", post(category_controller::create_category))
+        .route("/:id", put(category_controller::update_category))
+        .route("/:id", delete(category_controller::delete_category))
+}
+
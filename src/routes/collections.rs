@@ -0,0 +1,31 @@
+use axum::{routing::{get, post, put, delete}, Router};
+use crate::controllers::collection_controller;
+use crate::AppState;
+
+/// 收藏夹路由
+/// 
+/// 路由路径（相对于 /api/collections）：
+/// - GET /api/collections/:id - 获取收藏夹（可选认证，私有收藏夹只有创建者可见）
+/// - GET /api/collections/:id/articles - 收藏夹中的文章（可选认证，分页，只返回可读的文章）
+/// - POST /api/collections - 创建收藏夹（需要认证）
+/// - PUT /api/collections/:id - 更新收藏夹（需要认证，仅创建者）
+/// - DELETE /api/collections/:id - 删除收藏夹（需要认证，创建者或管理员）
+/// - PUT /api/collections/:id/articles/:article_id - 加入文章（需要认证，仅创建者，幂等）
+/// - DELETE /api/collections/:id/articles/:article_id - 移出文章（需要认证，仅创建者，幂等）
+/// 
+/// 用户的收藏夹列表见 GET /api/users/:id/collections
+/// 
+/// 注意：认证由 handler 中的提取器控制，不需要中间件
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        // 可选认证的路由（handler 中有 OptionalAuthUser）
+        .route("/:id", get(collection_controller::get_collection))
+        .route("/:id/articles", get(collection_controller::list_collection_articles))
+        
+        // 需要认证的路由（handler 中有 AuthUser）
+        .route("/", post(collection_controller::create_collection))
+        .route("/:id", put(collection_controller::update_collection))
+        .route("/:id", delete(collection_controller::delete_collection))
+        .route("/:id/articles/:article_id", put(collection_controller::add_collection_article))
+        .route("/:id/articles/:article_id", delete(collection_controller::remove_collection_article))
+}
@@ -0,0 +1,24 @@
+use axum::{routing::{get, post, put, delete}, Router};
+use crate::controllers::comment_controller;
+use crate::AppState;
+
+/// 评论路由
+/// 
+/// 路由路径（相对于 /api/articles/:id/comments）：
+/// - GET /api/articles/:id/comments - 获取评论树（可选认证，handler 中有 OptionalAuthUser）
+/// - POST /api/articles/:id/comments - 发表评论或回复（需要认证，handler 中有 AuthUser；经过内容过滤）
+/// - PUT /api/articles/:id/comments/:comment_id - 编辑评论（需要认证，仅作者；经过内容过滤）
+/// - DELETE /api/articles/:id/comments/:comment_id - 删除评论（需要认证，作者或管理员）
+/// 
+/// 注意：认证由 handler 中的提取器控制，不需要中间件
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        // 可选认证的路由（handler 中有 OptionalAuthUser）
+        .route("/", get(comment_controller::list_comments))
+        
+        // 需要认证的路由（handler 中有 AuthUser）
+        .route("/", post(comment_controller::create_comment))
+        .route("/:comment_id", put(comment_controller::update_comment))
+        .route("/:comment_id", delete(comment_controller::delete_comment))
+}
+
@@ -0,0 +1,24 @@
+use axum::{routing::get, Router};
+use crate::controllers::feed_controller;
+use crate::AppState;
+
+/// 订阅源路由（公开，仅包含公开文章）
+/// 
+/// 路由路径（相对于 /feeds）：
+/// - GET /feeds/articles.rss - 全站 RSS 2.0
+/// - GET /feeds/articles.atom - 全站 Atom 1.0
+/// - GET /feeds/authors/:username/articles.rss - 指定作者的 RSS
+/// - GET /feeds/authors/:username/articles.atom - 指定作者的 Atom
+/// - GET /feeds/categories/:slug/articles.rss - 指定分类（含子孙分类）的 RSS
+/// - GET /feeds/categories/:slug/articles.atom - 指定分类（含子孙分类）的 Atom
+/// 
+/// 所有订阅源都带 `ETag` 和 `Last-Modified`，支持条件请求返回 304
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/articles.rss", get(feed_controller::articles_rss))
+        .route("/articles.atom", get(feed_controller::articles_atom))
+        .route("/authors/:username/articles.rss", get(feed_controller::author_rss))
+        .route("/authors/:username/articles.atom", get(feed_controller::author_atom))
+        .route("/categories/:slug/articles.rss", get(feed_controller::category_rss))
+        .route("/categories/:slug/articles.atom", get(feed_controller::category_atom))
+}
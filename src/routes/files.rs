@@ -0,0 +1,15 @@
+use axum::{routing::get, Router};
+use crate::controllers::attachment_controller;
+use crate::AppState;
+
+/// 文件下载路由
+/// 
+/// 路由路径（相对于 /files）：
+/// - GET /files/attachments/:id - 下载附件（可选认证，带 ETag；缓存每次使用前需要重新验证）
+/// 
+/// 注意：认证由 handler 中的提取器控制，不需要中间件
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/attachments/:id", get(attachment_controller::download_attachment))
+}
+
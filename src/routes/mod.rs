@@ -6,10 +6,38 @@ use tower_http::{compression::CompressionLayer, timeout::TimeoutLayer};
 
 /// 文章路由模块
 mod articles;
+/// 文章附件路由模块
+mod attachments;
 /// 认证路由模块
 mod auth;
+/// 分类路由模块
+mod categories;
+/// 评论路由模块
+mod comments;
+/// 订阅源路由模块
+mod feeds;
+/// 文件下载路由模块
+mod files;
 /// 健康检查路由模块
 mod health;
+/// 内容审核路由模块
+mod moderation;
+/// 收藏夹路由模块
+mod collections;
+/// 文章修订路由模块
+mod revisions;
+/// 编辑审核路由模块
+mod reviews;
+/// 搜索路由模块
+mod search;
+/// 文章系列路由模块
+mod series;
+/// 站点地图路由模块
+mod sitemap;
+/// 首页动态路由模块
+mod timeline;
+/// 回收站路由模块
+mod trash;
 /// 用户路由模块
 mod users;
 
@@ -32,6 +60,12 @@ pub fn create_router(state: AppState) -> Router {
     Router::new()
         // 健康检查路由（公开，不需要认证）
         .nest("/", health::routes())
+        // 文件下载路由（附件，可选认证）
+        .nest("/files", files::routes())
+        // 订阅源路由（RSS/Atom，公开）
+        .nest("/feeds", feeds::routes())
+        // 站点地图路由（公开）
+        .merge(sitemap::routes())
         // API 路由（统一使用 /api 前缀）
         .nest("/api", api_routes())
         // 1. CORS - 最外层，需要处理预检请求（OPTIONS），应该最早处理
@@ -54,6 +88,17 @@ fn api_routes() -> Router<AppState> {
         .nest("/auth", auth::routes())
         .nest("/users", users::routes())
         .nest("/articles", articles::routes())
+        .nest("/articles/:id/comments", comments::routes())
+        .nest("/articles/:id/revisions", revisions::routes())
+        .nest("/articles/:id/attachments", attachments::routes())
+        .nest("/categories", categories::routes())
+        .nest("/series", series::routes())
+        .nest("/collections", collections::routes())
+        .nest("/search", search::routes())
+        .nest("/moderation", moderation::routes())
+        .nest("/reviews", reviews::routes())
+        .nest("/trash", trash::routes())
+        .nest("/feed", timeline::routes())
 
     // 未来可以轻松添加更多模块：
     // .nest("/products", products::routes())  // 认证由 handler 中的 AuthUser 控制
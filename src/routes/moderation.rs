@@ -0,0 +1,24 @@
+use axum::{routing::{get, post}, Router};
+use crate::controllers::moderation_controller;
+use crate::AppState;
+
+/// 内容审核路由
+///
+/// 路由路径（相对于 /api/moderation，全部需要管理员权限）：
+/// - GET /api/moderation/queue - 待处理队列（同一内容的举报合并为一项，按举报数倒序，分页；
+///   包括用户举报和内容过滤器自动提交的 `automated` 举报）
+/// - POST /api/moderation/articles/:id/actions - 对文章执行管理操作
+///   （`hide` 隐藏、`restore` 恢复、`delete` 删除（放入回收站）、`warn` 警告作者、`dismiss` 驳回举报；
+///   操作会被记录，并同时处理该文章的待处理举报）
+/// - POST /api/moderation/comments/:id/actions - 对评论执行管理操作（`delete`、`warn`、`dismiss`）
+/// - GET /api/moderation/actions?target_id=&user_id= - 管理操作记录（分页）
+///
+/// 注意：认证由 handler 中的提取器控制，不需要中间件
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        // 需要认证的路由（handler 中有 AuthUser，service 中校验管理员权限）
+        .route("/queue", get(moderation_controller::moderation_queue))
+        .route("/articles/:id/actions", post(moderation_controller::moderate_article))
+        .route("/comments/:id/actions", post(moderation_controller::moderate_comment))
+        .route("/actions", get(moderation_controller::list_moderation_actions))
+}
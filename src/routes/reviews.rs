@@ -0,0 +1,16 @@
+use axum::{routing::get, Router};
+use crate::controllers::review_controller;
+use crate::AppState;
+
+/// 编辑审核路由（仅在启用编辑审核流程时可用，否则返回 404）
+/// 
+/// 路由路径（相对于 /api/reviews）：
+/// - GET /api/reviews?status=submitted - 审核队列（需要认证，编辑或管理员，分页，先提交的排在前面；
+///   `status` 可选 draft、submitted、changes_requested、approved、published，缺省为 submitted）
+/// 
+/// 单篇文章的提交、审核和发布接口位于 /api/articles/:id/review 下
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        // 需要认证的路由（handler 中有 AuthUser 参数）
+        .route("/", get(review_controller::list_review_queue))
+}
@@ -0,0 +1,23 @@
+use axum::{routing::{get, post}, Router};
+use crate::controllers::revision_controller;
+use crate::AppState;
+
+/// 文章修订路由
+/// 
+/// 路由路径（相对于 /api/articles/:id/revisions）：
+/// - GET /api/articles/:id/revisions - 修订列表（需要认证，作者或管理员）
+/// - GET /api/articles/:id/revisions/diff?from=1&to=2 - 两个修订的逐行差异
+/// - GET /api/articles/:id/revisions/:number - 指定修订内容
+/// - POST /api/articles/:id/revisions/:number/restore - 以旧修订内容生成新修订
+///   （必须带 `If-Match`，缺少时 428，版本不一致时 412）
+/// 
+/// 注意：认证由 handler 中的提取器控制，不需要中间件
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        // 需要认证的路由（handler 中有 AuthUser）
+        .route("/", get(revision_controller::list_revisions))
+        .route("/diff", get(revision_controller::diff_revisions))
+        .route("/:number", get(revision_controller::get_revision))
+        .route("/:number/restore", post(revision_controller::restore_revision))
+}
+
@@ -0,0 +1,16 @@
+use axum::{routing::get, Router};
+use crate::controllers::search_controller;
+use crate::AppState;
+
+/// 搜索路由
+/// 
+/// 路由路径（相对于 /api/search）：
+/// - GET /api/search/articles?q=... - 全文搜索文章（可选认证，handler 中有 OptionalAuthUser）
+/// 
+/// 注意：认证由 handler 中的提取器控制，不需要中间件
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        // 可选认证的路由（handler 中有 OptionalAuthUser）
+        .route("/articles", get(search_controller::search_articles))
+}
+
@@ -0,0 +1,27 @@
+use axum::{routing::{get, post, put, delete}, Router};
+use crate::controllers::series_controller;
+use crate::AppState;
+
+/// 文章系列路由
+/// 
+/// 路由路径（相对于 /api/series）：
+/// - GET /api/series/:id - 获取系列详情及按顺序排列的文章（可选认证，只列出可读的文章）
+/// - POST /api/series - 创建系列（需要认证）
+/// - PUT /api/series/:id - 更新系列标题、简介（需要认证，作者或管理员）
+/// - PUT /api/series/:id/articles - 调整系列中文章的顺序（需要认证，作者或管理员）
+/// - DELETE /api/series/:id - 删除系列，文章移出系列但保留（需要认证，作者或管理员）
+/// 
+/// 作者的系列列表见 GET /api/users/:id/series，文章加入系列见 PUT /api/articles/:id/series
+/// 
+/// 注意：认证由 handler 中的提取器控制，不需要中间件
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        // 可选认证的路由（handler 中有 OptionalAuthUser）
+        .route("/:id", get(series_controller::get_series))
+        
+        // 需要认证的路由（handler 中有 AuthUser）
+        .route("/", post(series_controller::create_series))
+        .route("/:id", put(series_controller::update_series))
+        .route("/:id", delete(series_controller::delete_series))
+        .route("/:id/articles", put(series_controller::reorder_series))
+}
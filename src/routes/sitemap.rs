@@ -0,0 +1,16 @@
+use axum::{routing::get, Router};
+use crate::controllers::sitemap_controller;
+use crate::AppState;
+
+/// 站点地图路由（公开）
+/// 
+/// 路由路径：
+/// - GET /sitemap.xml - 站点地图；URL 超过 50000 个时为站点地图索引
+/// - GET /sitemaps/sitemap-N.xml - 第 N 个站点地图分片（仅在有索引时存在）
+/// 
+/// 内容由后台任务定期生成并缓存，请求不会扫描数据表
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/sitemap.xml", get(sitemap_controller::sitemap))
+        .route("/sitemaps/:file", get(sitemap_controller::sitemap_page))
+}
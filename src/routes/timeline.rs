@@ -0,0 +1,17 @@
+use axum::{routing::get, Router};
+use crate::controllers::follow_controller;
+use crate::AppState;
+
+/// 首页动态路由
+/// 
+/// 路由路径（相对于 /api/feed）：
+/// - GET /api/feed - 关注的作者发布的公开文章（需要认证，按创建时间倒序，
+///   游标分页 `after=<cursor>&limit=20`，可选 `include_total=true`，响应带 `Link` 头；
+///   标题和摘要按 `?lang=` 或 `Accept-Language` 选择译文）
+/// 
+/// 注意：认证由 handler 中的提取器控制，不需要中间件
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        // 需要认证的路由（handler 中有 AuthUser 参数）
+        .route("/", get(follow_controller::home_feed))
+}
@@ -0,0 +1,24 @@
+use axum::{routing::{get, post}, Router};
+use crate::controllers::trash_controller;
+use crate::AppState;
+
+/// 回收站路由
+///
+/// 删除的文章和用户先进入回收站，保留期（`TRASH_RETENTION_DAYS`）过后由后台任务彻底删除；
+/// 回收站中的内容不会出现在其他任何接口中，被删除的用户不能登录
+///
+/// 路由路径（相对于 /api/trash，全部需要认证）：
+/// - GET /api/trash/articles - 回收站中的文章（分页，管理员查看全部，其他用户查看自己的；返回 `deleted_at`、`purge_at`）
+/// - POST /api/trash/articles/:id/restore - 恢复文章（作者或管理员，作者账户也被删除时需先恢复作者）
+/// - GET /api/trash/users - 回收站中的用户（仅管理员，分页）
+/// - POST /api/trash/users/:id/restore - 恢复用户（仅管理员，随用户一起删除的文章同时恢复）
+///
+/// 注意：认证由 handler 中的提取器控制，不需要中间件
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        // 需要认证的路由（handler 中有 AuthUser）
+        .route("/articles", get(trash_controller::list_trashed_articles))
+        .route("/articles/:id/restore", post(trash_controller::restore_article))
+        .route("/users", get(trash_controller::list_trashed_users))
+        .route("/users/:id/restore", post(trash_controller::restore_user))
+}
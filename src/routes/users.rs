@@ -1,14 +1,28 @@
 use axum::{routing::{get, put, delete}, Router};
-use crate::controllers::user_controller;
+use crate::controllers::{
+    collection_controller, engagement_controller, follow_controller, series_controller, user_controller,
+};
 use crate::AppState;
 
 /// 用户路由
 /// 
 /// 路由路径（相对于 /api/users）：
-/// - GET /api/users - 获取用户列表（分页，不需要认证）
-/// - GET /api/users/:id - 获取指定用户信息（不需要认证）
-/// - PUT /api/users/:id - 更新用户信息（需要认证，handler 中有 AuthUser）
-/// - DELETE /api/users/:id - 删除用户（需要认证，handler 中有 AuthUser）
+/// - GET /api/users - 获取用户列表（分页，不需要认证，`page`/`page_size` 可省略，响应带 `Link` 头）
+///   支持 `username_contains`、`created_after`、`created_before` 过滤，
+///   以及 `sort=-created_at,username`（可选字段：created_at、username）；
+///   带 `after=<cursor>&limit=20` 时改为游标分页
+/// - GET /api/users/:id - 获取指定用户信息（不需要认证，响应带 `ETag`，支持 `If-None-Match` 返回 304）
+/// - GET /api/users/:id/series - 获取作者的系列列表（不需要认证）
+/// - GET /api/users/:id/followers - 获取用户的关注者（不需要认证，分页，按关注时间倒序）
+/// - GET /api/users/:id/following - 获取用户关注的人（不需要认证，分页，按关注时间倒序）
+/// - GET /api/users/:id/collections - 获取用户的收藏夹列表（可选认证，本人可见私有收藏夹）
+/// - GET /api/users/me/bookmarks - 获取当前用户的收藏列表（需要认证，分页）
+/// - PUT /api/users/:id/follow - 关注用户（需要认证，幂等，不能关注自己）
+/// - DELETE /api/users/:id/follow - 取消关注（需要认证，幂等）
+/// - PUT /api/users/:id - 更新用户信息（需要认证，handler 中有 AuthUser；
+///   必须带 `If-Match`，缺少时 428，版本不一致时 412）
+/// - DELETE /api/users/:id - 删除用户（需要认证，handler 中有 AuthUser；用户及其文章放入回收站，
+///   该用户已签发的令牌立即失效；保留期内管理员可在 /api/trash 中恢复）
 /// 
 /// 注意：认证由 handler 中的提取器控制，不需要中间件
 pub fn routes() -> Router<AppState> {
@@ -16,8 +30,17 @@ pub fn routes() -> Router<AppState> {
         // 公开路由（handler 中没有认证参数）
         .route("/", get(user_controller::list_users))
         .route("/:id", get(user_controller::get_user))
+        .route("/:id/series", get(series_controller::list_user_series))
+        .route("/:id/followers", get(follow_controller::list_followers))
+        .route("/:id/following", get(follow_controller::list_following))
+        
+        // 可选认证的路由（handler 中有 OptionalAuthUser）
+        .route("/:id/collections", get(collection_controller::list_user_collections))
         
         // 需要认证的路由（handler 中有 AuthUser 参数）
+        .route("/me/bookmarks", get(engagement_controller::list_my_bookmarks))
+        .route("/:id/follow", put(follow_controller::follow_user))
+        .route("/:id/follow", delete(follow_controller::unfollow_user))
         .route("/:id", put(user_controller::update_user))
         .route("/:id", delete(user_controller::delete_user))
 }
@@ -1,108 +1,519 @@
 use sea_orm::DatabaseConnection;
 use uuid::Uuid;
-use crate::entities::article::ActiveModel;
+use crate::config::EditorialConfig;
+use crate::content_filter::{content_hash, ContentFilterPipeline, ContentKind, FilterInput};
+use crate::entities::article::{ActiveModel, ContentFormat, Model};
+use crate::entities::content_report::ReportTarget;
 use crate::errors::{AppError, Result};
-use crate::extractors::Pagination;
-use crate::models::{ArticleResponse, CreateArticleRequest};
-use crate::repositories::article_repository;
-use crate::services::user_service::PagedResult;
+use crate::extractors::{Cursor, CursorPagination, IfMatch, ListQuery, Pagination, PreferredLanguages};
+use crate::extractors::AuthUser;
+use crate::models::{
+    ArticleField, ArticleListQuery, ArticleResponse, BodyFormat, BodyFormatQuery,
+    CreateArticleRequest, PinArticleRequest,
+};
+use crate::render::{content_stats, render_content};
+use crate::repositories::article_repository::{self, CategoryFilter, NewPin};
+use crate::repositories::translation_repository;
+use crate::services::{
+    category_service, engagement_service, moderation_service, review_service, revision_service,
+    series_service, translation_service,
+};
+use crate::services::user_service::{
+    ensure_cursor_sort, CursorInfo, CursorPagedResult, PagedResult,
+};
+
+/// 同时置顶的文章数上限
+const MAX_PINNED_ARTICLES: usize = 20;
 
 /// 获取文章列表（带分页）
 pub async fn list_articles(
     db: &DatabaseConnection,
-    pagination: Pagination,
+    pagination: &Pagination,
+    query: ArticleListQuery,
+    list_query: ListQuery<ArticleField>,
+    body: BodyFormatQuery,
+    languages: &PreferredLanguages,
     user_id: Option<Uuid>,
 ) -> Result<PagedResult<Vec<ArticleResponse>>> {
     let offset = pagination.offset();
     let limit = pagination.limit();
     let is_public_only = user_id.is_none();
+    let category = category_filter(db, &query).await?;
     
     let (articles, total) = article_repository::find_all_with_pagination(
         db,
         user_id,
         is_public_only,
+        category,
+        &list_query,
         offset,
         limit,
     ).await?;
     
-    let articles_response: Vec<ArticleResponse> = articles
-        .into_iter()
-        .map(ArticleResponse::from)
-        .collect();
+    let mut articles_response: Vec<ArticleResponse> =
+        translation_service::localize(db, articles, languages)
+            .await?
+            .into_iter()
+            .map(|(article, localization)| list_item(article, body).localized(localization))
+            .collect();
+    engagement_service::attach_engagement(db, &mut articles_response, user_id).await?;
     
     Ok(PagedResult {
         list: articles_response,
-        pagination: crate::services::user_service::PaginationInfo {
-            page: pagination.page,
-            page_size: pagination.page_size,
+        pagination: crate::services::user_service::PaginationInfo::new(pagination, total),
+    })
+}
+
+/// 游标分页获取文章列表（按创建时间倒序）
+pub async fn list_articles_after_cursor(
+    db: &DatabaseConnection,
+    pagination: &CursorPagination,
+    query: ArticleListQuery,
+    list_query: ListQuery<ArticleField>,
+    body: BodyFormatQuery,
+    languages: &PreferredLanguages,
+    user_id: Option<Uuid>,
+) -> Result<CursorPagedResult<Vec<ArticleResponse>>> {
+    ensure_cursor_sort(&list_query)?;
+    let category = category_filter(db, &query).await?;
+
+    let (articles, has_more, total) = article_repository::find_after_cursor(
+        db,
+        user_id,
+        user_id.is_none(),
+        category,
+        &list_query,
+        pagination,
+    )
+    .await?;
+
+    let next_cursor = has_more
+        .then(|| articles.last())
+        .flatten()
+        .and_then(|a| Some(Cursor { created_at: a.created_at?, id: a.id }.encode()));
+
+    let mut list: Vec<ArticleResponse> = translation_service::localize(db, articles, languages)
+        .await?
+        .into_iter()
+        .map(|(article, localization)| list_item(article, body).localized(localization))
+        .collect();
+    engagement_service::attach_engagement(db, &mut list, user_id).await?;
+
+    Ok(CursorPagedResult {
+        list,
+        cursor: CursorInfo {
+            limit: pagination.limit,
+            next_cursor,
             total,
-            total_pages: (total as f64 / limit as f64).ceil() as u64,
         },
     })
 }
 
-/// 根据 ID 获取文章
+/// 构建列表项：缺省只返回摘要，`include_content=true` 时按 `format` 返回完整正文
+fn list_item(article: Model, body: BodyFormatQuery) -> ArticleResponse {
+    if body.include_content {
+        ArticleResponse::with_format(article, body.format)
+    } else {
+        ArticleResponse::summary(article)
+    }
+}
+
+/// 获取精选文章（置顶有效的公开文章，按置顶位置排序）
+pub async fn featured_articles(
+    db: &DatabaseConnection,
+    languages: &PreferredLanguages,
+    user_id: Option<Uuid>,
+) -> Result<Vec<ArticleResponse>> {
+    let articles = article_repository::find_pinned(db, chrono::Utc::now()).await?;
+
+    let mut list: Vec<ArticleResponse> = translation_service::localize(db, articles, languages)
+        .await?
+        .into_iter()
+        .map(|(article, localization)| ArticleResponse::summary(article).localized(localization))
+        .collect();
+    engagement_service::attach_engagement(db, &mut list, user_id).await?;
+
+    Ok(list)
+}
+
+/// 置顶文章或调整置顶位置、到期时间（仅编辑和管理员，只能置顶公开文章）
+///
+/// 其余置顶文章依次后移；已过期的置顶同时被清除
+pub async fn pin_article(
+    db: &DatabaseConnection,
+    article_id: Uuid,
+    auth_user: &AuthUser,
+    payload: PinArticleRequest,
+) -> Result<ArticleResponse> {
+    auth_user.require_editor()?;
+
+    let article = article_repository::find_by_id(db, article_id).await?
+        .ok_or(AppError::NotFound)?;
+    if article.is_public != Some(true) {
+        return Err(AppError::Validation("只能置顶公开文章".to_string()));
+    }
+
+    let now = chrono::Utc::now();
+    if payload.expires_at.is_some_and(|expires_at| expires_at <= now) {
+        return Err(AppError::Validation("置顶到期时间必须晚于当前时间".to_string()));
+    }
+    if payload.position == Some(0) {
+        return Err(AppError::Validation("置顶位置从 1 开始".to_string()));
+    }
+
+    let mut ordered = article_repository::find_pinned_ids(db, now).await?;
+    ordered.retain(|id| *id != article_id);
+    if ordered.len() >= MAX_PINNED_ARTICLES {
+        return Err(AppError::Validation(format!(
+            "最多同时置顶 {} 篇文章",
+            MAX_PINNED_ARTICLES
+        )));
+    }
+    let index = payload
+        .position
+        .map_or(ordered.len(), |position| (position as usize - 1).min(ordered.len()));
+    ordered.insert(index, article_id);
+
+    article_repository::set_pins(
+        db,
+        &ordered,
+        Some(NewPin {
+            article_id,
+            pinned_until: payload.expires_at,
+            pinned_at: now,
+            pinned_by: auth_user.user_id,
+        }),
+    )
+    .await?;
+
+    let article = article_repository::find_by_id(db, article_id).await?
+        .ok_or(AppError::NotFound)?;
+    Ok(ArticleResponse::summary(article))
+}
+
+/// 取消置顶（仅编辑和管理员，幂等），其余置顶文章依次前移
+pub async fn unpin_article(
+    db: &DatabaseConnection,
+    article_id: Uuid,
+    auth_user: &AuthUser,
+) -> Result<()> {
+    auth_user.require_editor()?;
+
+    article_repository::find_by_id_including_hidden(db, article_id).await?
+        .ok_or(AppError::NotFound)?;
+
+    let mut ordered = article_repository::find_pinned_ids(db, chrono::Utc::now()).await?;
+    ordered.retain(|id| *id != article_id);
+
+    article_repository::set_pins(db, &ordered, None).await
+}
+
+/// 根据列表参数构建分类过滤条件
+async fn category_filter(
+    db: &DatabaseConnection,
+    query: &ArticleListQuery,
+) -> Result<Option<CategoryFilter>> {
+    Ok(match query.category_id {
+        Some(category_id) if query.include_descendants => {
+            let category = category_service::find_category(db, category_id).await?;
+            Some(CategoryFilter::Subtree(category.path))
+        }
+        Some(category_id) => Some(CategoryFilter::Exact(category_id)),
+        None => None,
+    })
+}
+
+/// 根据 ID 获取文章（私有文章仅作者可见）
+///
+/// 按 `languages` 返回最合适的已发布译文，没有匹配的译文时返回原文
 pub async fn get_article_by_id(
     db: &DatabaseConnection,
     article_id: Uuid,
+    format: BodyFormat,
+    languages: &PreferredLanguages,
+    user_id: Option<Uuid>,
 ) -> Result<ArticleResponse> {
+    let article = find_readable_article(db, article_id, user_id).await?;
+    article_detail(db, article, format, languages, user_id).await
+}
+
+/// 构建单篇文章的完整响应（系列导航、译文和互动统计），调用方负责检查阅读权限
+pub async fn article_detail(
+    db: &DatabaseConnection,
+    article: Model,
+    format: BodyFormat,
+    languages: &PreferredLanguages,
+    user_id: Option<Uuid>,
+) -> Result<ArticleResponse> {
+    let series = series_service::series_info(db, &article, user_id).await?;
+
+    let (article, localization) = translation_service::localize(db, vec![article], languages)
+        .await?
+        .pop()
+        .ok_or(AppError::NotFound)?;
+    let mut response = ArticleResponse::with_format(article, format).localized(localization);
+    response.series = series;
+    engagement_service::attach_engagement(db, std::slice::from_mut(&mut response), user_id).await?;
+
+    Ok(response)
+}
+
+/// 查找当前用户可读的文章
+///
+/// 公开文章所有人可读，私有文章只有作者可读；
+/// 不可读时返回 404，避免泄露私有文章是否存在
+pub async fn find_readable_article(
+    db: &DatabaseConnection,
+    article_id: Uuid,
+    user_id: Option<Uuid>,
+) -> Result<Model> {
     let article = article_repository::find_by_id(db, article_id).await?
         .ok_or(AppError::NotFound)?;
-    
-    Ok(ArticleResponse::from(article))
+
+    if !can_read(&article, user_id) {
+        return Err(AppError::NotFound);
+    }
+
+    Ok(article)
 }
 
-/// 创建文章
+/// 查找当前用户可编辑的文章（作者本人或管理员）
+pub async fn find_editable_article(
+    db: &DatabaseConnection,
+    article_id: Uuid,
+    auth_user: &AuthUser,
+) -> Result<Model> {
+    let article = article_repository::find_by_id(db, article_id).await?
+        .ok_or(AppError::NotFound)?;
+
+    if article.user_id != Some(auth_user.user_id) && !auth_user.is_admin() {
+        return Err(AppError::Forbidden);
+    }
+
+    Ok(article)
+}
+
+/// 判断用户是否可以阅读文章
+pub fn can_read(article: &Model, user_id: Option<Uuid>) -> bool {
+    is_readable(article.user_id, article.is_public, user_id)
+}
+
+/// 根据作者和公开状态判断用户是否可以阅读文章（只查询了部分列时使用）
+pub fn is_readable(author_id: Option<Uuid>, is_public: Option<bool>, user_id: Option<Uuid>) -> bool {
+    is_public == Some(true) || (user_id.is_some() && author_id == user_id)
+}
+
+/// 创建文章（写入前经过内容过滤，需要审核时写入后放入待处理队列）
 pub async fn create_article(
     db: &DatabaseConnection,
+    filter: &ContentFilterPipeline,
+    editorial: &EditorialConfig,
     user_id: Uuid,
     payload: CreateArticleRequest,
 ) -> Result<ArticleResponse> {
+    let review_status = review_service::initial_review_status(editorial, payload.is_public)?;
+    if let Some(category_id) = payload.category_id {
+        category_service::find_category(db, category_id).await?;
+    }
+
     let article_id = Uuid::new_v4();
+    let flagged = moderation_service::screen_content(
+        db,
+        filter,
+        &FilterInput {
+            kind: ContentKind::Article,
+            id: None,
+            author_id: user_id,
+            title: Some(&payload.title),
+            body: &payload.content,
+        },
+    )
+    .await?;
     let now = chrono::Utc::now();
+    let content_format = payload.content_format.unwrap_or_default();
+    let revision = revision_service::new_revision(
+        article_id,
+        payload.title.clone(),
+        payload.content.clone(),
+        content_format,
+        user_id,
+        None,
+    );
     
-    let article = ActiveModel {
+    let language = payload
+        .language
+        .as_deref()
+        .map(translation_service::validate_language)
+        .transpose()?;
+    let mut article = ActiveModel {
         id: sea_orm::Set(article_id),
         title: sea_orm::Set(payload.title),
-        content: sea_orm::Set(payload.content),
         user_id: sea_orm::Set(Some(user_id)),
         is_public: sea_orm::Set(payload.is_public),
+        review_status: sea_orm::Set(review_status),
+        category_id: sea_orm::Set(payload.category_id),
         created_at: sea_orm::Set(Some(now)),
+        updated_at: sea_orm::Set(Some(now)),
+        version: sea_orm::Set(1),
+        ..Default::default()
     };
+    set_content(&mut article, payload.content, content_format);
+    if let Some(language) = language {
+        article.language = sea_orm::Set(language);
+    }
     
-    let created_article = article_repository::create(db, article).await?;
+    let created_article = article_repository::create_with_revision(db, article, revision).await?;
+    if let Some(reason) = flagged {
+        moderation_service::flag_content(db, ReportTarget::Article, created_article.id, reason).await;
+    }
     
     Ok(ArticleResponse::from(created_article))
 }
 
-/// 更新文章
+/// 更新文章（作者本人或管理员），每次更新都会追加一条修订
+///
+/// `if_match` 与文章当前版本不一致时返回 412；新内容同样经过内容过滤。
+/// 审核状态随公开状态和内容变化调整，见 [`review_service::review_after_update`]
 pub async fn update_article(
     db: &DatabaseConnection,
+    filter: &ContentFilterPipeline,
+    editorial: &EditorialConfig,
     article_id: Uuid,
+    auth_user: &AuthUser,
     payload: CreateArticleRequest,
+    if_match: &IfMatch,
 ) -> Result<ArticleResponse> {
-    // 检查文章是否存在
-    let existing_article = article_repository::find_by_id(db, article_id).await?
-        .ok_or(AppError::NotFound)?;
+    // 检查文章是否存在且有编辑权限
+    let existing_article = find_editable_article(db, article_id, auth_user).await?;
+    if !if_match.matches(existing_article.version) {
+        return Err(AppError::version_conflict());
+    }
+    let previous_status = existing_article.review_status;
+    let review_status = review_service::review_after_update(
+        editorial,
+        &existing_article,
+        payload.is_public,
+        payload.title != existing_article.title || payload.content != existing_article.content,
+        auth_user,
+    )?;
+    let language = payload
+        .language
+        .as_deref()
+        .map(translation_service::validate_language)
+        .transpose()?
+        .filter(|language| *language != existing_article.language);
+    if let Some(language) = &language {
+        if translation_repository::find_by_article_and_language(db, article_id, language)
+            .await?
+            .is_some()
+        {
+            return Err(AppError::Validation(
+                "该语言已有译文，请先删除译文再修改原文语言".to_string(),
+            ));
+        }
+    }
+    let flagged = moderation_service::screen_content(
+        db,
+        filter,
+        &FilterInput {
+            kind: ContentKind::Article,
+            id: Some(article_id),
+            author_id: existing_article.user_id.unwrap_or(auth_user.user_id),
+            title: Some(&payload.title),
+            body: &payload.content,
+        },
+    )
+    .await?;
+    let content_format = payload.content_format.unwrap_or(existing_article.content_format);
+    let baseline = revision_service::baseline_revision(&existing_article);
+    let revision = revision_service::new_revision(
+        article_id,
+        payload.title.clone(),
+        payload.content.clone(),
+        content_format,
+        auth_user.user_id,
+        None,
+    );
     
     // 构建更新模型
     let mut article: ActiveModel = existing_article.into();
     article.title = sea_orm::Set(payload.title);
-    article.content = sea_orm::Set(payload.content);
+    set_content(&mut article, payload.content, content_format);
     if let Some(is_public) = payload.is_public {
         article.is_public = sea_orm::Set(Some(is_public));
     }
+    if let Some(review_status) = review_status {
+        article.review_status = sea_orm::Set(review_status);
+    }
+    if let Some(category_id) = payload.category_id {
+        category_service::find_category(db, category_id).await?;
+        article.category_id = sea_orm::Set(Some(category_id));
+    }
+    if let Some(language) = language {
+        article.language = sea_orm::Set(language);
+    }
     
-    let updated_article = article_repository::update(db, article_id, article).await?;
+    let updated_article =
+        article_repository::update_with_revision(db, article_id, article, baseline, revision)
+            .await?;
+    if let Some(review_status) = review_status {
+        review_service::record_status_change(
+            db,
+            article_id,
+            auth_user.user_id,
+            previous_status,
+            review_status,
+        )
+        .await?;
+    }
+    if let Some(reason) = flagged {
+        moderation_service::flag_content(db, ReportTarget::Article, article_id, reason).await;
+    }
     
     Ok(ArticleResponse::from(updated_article))
 }
 
-/// 删除文章
+/// 设置文章分类（作者本人或管理员）
+pub async fn assign_category(
+    db: &DatabaseConnection,
+    article_id: Uuid,
+    auth_user: &AuthUser,
+    category_id: Option<Uuid>,
+) -> Result<ArticleResponse> {
+    let existing_article = find_editable_article(db, article_id, auth_user).await?;
+
+    if let Some(category_id) = category_id {
+        category_service::find_category(db, category_id).await?;
+    }
+
+    let mut article: ActiveModel = existing_article.into();
+    article.category_id = sea_orm::Set(category_id);
+
+    let updated_article = article_repository::update(db, article_id, article).await?;
+
+    Ok(ArticleResponse::from(updated_article))
+}
+
+/// 设置文章正文，同时生成渲染后的 HTML 缓存、摘要、字数、预计阅读时间和正文指纹
+pub fn set_content(article: &mut ActiveModel, content: String, format: ContentFormat) {
+    let stats = content_stats(&content, format);
+    article.content_hash = sea_orm::Set(Some(content_hash(&content)));
+    article.content_html = sea_orm::Set(Some(render_content(&content, format)));
+    article.excerpt = sea_orm::Set(Some(stats.excerpt));
+    article.word_count = sea_orm::Set(Some(stats.word_count));
+    article.reading_minutes = sea_orm::Set(Some(stats.reading_minutes));
+    article.content = sea_orm::Set(content);
+    article.content_format = sea_orm::Set(format);
+}
+
+/// 删除文章（作者本人或管理员；放入回收站，保留期内可以恢复）
 pub async fn delete_article(
     db: &DatabaseConnection,
     article_id: Uuid,
+    auth_user: &AuthUser,
 ) -> Result<()> {
-    article_repository::delete(db, article_id).await
+    find_editable_article(db, article_id, auth_user).await?;
+
+    article_repository::delete(db, article_id, chrono::Utc::now()).await
 }
 
@@ -0,0 +1,180 @@
+use axum::body::Bytes;
+use sea_orm::DatabaseConnection;
+use uuid::Uuid;
+use crate::config::StorageConfig;
+use crate::entities::attachment::{ActiveModel, Model};
+use crate::errors::{AppError, Result};
+use crate::extractors::AuthUser;
+use crate::models::AttachmentResponse;
+use crate::repositories::attachment_repository;
+use crate::services::article_service;
+use crate::storage::Storage;
+
+/// 文件名最大长度（字符数）
+const MAX_FILENAME_LENGTH: usize = 200;
+
+/// 上传附件（文章作者或管理员）
+///
+/// 文件类型根据内容判断（不信任客户端声明的 Content-Type），只接受白名单内的类型
+pub async fn upload_attachment(
+    db: &DatabaseConnection,
+    storage: &dyn Storage,
+    config: &StorageConfig,
+    article_id: Uuid,
+    auth_user: &AuthUser,
+    filename: Option<&str>,
+    data: Bytes,
+) -> Result<AttachmentResponse> {
+    article_service::find_editable_article(db, article_id, auth_user).await?;
+
+    if data.is_empty() {
+        return Err(AppError::Validation("文件内容为空".to_string()));
+    }
+    let size = data.len() as u64;
+    if size > config.max_file_size {
+        return Err(AppError::PayloadTooLarge(format!(
+            "单个附件不能超过 {} 字节",
+            config.max_file_size
+        )));
+    }
+
+    let content_type = sniff_content_type(&data)
+        .ok_or_else(|| AppError::UnsupportedMediaType("不支持的文件类型".to_string()))?;
+
+    // 先做一次不加锁的检查，避免写入注定超出配额的文件；以写入记录时的检查为准
+    let used = attachment_repository::total_size_by_user(db, auth_user.user_id).await?;
+    if used + size > config.user_quota {
+        return Err(AppError::PayloadTooLarge(format!(
+            "附件空间不足：已使用 {} 字节，配额 {} 字节",
+            used, config.user_quota
+        )));
+    }
+
+    let attachment_id = Uuid::new_v4();
+    let now = chrono::Utc::now();
+    let storage_key = format!("attachments/{}/{}", now.format("%Y/%m"), attachment_id);
+
+    storage.put(&storage_key, data, content_type).await?;
+
+    let attachment = ActiveModel {
+        id: sea_orm::Set(attachment_id),
+        article_id: sea_orm::Set(article_id),
+        user_id: sea_orm::Set(Some(auth_user.user_id)),
+        storage_key: sea_orm::Set(storage_key.clone()),
+        filename: sea_orm::Set(sanitize_filename(filename.unwrap_or_default())),
+        content_type: sea_orm::Set(content_type.to_string()),
+        size_bytes: sea_orm::Set(size as i64),
+        created_at: sea_orm::Set(now),
+    };
+
+    let created = attachment_repository::create_within_quota(
+        db,
+        attachment,
+        auth_user.user_id,
+        config.user_quota,
+    )
+    .await;
+    match created {
+        Ok(created) => Ok(AttachmentResponse::from(created)),
+        Err(e) => {
+            // 元数据写入失败（包括并发上传导致超出配额）时清理已写入的文件，避免产生孤儿文件
+            if let Err(cleanup) = storage.delete(&storage_key).await {
+                tracing::warn!("清理附件文件失败 {}: {}", storage_key, cleanup);
+            }
+            Err(e)
+        }
+    }
+}
+
+/// 获取文章的附件列表（遵循文章可见性）
+pub async fn list_attachments(
+    db: &DatabaseConnection,
+    article_id: Uuid,
+    user_id: Option<Uuid>,
+) -> Result<Vec<AttachmentResponse>> {
+    article_service::find_readable_article(db, article_id, user_id).await?;
+
+    let attachments = attachment_repository::find_by_article(db, article_id).await?;
+
+    Ok(attachments.into_iter().map(AttachmentResponse::from).collect())
+}
+
+/// 删除附件（上传者、文章作者或管理员）
+///
+/// 先删除记录再删除文件；文件删除失败只记录日志，留下的孤儿文件不影响使用
+pub async fn delete_attachment(
+    db: &DatabaseConnection,
+    storage: &dyn Storage,
+    article_id: Uuid,
+    attachment_id: Uuid,
+    auth_user: &AuthUser,
+) -> Result<()> {
+    let attachment = attachment_repository::find_by_id(db, attachment_id).await?
+        .filter(|a| a.article_id == article_id)
+        .ok_or(AppError::NotFound)?;
+
+    if attachment.user_id != Some(auth_user.user_id) {
+        article_service::find_editable_article(db, article_id, auth_user).await?;
+    }
+
+    attachment_repository::delete(db, attachment_id).await?;
+    if let Err(e) = storage.delete(&attachment.storage_key).await {
+        tracing::warn!("删除附件文件失败 {}: {}", attachment.storage_key, e);
+    }
+    Ok(())
+}
+
+/// 查找可下载的附件，返回附件和所属文章是否公开
+pub async fn find_downloadable(
+    db: &DatabaseConnection,
+    attachment_id: Uuid,
+    user_id: Option<Uuid>,
+) -> Result<(Model, bool)> {
+    let attachment = attachment_repository::find_by_id(db, attachment_id).await?
+        .ok_or(AppError::NotFound)?;
+    let article = article_service::find_readable_article(db, attachment.article_id, user_id).await?;
+
+    Ok((attachment, article.is_public == Some(true)))
+}
+
+/// 根据文件头判断文件类型（白名单）
+fn sniff_content_type(data: &[u8]) -> Option<&'static str> {
+    const SIGNATURES: &[(&[u8], &str)] = &[
+        (b"\x89PNG\r\n\x1a\n", "image/png"),
+        (b"\xff\xd8\xff", "image/jpeg"),
+        (b"GIF87a", "image/gif"),
+        (b"GIF89a", "image/gif"),
+        (b"%PDF-", "application/pdf"),
+        (b"PK\x03\x04", "application/zip"),
+    ];
+
+    if let Some((_, content_type)) = SIGNATURES.iter().find(|(magic, _)| data.starts_with(magic)) {
+        return Some(content_type);
+    }
+    if data.len() >= 12 && &data[..4] == b"RIFF" && &data[8..12] == b"WEBP" {
+        return Some("image/webp");
+    }
+    // 不含 NUL 的合法 UTF-8 视为纯文本（Markdown、CSV 等）
+    if !data.contains(&0) && std::str::from_utf8(data).is_ok() {
+        return Some("text/plain; charset=utf-8");
+    }
+
+    None
+}
+
+/// 清理文件名：去掉路径部分和控制字符，限制长度
+fn sanitize_filename(filename: &str) -> String {
+    let basename = filename.rsplit(['/', '\\']).next().unwrap_or_default();
+    let cleaned: String = basename
+        .chars()
+        .filter(|c| !c.is_control())
+        .take(MAX_FILENAME_LENGTH)
+        .collect();
+    let cleaned = cleaned.trim();
+
+    if cleaned.is_empty() || cleaned == "." || cleaned == ".." {
+        "file".to_string()
+    } else {
+        cleaned.to_string()
+    }
+}
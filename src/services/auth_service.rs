@@ -1,7 +1,7 @@
 use sea_orm::DatabaseConnection;
 use bcrypt::{hash, verify, DEFAULT_COST};
 use uuid::Uuid;
-use crate::entities::user::ActiveModel;
+use crate::entities::user::{ActiveModel, UserRole};
 use crate::errors::{AppError, Result};
 use crate::jwt::generate_token;
 use crate::models::{CreateUserRequest, LoginRequest, LoginResponse, UserResponse};
@@ -38,8 +38,11 @@ pub async fn register(
         username: sea_orm::Set(payload.username),
         email: sea_orm::Set(payload.email),
         password_hash: sea_orm::Set(password_hash),
+        role: sea_orm::Set(UserRole::User),
         created_at: sea_orm::Set(now),
         updated_at: sea_orm::Set(now),
+        version: sea_orm::Set(1),
+        deleted_at: sea_orm::Set(None),
     };
     
     let created_user = user_repository::create(db, user).await?;
@@ -66,7 +69,7 @@ pub async fn login(
     }
     
     // 生成 JWT token
-    let token = generate_token(user.id, user.username.clone(), jwt_config)?;
+    let token = generate_token(user.id, user.username.clone(), user.role, jwt_config)?;
     
     Ok(LoginResponse {
         token,
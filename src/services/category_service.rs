@@ -0,0 +1,225 @@
+use sea_orm::DatabaseConnection;
+use std::collections::HashMap;
+use uuid::Uuid;
+use crate::entities::category::{ActiveModel, Model};
+use crate::errors::{AppError, Result};
+use crate::models::{CategoryResponse, CategoryTreeNode, CreateCategoryRequest, UpdateCategoryRequest};
+use crate::repositories::category_repository;
+
+/// 分类树的最大层级（根节点为第 1 层）
+pub const MAX_CATEGORY_DEPTH: i32 = 8;
+
+/// 获取完整分类树
+pub async fn list_category_tree(db: &DatabaseConnection) -> Result<Vec<CategoryTreeNode>> {
+    let categories = category_repository::find_all(db).await?;
+
+    Ok(build_tree(categories, None))
+}
+
+/// 根据 ID 获取分类（包含其子树）
+pub async fn get_category(db: &DatabaseConnection, category_id: Uuid) -> Result<CategoryTreeNode> {
+    let category = category_repository::find_by_id(db, category_id).await?
+        .ok_or(AppError::NotFound)?;
+
+    let mut subtree = build_tree(
+        category_repository::find_subtree(db, &category.path).await?,
+        category.parent_id,
+    );
+
+    subtree.pop().ok_or(AppError::NotFound)
+}
+
+/// 创建分类
+pub async fn create_category(
+    db: &DatabaseConnection,
+    payload: CreateCategoryRequest,
+) -> Result<CategoryResponse> {
+    let name = validate_name(&payload.name)?;
+    let slug = validate_slug(&payload.slug)?;
+    ensure_slug_available(db, &slug, None).await?;
+
+    let category_id = Uuid::new_v4();
+    let (path, depth) = match payload.parent_id {
+        Some(parent_id) => {
+            let parent = category_repository::find_by_id(db, parent_id).await?
+                .ok_or_else(|| AppError::Validation("父分类不存在".to_string()))?;
+            (child_path(&parent.path, category_id), parent.depth + 1)
+        }
+        None => (child_path("/", category_id), 1),
+    };
+    if depth > MAX_CATEGORY_DEPTH {
+        return Err(AppError::Validation(format!("分类层级不能超过 {} 层", MAX_CATEGORY_DEPTH)));
+    }
+
+    let now = chrono::Utc::now();
+    let category = ActiveModel {
+        id: sea_orm::Set(category_id),
+        parent_id: sea_orm::Set(payload.parent_id),
+        name: sea_orm::Set(name),
+        slug: sea_orm::Set(slug),
+        path: sea_orm::Set(path),
+        depth: sea_orm::Set(depth),
+        sort_order: sea_orm::Set(payload.sort_order.unwrap_or(0)),
+        created_at: sea_orm::Set(now),
+        updated_at: sea_orm::Set(now),
+    };
+
+    let created = category_repository::create(db, category).await?;
+
+    Ok(CategoryResponse::from(created))
+}
+
+/// 更新分类（支持修改父分类，子树随之移动）
+pub async fn update_category(
+    db: &DatabaseConnection,
+    category_id: Uuid,
+    payload: UpdateCategoryRequest,
+) -> Result<CategoryResponse> {
+    let existing = category_repository::find_by_id(db, category_id).await?
+        .ok_or(AppError::NotFound)?;
+
+    // 先处理移动，移动会批量更新子树的 path 和 depth
+    if let Some(new_parent_id) = payload.parent_id {
+        if new_parent_id != existing.parent_id {
+            move_category(db, &existing, new_parent_id).await?;
+        }
+    }
+
+    let current = category_repository::find_by_id(db, category_id).await?
+        .ok_or(AppError::NotFound)?;
+    let mut category: ActiveModel = current.into();
+
+    if let Some(name) = payload.name {
+        category.name = sea_orm::Set(validate_name(&name)?);
+    }
+    if let Some(slug) = payload.slug {
+        let slug = validate_slug(&slug)?;
+        ensure_slug_available(db, &slug, Some(category_id)).await?;
+        category.slug = sea_orm::Set(slug);
+    }
+    if let Some(sort_order) = payload.sort_order {
+        category.sort_order = sea_orm::Set(sort_order);
+    }
+    category.updated_at = sea_orm::Set(chrono::Utc::now());
+
+    let updated = category_repository::update(db, category_id, category).await?;
+
+    Ok(CategoryResponse::from(updated))
+}
+
+/// 删除分类（存在子分类时拒绝删除，文章会被移出该分类）
+pub async fn delete_category(db: &DatabaseConnection, category_id: Uuid) -> Result<()> {
+    if category_repository::count_children(db, category_id).await? > 0 {
+        return Err(AppError::Validation("请先删除或移动子分类".to_string()));
+    }
+
+    category_repository::delete(db, category_id).await
+}
+
+/// 校验分类是否存在，返回分类实体
+pub async fn find_category(db: &DatabaseConnection, category_id: Uuid) -> Result<Model> {
+    category_repository::find_by_id(db, category_id).await?
+        .ok_or_else(|| AppError::Validation("分类不存在".to_string()))
+}
+
+/// 将分类移动到新的父分类下
+async fn move_category(
+    db: &DatabaseConnection,
+    category: &Model,
+    new_parent_id: Option<Uuid>,
+) -> Result<()> {
+    let (new_path, new_depth) = match new_parent_id {
+        Some(parent_id) => {
+            let parent = category_repository::find_by_id(db, parent_id).await?
+                .ok_or_else(|| AppError::Validation("父分类不存在".to_string()))?;
+            // 不能移动到自身或自己的子孙分类下
+            if parent.path.starts_with(&category.path) {
+                return Err(AppError::Validation("不能将分类移动到其子分类下".to_string()));
+            }
+            (child_path(&parent.path, category.id), parent.depth + 1)
+        }
+        None => (child_path("/", category.id), 1),
+    };
+
+    let depth_delta = new_depth - category.depth;
+    let subtree = category_repository::find_subtree(db, &category.path).await?;
+    let max_depth = subtree.iter().map(|c| c.depth).max().unwrap_or(category.depth);
+    if max_depth + depth_delta > MAX_CATEGORY_DEPTH {
+        return Err(AppError::Validation(format!("分类层级不能超过 {} 层", MAX_CATEGORY_DEPTH)));
+    }
+
+    category_repository::move_subtree(
+        db,
+        category.id,
+        new_parent_id,
+        &category.path,
+        &new_path,
+        depth_delta,
+    )
+    .await
+}
+
+/// 将扁平列表组装成树，`root_parent` 为树根节点的父分类
+fn build_tree(categories: Vec<Model>, root_parent: Option<Uuid>) -> Vec<CategoryTreeNode> {
+    let mut children: HashMap<Option<Uuid>, Vec<Model>> = HashMap::new();
+    for category in categories {
+        children.entry(category.parent_id).or_default().push(category);
+    }
+
+    fn attach(
+        parent: Option<Uuid>,
+        children: &mut HashMap<Option<Uuid>, Vec<Model>>,
+    ) -> Vec<CategoryTreeNode> {
+        let nodes = children.remove(&parent).unwrap_or_default();
+        nodes
+            .into_iter()
+            .map(|category| {
+                let id = category.id;
+                CategoryTreeNode {
+                    category: CategoryResponse::from(category),
+                    children: attach(Some(id), children),
+                }
+            })
+            .collect()
+    }
+
+    attach(root_parent, &mut children)
+}
+
+fn child_path(parent_path: &str, id: Uuid) -> String {
+    format!("{}{}/", parent_path, id)
+}
+
+fn validate_name(name: &str) -> Result<String> {
+    let name = name.trim();
+    if name.is_empty() || name.chars().count() > 50 {
+        return Err(AppError::Validation("分类名称长度必须在 1-50 个字符之间".to_string()));
+    }
+    Ok(name.to_string())
+}
+
+fn validate_slug(slug: &str) -> Result<String> {
+    let slug = slug.trim().to_lowercase();
+    let valid = !slug.is_empty()
+        && slug.len() <= 64
+        && slug.chars().all(|c| c.is_ascii_alphanumeric() || c == '-');
+    if !valid {
+        return Err(AppError::Validation(
+            "slug 只能包含小写字母、数字和连字符，长度 1-64".to_string(),
+        ));
+    }
+    Ok(slug)
+}
+
+async fn ensure_slug_available(
+    db: &DatabaseConnection,
+    slug: &str,
+    exclude_id: Option<Uuid>,
+) -> Result<()> {
+    if let Some(existing) = category_repository::find_by_slug(db, slug).await? {
+        if Some(existing.id) != exclude_id {
+            return Err(AppError::Validation("slug 已存在".to_string()));
+        }
+    }
+    Ok(())
+}
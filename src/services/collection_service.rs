@@ -0,0 +1,201 @@
+use sea_orm::DatabaseConnection;
+use uuid::Uuid;
+use crate::entities::collection::{ActiveModel, Model};
+use crate::errors::{AppError, Result};
+use crate::extractors::{AuthUser, Pagination};
+use crate::models::{
+    ArticleResponse, CollectionResponse, CreateCollectionRequest, UpdateCollectionRequest,
+};
+use crate::repositories::{collection_repository, user_repository};
+use crate::services::{article_service, engagement_service};
+use crate::services::user_service::{PagedResult, PaginationInfo};
+
+/// 收藏夹名称最大长度
+const MAX_NAME_CHARS: usize = 100;
+
+/// 获取用户的收藏夹列表（本人可见全部，其他人只能看到公开的收藏夹）
+pub async fn list_user_collections(
+    db: &DatabaseConnection,
+    user_id: Uuid,
+    viewer: Option<Uuid>,
+) -> Result<Vec<CollectionResponse>> {
+    user_repository::find_by_id(db, user_id).await?
+        .ok_or(AppError::NotFound)?;
+
+    let collections =
+        collection_repository::find_by_user(db, user_id, viewer != Some(user_id)).await?;
+
+    Ok(collections.into_iter().map(CollectionResponse::from).collect())
+}
+
+/// 获取收藏夹（私有收藏夹只有创建者可见）
+pub async fn get_collection(
+    db: &DatabaseConnection,
+    collection_id: Uuid,
+    viewer: Option<Uuid>,
+) -> Result<CollectionResponse> {
+    let collection = find_visible_collection(db, collection_id, viewer).await?;
+
+    Ok(CollectionResponse::from(collection))
+}
+
+/// 获取收藏夹中的文章（带分页，只返回当前用户可读的文章）
+pub async fn list_collection_articles(
+    db: &DatabaseConnection,
+    collection_id: Uuid,
+    viewer: Option<Uuid>,
+    pagination: &Pagination,
+) -> Result<PagedResult<Vec<ArticleResponse>>> {
+    find_visible_collection(db, collection_id, viewer).await?;
+
+    let (articles, total) = collection_repository::find_articles_with_pagination(
+        db,
+        collection_id,
+        viewer,
+        pagination.offset(),
+        pagination.limit(),
+    )
+    .await?;
+
+    let mut articles: Vec<ArticleResponse> =
+        articles.into_iter().map(ArticleResponse::summary).collect();
+    engagement_service::attach_engagement(db, &mut articles, viewer).await?;
+
+    Ok(PagedResult {
+        list: articles,
+        pagination: PaginationInfo::new(pagination, total),
+    })
+}
+
+/// 创建收藏夹
+pub async fn create_collection(
+    db: &DatabaseConnection,
+    user_id: Uuid,
+    payload: CreateCollectionRequest,
+) -> Result<CollectionResponse> {
+    let now = chrono::Utc::now();
+    let collection = ActiveModel {
+        id: sea_orm::Set(Uuid::new_v4()),
+        user_id: sea_orm::Set(user_id),
+        name: sea_orm::Set(validate_name(&payload.name)?),
+        description: sea_orm::Set(payload.description),
+        is_public: sea_orm::Set(payload.is_public),
+        created_at: sea_orm::Set(now),
+        updated_at: sea_orm::Set(now),
+    };
+
+    let created = collection_repository::create(db, collection).await?;
+
+    Ok(CollectionResponse::from(created))
+}
+
+/// 更新收藏夹（仅创建者）
+pub async fn update_collection(
+    db: &DatabaseConnection,
+    collection_id: Uuid,
+    auth_user: &AuthUser,
+    payload: UpdateCollectionRequest,
+) -> Result<CollectionResponse> {
+    let existing = find_owned_collection(db, collection_id, auth_user).await?;
+
+    let mut collection: ActiveModel = existing.into();
+    if let Some(name) = payload.name {
+        collection.name = sea_orm::Set(validate_name(&name)?);
+    }
+    if let Some(description) = payload.description {
+        collection.description = sea_orm::Set(description);
+    }
+    if let Some(is_public) = payload.is_public {
+        collection.is_public = sea_orm::Set(is_public);
+    }
+    collection.updated_at = sea_orm::Set(chrono::Utc::now());
+
+    let updated = collection_repository::update(db, collection_id, collection).await?;
+
+    Ok(CollectionResponse::from(updated))
+}
+
+/// 删除收藏夹（创建者或管理员）
+pub async fn delete_collection(
+    db: &DatabaseConnection,
+    collection_id: Uuid,
+    auth_user: &AuthUser,
+) -> Result<()> {
+    if !auth_user.is_admin() {
+        find_owned_collection(db, collection_id, auth_user).await?;
+    }
+
+    collection_repository::delete(db, collection_id).await
+}
+
+/// 把文章加入收藏夹（仅创建者，文章必须对创建者可见，幂等）
+pub async fn add_collection_article(
+    db: &DatabaseConnection,
+    collection_id: Uuid,
+    article_id: Uuid,
+    auth_user: &AuthUser,
+) -> Result<()> {
+    find_owned_collection(db, collection_id, auth_user).await?;
+    article_service::find_readable_article(db, article_id, Some(auth_user.user_id)).await?;
+
+    let item = crate::entities::collection_item::ActiveModel {
+        collection_id: sea_orm::Set(collection_id),
+        article_id: sea_orm::Set(article_id),
+        created_at: sea_orm::Set(chrono::Utc::now()),
+    };
+
+    collection_repository::insert_item_ignore(db, item).await
+}
+
+/// 把文章移出收藏夹（仅创建者，幂等）
+pub async fn remove_collection_article(
+    db: &DatabaseConnection,
+    collection_id: Uuid,
+    article_id: Uuid,
+    auth_user: &AuthUser,
+) -> Result<()> {
+    find_owned_collection(db, collection_id, auth_user).await?;
+    collection_repository::delete_item(db, collection_id, article_id).await
+}
+
+/// 查找当前用户可见的收藏夹，不可见时返回 404
+async fn find_visible_collection(
+    db: &DatabaseConnection,
+    collection_id: Uuid,
+    viewer: Option<Uuid>,
+) -> Result<Model> {
+    let collection = collection_repository::find_by_id(db, collection_id).await?
+        .ok_or(AppError::NotFound)?;
+
+    if !collection.is_public && viewer != Some(collection.user_id) {
+        return Err(AppError::NotFound);
+    }
+
+    Ok(collection)
+}
+
+/// 查找当前用户创建的收藏夹（他人的私有收藏夹返回 404，公开收藏夹返回 403）
+async fn find_owned_collection(
+    db: &DatabaseConnection,
+    collection_id: Uuid,
+    auth_user: &AuthUser,
+) -> Result<Model> {
+    let collection = find_visible_collection(db, collection_id, Some(auth_user.user_id)).await?;
+
+    if collection.user_id != auth_user.user_id {
+        return Err(AppError::Forbidden);
+    }
+
+    Ok(collection)
+}
+
+fn validate_name(name: &str) -> Result<String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(AppError::Validation("收藏夹名称不能为空".to_string()));
+    }
+    if name.chars().count() > MAX_NAME_CHARS {
+        return Err(AppError::Validation(format!("收藏夹名称不能超过 {} 个字符", MAX_NAME_CHARS)));
+    }
+    Ok(name.to_string())
+}
@@ -0,0 +1,206 @@
+use sea_orm::DatabaseConnection;
+use std::collections::HashMap;
+use uuid::Uuid;
+use crate::content_filter::{content_hash, ContentFilterPipeline, ContentKind, FilterInput};
+use crate::entities::comment::{ActiveModel, Model};
+use crate::entities::content_report::ReportTarget;
+use crate::errors::{AppError, Result};
+use crate::extractors::AuthUser;
+use crate::models::{CommentResponse, CreateCommentRequest, UpdateCommentRequest};
+use crate::repositories::comment_repository;
+use crate::services::{article_service, moderation_service};
+
+/// 评论最大嵌套深度（顶层评论深度为 0）
+pub const MAX_COMMENT_DEPTH: i32 = 4;
+
+/// 评论内容最大长度（字符数）
+const MAX_COMMENT_LENGTH: usize = 2000;
+
+/// 获取文章的评论树
+pub async fn list_comments(
+    db: &DatabaseConnection,
+    article_id: Uuid,
+    user_id: Option<Uuid>,
+) -> Result<Vec<CommentResponse>> {
+    article_service::find_readable_article(db, article_id, user_id).await?;
+
+    let comments = comment_repository::find_by_article(db, article_id).await?;
+
+    Ok(build_tree(comments))
+}
+
+/// 发表评论或回复（写入前经过内容过滤，需要审核时写入后放入待处理队列）
+pub async fn create_comment(
+    db: &DatabaseConnection,
+    filter: &ContentFilterPipeline,
+    article_id: Uuid,
+    user_id: Uuid,
+    payload: CreateCommentRequest,
+) -> Result<CommentResponse> {
+    article_service::find_readable_article(db, article_id, Some(user_id)).await?;
+    let content = validate_content(&payload.content)?;
+
+    let depth = match payload.parent_id {
+        Some(parent_id) => {
+            let parent = find_article_comment(db, article_id, parent_id).await?;
+            if parent.is_deleted {
+                return Err(AppError::Validation("不能回复已删除的评论".to_string()));
+            }
+            if parent.depth >= MAX_COMMENT_DEPTH {
+                return Err(AppError::Validation("评论嵌套层级已达上限".to_string()));
+            }
+            parent.depth + 1
+        }
+        None => 0,
+    };
+    let flagged = screen_comment(db, filter, None, user_id, &content).await?;
+
+    let now = chrono::Utc::now();
+    let comment = ActiveModel {
+        id: sea_orm::Set(Uuid::new_v4()),
+        article_id: sea_orm::Set(article_id),
+        user_id: sea_orm::Set(Some(user_id)),
+        parent_id: sea_orm::Set(payload.parent_id),
+        depth: sea_orm::Set(depth),
+        content_hash: sea_orm::Set(Some(content_hash(&content))),
+        content: sea_orm::Set(content),
+        is_deleted: sea_orm::Set(false),
+        created_at: sea_orm::Set(now),
+        updated_at: sea_orm::Set(now),
+    };
+
+    let created = comment_repository::create(db, comment).await?;
+    if let Some(reason) = flagged {
+        moderation_service::flag_content(db, ReportTarget::Comment, created.id, reason).await;
+    }
+
+    Ok(CommentResponse::from(created))
+}
+
+/// 编辑评论（仅评论作者，新内容同样经过内容过滤）
+pub async fn update_comment(
+    db: &DatabaseConnection,
+    filter: &ContentFilterPipeline,
+    article_id: Uuid,
+    comment_id: Uuid,
+    user_id: Uuid,
+    payload: UpdateCommentRequest,
+) -> Result<CommentResponse> {
+    article_service::find_readable_article(db, article_id, Some(user_id)).await?;
+    let existing = find_article_comment(db, article_id, comment_id).await?;
+
+    if existing.is_deleted {
+        return Err(AppError::NotFound);
+    }
+    if existing.user_id != Some(user_id) {
+        return Err(AppError::Forbidden);
+    }
+
+    let content = validate_content(&payload.content)?;
+    let flagged = screen_comment(db, filter, Some(comment_id), user_id, &content).await?;
+
+    let mut comment: ActiveModel = existing.into();
+    comment.content_hash = sea_orm::Set(Some(content_hash(&content)));
+    comment.content = sea_orm::Set(content);
+    comment.updated_at = sea_orm::Set(chrono::Utc::now());
+
+    let updated = comment_repository::update(db, comment_id, comment).await?;
+    if let Some(reason) = flagged {
+        moderation_service::flag_content(db, ReportTarget::Comment, comment_id, reason).await;
+    }
+
+    Ok(CommentResponse::from(updated))
+}
+
+/// 删除评论（软删除，保留占位以维持评论树结构；评论作者或管理员）
+pub async fn delete_comment(
+    db: &DatabaseConnection,
+    article_id: Uuid,
+    comment_id: Uuid,
+    auth_user: &AuthUser,
+) -> Result<()> {
+    let existing = find_article_comment(db, article_id, comment_id).await?;
+
+    if existing.is_deleted {
+        return Err(AppError::NotFound);
+    }
+    if existing.user_id != Some(auth_user.user_id) && !auth_user.is_admin() {
+        return Err(AppError::Forbidden);
+    }
+
+    let mut comment: ActiveModel = existing.into();
+    comment.content = sea_orm::Set(String::new());
+    comment.content_hash = sea_orm::Set(None);
+    comment.is_deleted = sea_orm::Set(true);
+    comment.updated_at = sea_orm::Set(chrono::Utc::now());
+
+    comment_repository::update(db, comment_id, comment).await?;
+
+    Ok(())
+}
+
+/// 对评论内容运行内容过滤，返回需要审核的原因
+async fn screen_comment(
+    db: &DatabaseConnection,
+    filter: &ContentFilterPipeline,
+    comment_id: Option<Uuid>,
+    user_id: Uuid,
+    content: &str,
+) -> Result<Option<String>> {
+    let input = FilterInput {
+        kind: ContentKind::Comment,
+        id: comment_id,
+        author_id: user_id,
+        title: None,
+        body: content,
+    };
+    moderation_service::screen_content(db, filter, &input).await
+}
+
+/// 查找属于指定文章的评论
+async fn find_article_comment(
+    db: &DatabaseConnection,
+    article_id: Uuid,
+    comment_id: Uuid,
+) -> Result<Model> {
+    comment_repository::find_by_id(db, comment_id).await?
+        .filter(|c| c.article_id == article_id)
+        .ok_or(AppError::NotFound)
+}
+
+/// 将按时间排序的扁平评论列表组装成树
+fn build_tree(comments: Vec<Model>) -> Vec<CommentResponse> {
+    let mut children: HashMap<Option<Uuid>, Vec<Model>> = HashMap::new();
+    for comment in comments {
+        children.entry(comment.parent_id).or_default().push(comment);
+    }
+
+    fn attach(
+        parent: Option<Uuid>,
+        children: &mut HashMap<Option<Uuid>, Vec<Model>>,
+    ) -> Vec<CommentResponse> {
+        let nodes = children.remove(&parent).unwrap_or_default();
+        nodes
+            .into_iter()
+            .map(|comment| {
+                let id = comment.id;
+                let mut node = CommentResponse::from(comment);
+                node.children = attach(Some(id), children);
+                node
+            })
+            .collect()
+    }
+
+    attach(None, &mut children)
+}
+
+fn validate_content(content: &str) -> Result<String> {
+    let content = content.trim();
+    if content.is_empty() || content.chars().count() > MAX_COMMENT_LENGTH {
+        return Err(AppError::Validation(format!(
+            "评论内容长度必须在 1-{} 个字符之间",
+            MAX_COMMENT_LENGTH
+        )));
+    }
+    Ok(content.to_string())
+}
@@ -0,0 +1,128 @@
+use sea_orm::DatabaseConnection;
+use std::collections::HashMap;
+use uuid::Uuid;
+use crate::entities::reaction::ReactionKind;
+use crate::errors::Result;
+use crate::extractors::Pagination;
+use crate::models::{ArticleResponse, ViewerEngagement};
+use crate::repositories::{bookmark_repository, reaction_repository};
+use crate::services::article_service;
+use crate::services::user_service::{PagedResult, PaginationInfo};
+
+/// 设置对文章的表情反应（幂等，重复设置会覆盖之前的反应）
+pub async fn set_reaction(
+    db: &DatabaseConnection,
+    article_id: Uuid,
+    user_id: Uuid,
+    reaction: ReactionKind,
+) -> Result<()> {
+    article_service::find_readable_article(db, article_id, Some(user_id)).await?;
+
+    let model = crate::entities::reaction::ActiveModel {
+        user_id: sea_orm::Set(user_id),
+        article_id: sea_orm::Set(article_id),
+        reaction: sea_orm::Set(reaction),
+        created_at: sea_orm::Set(chrono::Utc::now()),
+    };
+
+    reaction_repository::upsert(db, model).await
+}
+
+/// 取消表情反应（幂等）
+pub async fn remove_reaction(db: &DatabaseConnection, article_id: Uuid, user_id: Uuid) -> Result<()> {
+    reaction_repository::delete(db, user_id, article_id).await
+}
+
+/// 收藏文章（幂等）
+pub async fn add_bookmark(db: &DatabaseConnection, article_id: Uuid, user_id: Uuid) -> Result<()> {
+    article_service::find_readable_article(db, article_id, Some(user_id)).await?;
+
+    let model = crate::entities::bookmark::ActiveModel {
+        user_id: sea_orm::Set(user_id),
+        article_id: sea_orm::Set(article_id),
+        created_at: sea_orm::Set(chrono::Utc::now()),
+    };
+
+    bookmark_repository::insert_ignore(db, model).await
+}
+
+/// 取消收藏（幂等）
+pub async fn remove_bookmark(db: &DatabaseConnection, article_id: Uuid, user_id: Uuid) -> Result<()> {
+    bookmark_repository::delete(db, user_id, article_id).await
+}
+
+/// 获取用户的收藏列表（带分页）
+pub async fn list_bookmarks(
+    db: &DatabaseConnection,
+    user_id: Uuid,
+    pagination: &Pagination,
+) -> Result<PagedResult<Vec<ArticleResponse>>> {
+    let (articles, total) = bookmark_repository::find_articles_by_user_with_pagination(
+        db,
+        user_id,
+        pagination.offset(),
+        pagination.limit(),
+    )
+    .await?;
+
+    let mut articles: Vec<ArticleResponse> =
+        articles.into_iter().map(ArticleResponse::summary).collect();
+    attach_engagement(db, &mut articles, Some(user_id)).await?;
+
+    Ok(PagedResult {
+        list: articles,
+        pagination: PaginationInfo::new(pagination, total),
+    })
+}
+
+/// 为文章列表批量填充互动统计
+///
+/// 无论列表长度如何，只会发出固定数量的聚合查询；
+/// `viewer` 存在时额外返回该用户自己的反应和收藏状态
+pub async fn attach_engagement(
+    db: &DatabaseConnection,
+    articles: &mut [ArticleResponse],
+    viewer: Option<Uuid>,
+) -> Result<()> {
+    if articles.is_empty() {
+        return Ok(());
+    }
+    let ids: Vec<Uuid> = articles.iter().map(|a| a.id).collect();
+
+    let mut reaction_counts: HashMap<Uuid, Vec<(ReactionKind, i64)>> = HashMap::new();
+    for (article_id, reaction, count) in reaction_repository::count_by_articles(db, &ids).await? {
+        reaction_counts.entry(article_id).or_default().push((reaction, count));
+    }
+    let bookmark_counts: HashMap<Uuid, i64> = bookmark_repository::count_by_articles(db, &ids)
+        .await?
+        .into_iter()
+        .collect();
+
+    let mut viewer_state: Option<HashMap<Uuid, ViewerEngagement>> = None;
+    if let Some(user_id) = viewer {
+        let mut state: HashMap<Uuid, ViewerEngagement> = HashMap::new();
+        for reaction in reaction_repository::find_by_user_and_articles(db, user_id, &ids).await? {
+            state.entry(reaction.article_id).or_default().reaction = Some(reaction.reaction);
+        }
+        for bookmark in bookmark_repository::find_by_user_and_articles(db, user_id, &ids).await? {
+            state.entry(bookmark.article_id).or_default().bookmarked = true;
+        }
+        viewer_state = Some(state);
+    }
+
+    for article in articles.iter_mut() {
+        let engagement = &mut article.engagement;
+        if let Some(counts) = reaction_counts.remove(&article.id) {
+            engagement.reactions = counts
+                .into_iter()
+                .map(|(reaction, count)| (reaction, count as u64))
+                .collect();
+        }
+        engagement.bookmarks = bookmark_counts.get(&article.id).copied().unwrap_or(0) as u64;
+        engagement.viewer = viewer_state
+            .as_mut()
+            .map(|state| state.remove(&article.id).unwrap_or_default());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,250 @@
+use chrono::{DateTime, Utc};
+use sea_orm::DatabaseConnection;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use uuid::Uuid;
+use crate::config::{FeedConfig, ServerConfig};
+use crate::errors::{AppError, Result};
+use crate::render::render_content;
+use crate::repositories::article_repository::{self, CategoryFilter};
+use crate::repositories::{category_repository, user_repository};
+use crate::utils::escape_html;
+
+/// 订阅源格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FeedFormat {
+    Rss,
+    Atom,
+}
+
+impl FeedFormat {
+    pub fn content_type(self) -> &'static str {
+        match self {
+            FeedFormat::Rss => "application/rss+xml; charset=utf-8",
+            FeedFormat::Atom => "application/atom+xml; charset=utf-8",
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            FeedFormat::Rss => "rss",
+            FeedFormat::Atom => "atom",
+        }
+    }
+}
+
+/// 订阅源范围
+#[derive(Debug, Clone, Hash)]
+pub enum FeedScope {
+    /// 全站公开文章
+    All,
+    /// 指定作者（用户名）
+    Author(String),
+    /// 指定分类（slug，包含子孙分类）
+    Category(String),
+}
+
+/// 订阅源
+#[derive(Debug)]
+pub struct Feed {
+    pub format: FeedFormat,
+    pub title: String,
+    pub description: String,
+    /// 订阅源自身的地址
+    pub self_url: String,
+    /// 站点地址
+    pub site_url: String,
+    /// 最近一篇文章的修改时间，没有文章时为空
+    pub updated: Option<DateTime<Utc>>,
+    /// 弱 ETag 的标签值（不含 `W/` 和引号），由范围、格式和每篇文章的 ID 与修改时间计算
+    pub etag: String,
+    pub entries: Vec<FeedEntry>,
+}
+
+/// 订阅源中的一篇文章
+#[derive(Debug)]
+pub struct FeedEntry {
+    pub id: Uuid,
+    pub title: String,
+    pub url: String,
+    pub author: Option<String>,
+    /// 渲染并清洗后的 HTML 正文
+    pub content_html: String,
+    pub published: DateTime<Utc>,
+    pub updated: DateTime<Utc>,
+}
+
+/// 构建订阅源（仅包含公开文章）
+pub async fn build_feed(
+    db: &DatabaseConnection,
+    server: &ServerConfig,
+    config: &FeedConfig,
+    scope: FeedScope,
+    format: FeedFormat,
+) -> Result<Feed> {
+    let base = &server.public_url;
+    let (title, author_id, category, path) = match &scope {
+        FeedScope::All => (config.title.clone(), None, None, "/feeds/articles".to_string()),
+        FeedScope::Author(username) => {
+            let author = user_repository::find_by_username(db, username).await?
+                .ok_or(AppError::NotFound)?;
+            (
+                format!("{} - {}", config.title, author.username),
+                Some(author.id),
+                None,
+                format!("/feeds/authors/{}/articles", username),
+            )
+        }
+        FeedScope::Category(slug) => {
+            let category = category_repository::find_by_slug(db, slug).await?
+                .ok_or(AppError::NotFound)?;
+            (
+                format!("{} - {}", config.title, category.name),
+                None,
+                Some(CategoryFilter::Subtree(category.path)),
+                format!("/feeds/categories/{}/articles", slug),
+            )
+        }
+    };
+
+    let articles =
+        article_repository::find_public_recent(db, author_id, category, config.max_items).await?;
+
+    let author_ids: Vec<Uuid> = articles.iter().filter_map(|a| a.user_id).collect();
+    let authors: HashMap<Uuid, String> = user_repository::find_by_ids(db, &author_ids)
+        .await?
+        .into_iter()
+        .map(|u| (u.id, u.username))
+        .collect();
+
+    let mut hasher = DefaultHasher::new();
+    scope.hash(&mut hasher);
+    format.hash(&mut hasher);
+
+    let entries: Vec<FeedEntry> = articles
+        .into_iter()
+        .map(|article| {
+            let published = article.created_at.unwrap_or_default();
+            let updated = article.updated_at.unwrap_or(published);
+            article.id.hash(&mut hasher);
+            updated.hash(&mut hasher);
+
+            FeedEntry {
+                id: article.id,
+                url: format!("{}/api/articles/{}", base, article.id),
+                author: article.user_id.and_then(|id| authors.get(&id).cloned()),
+                content_html: article
+                    .content_html
+                    .unwrap_or_else(|| render_content(&article.content, article.content_format)),
+                title: article.title,
+                published,
+                updated,
+            }
+        })
+        .collect();
+
+    Ok(Feed {
+        format,
+        title,
+        description: config.description.clone(),
+        self_url: format!("{}{}.{}", base, path, format.extension()),
+        site_url: base.clone(),
+        updated: entries.iter().map(|e| e.updated).max(),
+        etag: format!("{:016x}", hasher.finish()),
+        entries,
+    })
+}
+
+/// 按订阅源格式渲染 XML
+pub fn render_feed(feed: &Feed) -> String {
+    match feed.format {
+        FeedFormat::Rss => render_rss(feed),
+        FeedFormat::Atom => render_atom(feed),
+    }
+}
+
+/// 渲染为 RSS 2.0
+fn render_rss(feed: &Feed) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(concat!(
+        "<rss version=\"2.0\" xmlns:atom=\"http://www.w3.org/2005/Atom\" ",
+        "xmlns:dc=\"http://purl.org/dc/elements/1.1/\">\n<channel>\n",
+    ));
+    push_element(&mut xml, "title", &feed.title);
+    push_element(&mut xml, "link", &feed.site_url);
+    push_element(&mut xml, "description", &feed.description);
+    xml.push_str(&format!(
+        "<atom:link href=\"{}\" rel=\"self\" type=\"application/rss+xml\"/>\n",
+        escape_html(&feed.self_url)
+    ));
+    if let Some(updated) = feed.updated {
+        push_element(&mut xml, "lastBuildDate", &updated.to_rfc2822());
+    }
+
+    for entry in &feed.entries {
+        xml.push_str("<item>\n");
+        push_element(&mut xml, "title", &entry.title);
+        push_element(&mut xml, "link", &entry.url);
+        xml.push_str(&format!(
+            "<guid isPermaLink=\"false\">urn:uuid:{}</guid>\n",
+            entry.id
+        ));
+        push_element(&mut xml, "pubDate", &entry.published.to_rfc2822());
+        if let Some(author) = &entry.author {
+            // RSS 的 <author> 要求邮箱地址，只有用户名时使用 Dublin Core 的 creator
+            push_element(&mut xml, "dc:creator", author);
+        }
+        push_element(&mut xml, "description", &entry.content_html);
+        xml.push_str("</item>\n");
+    }
+
+    xml.push_str("</channel>\n</rss>\n");
+    xml
+}
+
+/// 渲染为 Atom 1.0
+fn render_atom(feed: &Feed) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str("<feed xmlns=\"http://www.w3.org/2005/Atom\">\n");
+    push_element(&mut xml, "id", &feed.self_url);
+    push_element(&mut xml, "title", &feed.title);
+    push_element(&mut xml, "subtitle", &feed.description);
+    xml.push_str(&format!(
+        "<link href=\"{}\" rel=\"self\" type=\"application/atom+xml\"/>\n",
+        escape_html(&feed.self_url)
+    ));
+    xml.push_str(&format!("<link href=\"{}\"/>\n", escape_html(&feed.site_url)));
+    // Atom 要求 <updated>，没有文章时使用 Unix 纪元
+    push_element(
+        &mut xml,
+        "updated",
+        &feed.updated.unwrap_or_default().to_rfc3339(),
+    );
+
+    for entry in &feed.entries {
+        xml.push_str("<entry>\n");
+        push_element(&mut xml, "id", &format!("urn:uuid:{}", entry.id));
+        push_element(&mut xml, "title", &entry.title);
+        xml.push_str(&format!("<link href=\"{}\"/>\n", escape_html(&entry.url)));
+        push_element(&mut xml, "published", &entry.published.to_rfc3339());
+        push_element(&mut xml, "updated", &entry.updated.to_rfc3339());
+        if let Some(author) = &entry.author {
+            xml.push_str("<author>");
+            push_element(&mut xml, "name", author);
+            xml.push_str("</author>\n");
+        }
+        xml.push_str(&format!(
+            "<content type=\"html\">{}</content>\n",
+            escape_html(&entry.content_html)
+        ));
+        xml.push_str("</entry>\n");
+    }
+
+    xml.push_str("</feed>\n");
+    xml
+}
+
+fn push_element(xml: &mut String, name: &str, text: &str) {
+    xml.push_str(&format!("<{0}>{1}</{0}>\n", name, escape_html(text)));
+}
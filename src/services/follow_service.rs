@@ -0,0 +1,108 @@
+use sea_orm::DatabaseConnection;
+use uuid::Uuid;
+use crate::errors::{AppError, Result};
+use crate::extractors::{Cursor, CursorPagination, Pagination, PreferredLanguages};
+use crate::models::{ArticleResponse, UserResponse};
+use crate::repositories::{article_repository, follow_repository, user_repository};
+use crate::services::{engagement_service, translation_service};
+use crate::services::user_service::{CursorInfo, CursorPagedResult, PagedResult, PaginationInfo};
+
+/// 关注用户（幂等，不能关注自己）
+pub async fn follow_user(db: &DatabaseConnection, follower_id: Uuid, followee_id: Uuid) -> Result<()> {
+    if follower_id == followee_id {
+        return Err(AppError::Validation("不能关注自己".to_string()));
+    }
+    user_repository::find_by_id(db, followee_id).await?
+        .ok_or(AppError::NotFound)?;
+
+    let model = crate::entities::follow::ActiveModel {
+        follower_id: sea_orm::Set(follower_id),
+        followee_id: sea_orm::Set(followee_id),
+        created_at: sea_orm::Set(chrono::Utc::now()),
+    };
+
+    follow_repository::insert_ignore(db, model).await
+}
+
+/// 取消关注（幂等）
+pub async fn unfollow_user(db: &DatabaseConnection, follower_id: Uuid, followee_id: Uuid) -> Result<()> {
+    follow_repository::delete(db, follower_id, followee_id).await
+}
+
+/// 获取用户的关注者列表（带分页，按关注时间倒序）
+pub async fn list_followers(
+    db: &DatabaseConnection,
+    user_id: Uuid,
+    pagination: &Pagination,
+) -> Result<PagedResult<Vec<UserResponse>>> {
+    user_repository::find_by_id(db, user_id).await?
+        .ok_or(AppError::NotFound)?;
+
+    let (users, total) = follow_repository::find_followers_with_pagination(
+        db,
+        user_id,
+        pagination.offset(),
+        pagination.limit(),
+    )
+    .await?;
+
+    Ok(PagedResult {
+        list: users.into_iter().map(UserResponse::from).collect(),
+        pagination: PaginationInfo::new(pagination, total),
+    })
+}
+
+/// 获取用户关注的人（带分页，按关注时间倒序）
+pub async fn list_following(
+    db: &DatabaseConnection,
+    user_id: Uuid,
+    pagination: &Pagination,
+) -> Result<PagedResult<Vec<UserResponse>>> {
+    user_repository::find_by_id(db, user_id).await?
+        .ok_or(AppError::NotFound)?;
+
+    let (users, total) = follow_repository::find_following_with_pagination(
+        db,
+        user_id,
+        pagination.offset(),
+        pagination.limit(),
+    )
+    .await?;
+
+    Ok(PagedResult {
+        list: users.into_iter().map(UserResponse::from).collect(),
+        pagination: PaginationInfo::new(pagination, total),
+    })
+}
+
+/// 首页动态：关注的作者发布的公开文章（游标分页，按创建时间倒序）
+pub async fn home_feed(
+    db: &DatabaseConnection,
+    user_id: Uuid,
+    pagination: &CursorPagination,
+    languages: &PreferredLanguages,
+) -> Result<CursorPagedResult<Vec<ArticleResponse>>> {
+    let (articles, has_more, total) =
+        article_repository::find_feed_after_cursor(db, user_id, pagination).await?;
+
+    let next_cursor = has_more
+        .then(|| articles.last())
+        .flatten()
+        .and_then(|a| Some(Cursor { created_at: a.created_at?, id: a.id }.encode()));
+
+    let mut list: Vec<ArticleResponse> = translation_service::localize(db, articles, languages)
+        .await?
+        .into_iter()
+        .map(|(article, localization)| ArticleResponse::summary(article).localized(localization))
+        .collect();
+    engagement_service::attach_engagement(db, &mut list, Some(user_id)).await?;
+
+    Ok(CursorPagedResult {
+        list,
+        cursor: CursorInfo {
+            limit: pagination.limit,
+            next_cursor,
+            total,
+        },
+    })
+}
@@ -2,8 +2,44 @@
 pub mod auth_service;
 pub mod user_service;
 pub mod article_service;
+pub mod category_service;
+pub mod comment_service;
+pub mod engagement_service;
+pub mod search_service;
+pub mod revision_service;
+pub mod attachment_service;
+pub mod view_service;
+pub mod feed_service;
+pub mod sitemap_service;
+pub mod transfer_service;
+pub mod series_service;
+pub mod collection_service;
+pub mod moderation_service;
+pub mod translation_service;
+pub mod share_service;
+pub mod trash_service;
+pub mod follow_service;
+pub mod review_service;
 
 pub use auth_service::*;
 pub use user_service::*;
 pub use article_service::*;
+pub use category_service::*;
+pub use comment_service::*;
+pub use engagement_service::*;
+pub use search_service::*;
+pub use revision_service::*;
+pub use attachment_service::*;
+pub use view_service::*;
+pub use feed_service::*;
+pub use sitemap_service::*;
+pub use transfer_service::*;
+pub use series_service::*;
+pub use collection_service::*;
+pub use moderation_service::*;
+pub use translation_service::*;
+pub use share_service::*;
+pub use trash_service::*;
+pub use follow_service::*;
+pub use review_service::*;
 
@@ -0,0 +1,333 @@
+use sea_orm::DatabaseConnection;
+use std::collections::{BTreeMap, HashMap};
+use uuid::Uuid;
+use crate::content_filter::{ContentFilterPipeline, Decision, FilterInput};
+use crate::entities::content_report::{self, ReportReason, ReportStatus, ReportTarget};
+use crate::entities::moderation_action::{self, ModerationActionKind};
+use crate::errors::{AppError, Result};
+use crate::extractors::{AuthUser, Pagination};
+use crate::models::{
+    ArticleResponse, CommentResponse, CreateReportRequest, ModerateContentRequest,
+    ModerationActionQuery, ModerationActionResponse, ModerationQueueItem,
+};
+use crate::repositories::{article_repository, comment_repository, moderation_repository};
+use crate::services::article_service;
+use crate::services::user_service::{PagedResult, PaginationInfo};
+
+/// 举报说明、管理操作说明的最大长度
+const MAX_NOTE_CHARS: usize = 1000;
+
+/// 举报文章（需要能阅读该文章；同一用户重复举报时保留第一次的举报，幂等）
+pub async fn report_article(
+    db: &DatabaseConnection,
+    article_id: Uuid,
+    auth_user: &AuthUser,
+    payload: CreateReportRequest,
+) -> Result<()> {
+    let article =
+        article_service::find_readable_article(db, article_id, Some(auth_user.user_id)).await?;
+    if article.user_id == Some(auth_user.user_id) {
+        return Err(AppError::Validation("不能举报自己的文章".to_string()));
+    }
+
+    if payload.reason == ReportReason::Automated {
+        return Err(AppError::Validation("不能使用该举报原因".to_string()));
+    }
+    let details = normalize_note(payload.details, "举报说明")?;
+    if payload.reason == ReportReason::Other && details.is_none() {
+        return Err(AppError::Validation("举报原因为其他时必须填写说明".to_string()));
+    }
+
+    let report = content_report::ActiveModel {
+        id: sea_orm::Set(Uuid::new_v4()),
+        target_type: sea_orm::Set(ReportTarget::Article),
+        target_id: sea_orm::Set(article_id),
+        reporter_id: sea_orm::Set(Some(auth_user.user_id)),
+        reason: sea_orm::Set(payload.reason),
+        details: sea_orm::Set(details),
+        status: sea_orm::Set(ReportStatus::Open),
+        created_at: sea_orm::Set(chrono::Utc::now()),
+        resolved_at: sea_orm::Set(None),
+        resolved_by: sea_orm::Set(None),
+    };
+
+    moderation_repository::insert_report_ignore(db, report).await
+}
+
+/// 写入前运行内容过滤管道
+///
+/// 拒绝时返回校验错误；需要审核时返回审核原因，调用方在内容写入后交给 `flag_content`
+pub async fn screen_content(
+    db: &DatabaseConnection,
+    filter: &ContentFilterPipeline,
+    input: &FilterInput<'_>,
+) -> Result<Option<String>> {
+    match filter.check(db, input).await? {
+        Decision::Accept => Ok(None),
+        Decision::Flag(reasons) => Ok(Some(reasons.join("；"))),
+        Decision::Reject(reason) => Err(AppError::Validation(format!("内容未通过审核：{}", reason))),
+    }
+}
+
+/// 以内容过滤器的名义提交举报，把内容放入待处理队列
+///
+/// 该内容已有待处理的自动举报时不重复提交；内容已经写入，失败时只记录日志
+pub async fn flag_content(
+    db: &DatabaseConnection,
+    target_type: ReportTarget,
+    target_id: Uuid,
+    reason: String,
+) {
+    let result = async {
+        if moderation_repository::has_open_automated_report(db, target_type, target_id).await? {
+            return Ok(());
+        }
+
+        let report = content_report::ActiveModel {
+            id: sea_orm::Set(Uuid::new_v4()),
+            target_type: sea_orm::Set(target_type),
+            target_id: sea_orm::Set(target_id),
+            reporter_id: sea_orm::Set(None),
+            reason: sea_orm::Set(ReportReason::Automated),
+            details: sea_orm::Set(Some(reason)),
+            status: sea_orm::Set(ReportStatus::Open),
+            created_at: sea_orm::Set(chrono::Utc::now()),
+            resolved_at: sea_orm::Set(None),
+            resolved_by: sea_orm::Set(None),
+        };
+        moderation_repository::insert_report_ignore(db, report).await
+    }
+    .await;
+
+    if let Err(e) = result {
+        tracing::error!("提交自动举报失败 {:?} {}: {}", target_type, target_id, e);
+    }
+}
+
+/// 获取待处理队列（仅管理员，带分页）
+pub async fn moderation_queue(
+    db: &DatabaseConnection,
+    auth_user: &AuthUser,
+    pagination: &Pagination,
+) -> Result<PagedResult<Vec<ModerationQueueItem>>> {
+    auth_user.require_admin()?;
+
+    let (entries, total) = moderation_repository::find_queue_with_pagination(
+        db,
+        pagination.offset(),
+        pagination.limit(),
+    )
+    .await?;
+
+    let target_ids = |target_type: ReportTarget| -> Vec<Uuid> {
+        entries
+            .iter()
+            .filter(|entry| entry.target_type == target_type)
+            .map(|entry| entry.target_id)
+            .collect()
+    };
+    let article_ids = target_ids(ReportTarget::Article);
+    let comment_ids = target_ids(ReportTarget::Comment);
+
+    let mut reasons: HashMap<(ReportTarget, Uuid), BTreeMap<ReportReason, u64>> = HashMap::new();
+    for (target_type, ids) in [
+        (ReportTarget::Article, &article_ids),
+        (ReportTarget::Comment, &comment_ids),
+    ] {
+        for (target_id, reason, count) in
+            moderation_repository::count_open_reasons(db, target_type, ids).await?
+        {
+            reasons
+                .entry((target_type, target_id))
+                .or_default()
+                .insert(reason, count.max(0) as u64);
+        }
+    }
+
+    let mut articles: HashMap<Uuid, ArticleResponse> =
+        article_repository::find_by_ids(db, &article_ids)
+            .await?
+            .into_iter()
+            .map(|article| (article.id, ArticleResponse::summary(article)))
+            .collect();
+    let mut comments: HashMap<Uuid, CommentResponse> =
+        comment_repository::find_by_ids(db, &comment_ids)
+            .await?
+            .into_iter()
+            .filter(|comment| !comment.is_deleted)
+            .map(|comment| (comment.id, CommentResponse::from(comment)))
+            .collect();
+
+    let list = entries
+        .into_iter()
+        .map(|entry| ModerationQueueItem {
+            target_type: entry.target_type,
+            target_id: entry.target_id,
+            report_count: entry.report_count.max(0) as u64,
+            reasons: reasons.remove(&(entry.target_type, entry.target_id)).unwrap_or_default(),
+            first_reported_at: entry.first_reported_at,
+            last_reported_at: entry.last_reported_at,
+            article: match entry.target_type {
+                ReportTarget::Article => articles.remove(&entry.target_id),
+                ReportTarget::Comment => None,
+            },
+            comment: match entry.target_type {
+                ReportTarget::Article => None,
+                ReportTarget::Comment => comments.remove(&entry.target_id),
+            },
+        })
+        .collect();
+
+    Ok(PagedResult {
+        list,
+        pagination: PaginationInfo::new(pagination, total),
+    })
+}
+
+/// 对文章执行管理操作（仅管理员）
+///
+/// - `hide`：隐藏文章，待处理举报标记为已处理
+/// - `restore`：恢复被隐藏的文章
+/// - `delete`：删除文章（放入回收站，管理员可在回收站中恢复），待处理举报标记为已处理
+/// - `warn`：警告作者（必须填写说明），待处理举报标记为已处理
+/// - `dismiss`：驳回待处理举报，不修改文章
+pub async fn moderate_article(
+    db: &DatabaseConnection,
+    article_id: Uuid,
+    auth_user: &AuthUser,
+    payload: ModerateContentRequest,
+) -> Result<ModerationActionResponse> {
+    auth_user.require_admin()?;
+
+    let article = article_repository::find_by_id_including_hidden(db, article_id).await?
+        .ok_or(AppError::NotFound)?;
+    let note = normalize_note(payload.note, "操作说明")?;
+
+    let report_status = match payload.action {
+        ModerationActionKind::Hide if article.hidden_at.is_some() => {
+            return Err(AppError::Validation("文章已被隐藏".to_string()));
+        }
+        ModerationActionKind::Restore if article.hidden_at.is_none() => {
+            return Err(AppError::Validation("文章未被隐藏".to_string()));
+        }
+        action => report_status(action, note.as_deref())?,
+    };
+
+    let action = new_action(
+        auth_user,
+        ReportTarget::Article,
+        article.id,
+        article.user_id,
+        payload.action,
+        note,
+    );
+    moderation_repository::apply_action(db, action.clone(), report_status).await?;
+
+    Ok(ModerationActionResponse::from(action))
+}
+
+/// 对评论执行管理操作（仅管理员）
+///
+/// 支持 `delete`（软删除）、`warn`、`dismiss`，评论不支持隐藏和恢复
+pub async fn moderate_comment(
+    db: &DatabaseConnection,
+    comment_id: Uuid,
+    auth_user: &AuthUser,
+    payload: ModerateContentRequest,
+) -> Result<ModerationActionResponse> {
+    auth_user.require_admin()?;
+
+    let comment = comment_repository::find_by_id(db, comment_id).await?
+        .ok_or(AppError::NotFound)?;
+    let note = normalize_note(payload.note, "操作说明")?;
+
+    let report_status = match payload.action {
+        ModerationActionKind::Hide | ModerationActionKind::Restore => {
+            return Err(AppError::Validation("评论不支持隐藏和恢复，请使用删除".to_string()));
+        }
+        ModerationActionKind::Delete if comment.is_deleted => {
+            return Err(AppError::Validation("评论已被删除".to_string()));
+        }
+        action => report_status(action, note.as_deref())?,
+    };
+
+    let action = new_action(
+        auth_user,
+        ReportTarget::Comment,
+        comment.id,
+        comment.user_id,
+        payload.action,
+        note,
+    );
+    moderation_repository::apply_action(db, action.clone(), report_status).await?;
+
+    Ok(ModerationActionResponse::from(action))
+}
+
+/// 获取管理操作记录（仅管理员，带分页）
+pub async fn list_moderation_actions(
+    db: &DatabaseConnection,
+    auth_user: &AuthUser,
+    query: ModerationActionQuery,
+    pagination: &Pagination,
+) -> Result<PagedResult<Vec<ModerationActionResponse>>> {
+    auth_user.require_admin()?;
+
+    let (actions, total) = moderation_repository::find_actions_with_pagination(
+        db,
+        query.target_id,
+        query.user_id,
+        pagination.offset(),
+        pagination.limit(),
+    )
+    .await?;
+
+    Ok(PagedResult {
+        list: actions.into_iter().map(ModerationActionResponse::from).collect(),
+        pagination: PaginationInfo::new(pagination, total),
+    })
+}
+
+/// 处理内容后待处理举报的状态：驳回时为已驳回，恢复时不修改，其余为已处理
+fn report_status(action: ModerationActionKind, note: Option<&str>) -> Result<Option<ReportStatus>> {
+    match action {
+        ModerationActionKind::Warn if note.is_none() => {
+            Err(AppError::Validation("警告作者时必须填写说明".to_string()))
+        }
+        ModerationActionKind::Dismiss => Ok(Some(ReportStatus::Dismissed)),
+        ModerationActionKind::Restore => Ok(None),
+        _ => Ok(Some(ReportStatus::Resolved)),
+    }
+}
+
+/// 构造管理操作记录
+fn new_action(
+    auth_user: &AuthUser,
+    target_type: ReportTarget,
+    target_id: Uuid,
+    target_user_id: Option<Uuid>,
+    action: ModerationActionKind,
+    note: Option<String>,
+) -> moderation_action::Model {
+    moderation_action::Model {
+        id: Uuid::new_v4(),
+        target_type,
+        target_id,
+        target_user_id,
+        moderator_id: Some(auth_user.user_id),
+        action,
+        note,
+        created_at: chrono::Utc::now(),
+    }
+}
+
+/// 去掉首尾空白，空字符串视为未填写
+fn normalize_note(note: Option<String>, field: &str) -> Result<Option<String>> {
+    let Some(note) = note.map(|note| note.trim().to_string()).filter(|note| !note.is_empty()) else {
+        return Ok(None);
+    };
+    if note.chars().count() > MAX_NOTE_CHARS {
+        return Err(AppError::Validation(format!("{}不能超过 {} 个字符", field, MAX_NOTE_CHARS)));
+    }
+    Ok(Some(note))
+}
@@ -0,0 +1,494 @@
+use sea_orm::DatabaseConnection;
+use uuid::Uuid;
+use crate::config::EditorialConfig;
+use crate::entities::article::{Model, ReviewStatus};
+use crate::entities::article_review_event::ActiveModel;
+use crate::errors::{AppError, Result};
+use crate::extractors::{AuthUser, Pagination};
+use crate::models::{ArticleResponse, ArticleReviewResponse, ReviewActionRequest, ReviewEventResponse};
+use crate::repositories::{article_repository, review_repository};
+use crate::services::article_service;
+use crate::services::user_service::{PagedResult, PaginationInfo};
+
+/// 审核意见最大长度
+const MAX_COMMENT_CHARS: usize = 2000;
+
+/// 创建文章时的审核状态
+///
+/// 启用审核流程时不能直接创建公开文章；未启用时公开文章直接视为已发布
+pub fn initial_review_status(editorial: &EditorialConfig, is_public: Option<bool>) -> Result<ReviewStatus> {
+    match is_public {
+        Some(true) if editorial.enabled => Err(unreviewed_publish()),
+        Some(true) => Ok(ReviewStatus::Published),
+        _ => Ok(ReviewStatus::Draft),
+    }
+}
+
+/// 更新文章后的审核状态，状态不变时返回 `None`
+///
+/// 未启用审核流程时审核状态跟随 `is_public`。启用时：
+/// - 不能通过更新把未公开的文章改为公开，必须走审核流程
+/// - 撤回已发布的文章（`is_public=false`）后回到草稿
+/// - 修改待审核或已通过文章的标题、正文后回到草稿，需要重新提交
+/// - 已发布文章的标题、正文只有编辑可以直接修改，作者需要先撤回
+pub fn review_after_update(
+    editorial: &EditorialConfig,
+    existing: &Model,
+    is_public: Option<bool>,
+    content_changed: bool,
+    auth_user: &AuthUser,
+) -> Result<Option<ReviewStatus>> {
+    let status = existing.review_status;
+
+    if !editorial.enabled {
+        let next = match is_public {
+            Some(true) => ReviewStatus::Published,
+            Some(false) => ReviewStatus::Draft,
+            None => status,
+        };
+        return Ok((next != status).then_some(next));
+    }
+
+    let was_public = existing.is_public == Some(true);
+    if is_public == Some(true) && !was_public {
+        return Err(unreviewed_publish());
+    }
+    if is_public == Some(false) && was_public {
+        return Ok(Some(ReviewStatus::Draft));
+    }
+
+    match status {
+        ReviewStatus::Submitted | ReviewStatus::Approved if content_changed => {
+            Ok(Some(ReviewStatus::Draft))
+        }
+        ReviewStatus::Published if content_changed && !auth_user.is_editor() => {
+            Err(AppError::Validation(
+                "已发布的文章需要先撤回（is_public=false），修改后重新提交审核".to_string(),
+            ))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// 检查译文能否发布
+///
+/// 启用审核流程时，只有已通过审核或已发布的文章才能发布译文；
+/// 与原文相同，已发布文章的译文只有编辑可以直接发布或修改已发布的译文
+pub fn check_translation_publish(
+    editorial: &EditorialConfig,
+    article: &Model,
+    is_published: bool,
+    auth_user: &AuthUser,
+) -> Result<()> {
+    if !editorial.enabled || !is_published {
+        return Ok(());
+    }
+
+    match article.review_status {
+        ReviewStatus::Approved => Ok(()),
+        ReviewStatus::Published if auth_user.is_editor() => Ok(()),
+        ReviewStatus::Published => Err(AppError::Validation(
+            "已发布文章的译文需要编辑发布，请先以未发布状态保存译文".to_string(),
+        )),
+        status => Err(AppError::Validation(format!(
+            "文章当前的审核状态为 {}，审核通过后才能发布译文",
+            status_name(status)
+        ))),
+    }
+}
+
+/// 记录由更新文章引起的审核状态变更
+pub async fn record_status_change(
+    db: &DatabaseConnection,
+    article_id: Uuid,
+    actor_id: Uuid,
+    from: ReviewStatus,
+    to: ReviewStatus,
+) -> Result<()> {
+    review_repository::record(db, new_event(article_id, actor_id, from, to, None)).await?;
+    Ok(())
+}
+
+/// 提交审核（作者本人或管理员，草稿或被退回修改的文章）
+pub async fn submit_article(
+    db: &DatabaseConnection,
+    editorial: &EditorialConfig,
+    article_id: Uuid,
+    auth_user: &AuthUser,
+    payload: ReviewActionRequest,
+) -> Result<ArticleResponse> {
+    ensure_enabled(editorial)?;
+    let article = article_service::find_editable_article(db, article_id, auth_user).await?;
+    if !matches!(article.review_status, ReviewStatus::Draft | ReviewStatus::ChangesRequested) {
+        return Err(invalid_transition(article.review_status, "提交审核"));
+    }
+
+    let comment = validate_comment(payload.comment)?;
+    transition(db, &article, auth_user, ReviewStatus::Submitted, None, comment).await
+}
+
+/// 审核通过（编辑或管理员，不能审核自己的文章）
+pub async fn approve_article(
+    db: &DatabaseConnection,
+    editorial: &EditorialConfig,
+    article_id: Uuid,
+    auth_user: &AuthUser,
+    payload: ReviewActionRequest,
+) -> Result<ArticleResponse> {
+    ensure_enabled(editorial)?;
+    let article = find_reviewable_article(db, article_id, auth_user).await?;
+    if article.review_status != ReviewStatus::Submitted {
+        return Err(invalid_transition(article.review_status, "审核通过"));
+    }
+
+    let comment = validate_comment(payload.comment)?;
+    transition(db, &article, auth_user, ReviewStatus::Approved, None, comment).await
+}
+
+/// 退回修改（编辑或管理员，必须填写审核意见）
+pub async fn request_changes(
+    db: &DatabaseConnection,
+    editorial: &EditorialConfig,
+    article_id: Uuid,
+    auth_user: &AuthUser,
+    payload: ReviewActionRequest,
+) -> Result<ArticleResponse> {
+    ensure_enabled(editorial)?;
+    let article = find_reviewable_article(db, article_id, auth_user).await?;
+    if article.review_status != ReviewStatus::Submitted {
+        return Err(invalid_transition(article.review_status, "退回修改"));
+    }
+
+    let comment = validate_comment(payload.comment)?
+        .ok_or_else(|| AppError::Validation("退回修改时必须填写审核意见".to_string()))?;
+    transition(db, &article, auth_user, ReviewStatus::ChangesRequested, None, Some(comment)).await
+}
+
+/// 发布已通过审核的文章（作者本人、编辑或管理员）
+pub async fn publish_article(
+    db: &DatabaseConnection,
+    editorial: &EditorialConfig,
+    article_id: Uuid,
+    auth_user: &AuthUser,
+) -> Result<ArticleResponse> {
+    ensure_enabled(editorial)?;
+    let article = if auth_user.is_editor() {
+        find_article(db, article_id).await?
+    } else {
+        article_service::find_editable_article(db, article_id, auth_user).await?
+    };
+    if article.review_status != ReviewStatus::Approved {
+        return Err(invalid_transition(article.review_status, "发布"));
+    }
+
+    transition(db, &article, auth_user, ReviewStatus::Published, Some(true), None).await
+}
+
+/// 获取文章的审核详情（作者本人、编辑或管理员）
+pub async fn get_article_review(
+    db: &DatabaseConnection,
+    editorial: &EditorialConfig,
+    article_id: Uuid,
+    auth_user: &AuthUser,
+) -> Result<ArticleReviewResponse> {
+    ensure_enabled(editorial)?;
+    let article = if auth_user.is_editor() {
+        find_article(db, article_id).await?
+    } else {
+        article_service::find_editable_article(db, article_id, auth_user).await?
+    };
+
+    let events = review_repository::find_by_article(db, article_id)
+        .await?
+        .into_iter()
+        .map(ReviewEventResponse::from)
+        .collect();
+
+    Ok(ArticleReviewResponse {
+        author_id: article.user_id,
+        status: article.review_status,
+        article: ArticleResponse::from(article),
+        events,
+    })
+}
+
+/// 获取审核队列（编辑或管理员，带分页，缺省为待审核的文章）
+pub async fn list_review_queue(
+    db: &DatabaseConnection,
+    editorial: &EditorialConfig,
+    auth_user: &AuthUser,
+    status: Option<ReviewStatus>,
+    pagination: &Pagination,
+) -> Result<PagedResult<Vec<ArticleResponse>>> {
+    ensure_enabled(editorial)?;
+    auth_user.require_editor()?;
+
+    let (articles, total) = article_repository::find_by_review_status_with_pagination(
+        db,
+        status.unwrap_or(ReviewStatus::Submitted),
+        pagination.offset(),
+        pagination.limit(),
+    )
+    .await?;
+
+    Ok(PagedResult {
+        list: articles.into_iter().map(ArticleResponse::summary).collect(),
+        pagination: PaginationInfo::new(pagination, total),
+    })
+}
+
+/// 执行状态变更并记录
+async fn transition(
+    db: &DatabaseConnection,
+    article: &Model,
+    auth_user: &AuthUser,
+    to: ReviewStatus,
+    is_public: Option<bool>,
+    comment: Option<String>,
+) -> Result<ArticleResponse> {
+    let from = article.review_status;
+    let event = new_event(article.id, auth_user.user_id, from, to, comment);
+
+    let updated = review_repository::transition(db, article.id, from, to, is_public, event).await?;
+
+    Ok(ArticleResponse::from(updated))
+}
+
+fn new_event(
+    article_id: Uuid,
+    actor_id: Uuid,
+    from: ReviewStatus,
+    to: ReviewStatus,
+    comment: Option<String>,
+) -> ActiveModel {
+    ActiveModel {
+        id: sea_orm::Set(Uuid::new_v4()),
+        article_id: sea_orm::Set(article_id),
+        actor_id: sea_orm::Set(Some(actor_id)),
+        from_status: sea_orm::Set(from),
+        to_status: sea_orm::Set(to),
+        comment: sea_orm::Set(comment),
+        created_at: sea_orm::Set(chrono::Utc::now()),
+    }
+}
+
+/// 未启用审核流程时审核接口不存在
+fn ensure_enabled(editorial: &EditorialConfig) -> Result<()> {
+    if editorial.enabled {
+        Ok(())
+    } else {
+        Err(AppError::NotFound)
+    }
+}
+
+async fn find_article(db: &DatabaseConnection, article_id: Uuid) -> Result<Model> {
+    article_repository::find_by_id(db, article_id).await?
+        .ok_or(AppError::NotFound)
+}
+
+/// 查找当前用户可以审核的文章：需要编辑权限，且不能审核自己的文章（管理员除外）
+async fn find_reviewable_article(
+    db: &DatabaseConnection,
+    article_id: Uuid,
+    auth_user: &AuthUser,
+) -> Result<Model> {
+    auth_user.require_editor()?;
+    let article = find_article(db, article_id).await?;
+
+    if article.user_id == Some(auth_user.user_id) && !auth_user.is_admin() {
+        return Err(AppError::Forbidden);
+    }
+
+    Ok(article)
+}
+
+fn validate_comment(comment: Option<String>) -> Result<Option<String>> {
+    let Some(comment) = comment.map(|c| c.trim().to_string()).filter(|c| !c.is_empty()) else {
+        return Ok(None);
+    };
+    if comment.chars().count() > MAX_COMMENT_CHARS {
+        return Err(AppError::Validation(format!(
+            "审核意见不能超过 {} 个字符",
+            MAX_COMMENT_CHARS
+        )));
+    }
+    Ok(Some(comment))
+}
+
+fn invalid_transition(status: ReviewStatus, action: &str) -> AppError {
+    AppError::Validation(format!("文章当前的审核状态为 {}，不能{}", status_name(status), action))
+}
+
+fn unreviewed_publish() -> AppError {
+    AppError::Validation("已启用编辑审核，文章需要提交审核并通过后才能发布".to_string())
+}
+
+fn status_name(status: ReviewStatus) -> &'static str {
+    match status {
+        ReviewStatus::Draft => "草稿",
+        ReviewStatus::Submitted => "待审核",
+        ReviewStatus::ChangesRequested => "退回修改",
+        ReviewStatus::Approved => "已通过",
+        ReviewStatus::Published => "已发布",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::article::ContentFormat;
+    use crate::entities::user::UserRole;
+
+    const ENABLED: EditorialConfig = EditorialConfig { enabled: true };
+    const DISABLED: EditorialConfig = EditorialConfig { enabled: false };
+
+    fn article(review_status: ReviewStatus) -> Model {
+        Model {
+            id: Uuid::new_v4(),
+            title: "标题".to_string(),
+            content: "正文".to_string(),
+            content_format: ContentFormat::Markdown,
+            content_html: None,
+            excerpt: None,
+            word_count: None,
+            reading_minutes: None,
+            user_id: Some(Uuid::new_v4()),
+            is_public: Some(review_status == ReviewStatus::Published),
+            category_id: None,
+            series_id: None,
+            series_position: None,
+            view_count: 0,
+            created_at: None,
+            updated_at: None,
+            version: 1,
+            hidden_at: None,
+            content_hash: None,
+            language: "zh".to_string(),
+            pinned_position: None,
+            pinned_until: None,
+            pinned_at: None,
+            pinned_by: None,
+            deleted_at: None,
+            review_status,
+        }
+    }
+
+    fn user(role: UserRole) -> AuthUser {
+        AuthUser {
+            user_id: Uuid::new_v4(),
+            username: "tester".to_string(),
+            role,
+        }
+    }
+
+    #[test]
+    fn initial_status_follows_is_public_when_disabled() {
+        assert_eq!(initial_review_status(&DISABLED, Some(true)).unwrap(), ReviewStatus::Published);
+        assert_eq!(initial_review_status(&DISABLED, Some(false)).unwrap(), ReviewStatus::Draft);
+        assert_eq!(initial_review_status(&DISABLED, None).unwrap(), ReviewStatus::Draft);
+    }
+
+    #[test]
+    fn initial_status_rejects_public_articles_when_enabled() {
+        assert!(initial_review_status(&ENABLED, Some(true)).is_err());
+        assert_eq!(initial_review_status(&ENABLED, Some(false)).unwrap(), ReviewStatus::Draft);
+    }
+
+    #[test]
+    fn update_without_workflow_toggles_between_draft_and_published() {
+        let author = user(UserRole::User);
+        let draft = article(ReviewStatus::Draft);
+        let published = article(ReviewStatus::Published);
+
+        let next = |existing: &Model, is_public| {
+            review_after_update(&DISABLED, existing, is_public, true, &author).unwrap()
+        };
+        assert_eq!(next(&draft, Some(true)), Some(ReviewStatus::Published));
+        assert_eq!(next(&draft, Some(false)), None);
+        assert_eq!(next(&draft, None), None);
+        assert_eq!(next(&published, Some(false)), Some(ReviewStatus::Draft));
+        assert_eq!(next(&published, None), None);
+    }
+
+    #[test]
+    fn update_cannot_publish_unreviewed_article() {
+        let editor = user(UserRole::Editor);
+        for status in [
+            ReviewStatus::Draft,
+            ReviewStatus::Submitted,
+            ReviewStatus::ChangesRequested,
+            ReviewStatus::Approved,
+        ] {
+            let result = review_after_update(&ENABLED, &article(status), Some(true), false, &editor);
+            assert!(result.is_err(), "{:?} 不能直接公开", status);
+        }
+    }
+
+    #[test]
+    fn unpublishing_returns_to_draft() {
+        let author = user(UserRole::User);
+        let result =
+            review_after_update(&ENABLED, &article(ReviewStatus::Published), Some(false), true, &author);
+        assert_eq!(result.unwrap(), Some(ReviewStatus::Draft));
+    }
+
+    #[test]
+    fn content_change_invalidates_pending_review() {
+        let author = user(UserRole::User);
+        for status in [ReviewStatus::Submitted, ReviewStatus::Approved] {
+            let existing = article(status);
+            assert_eq!(
+                review_after_update(&ENABLED, &existing, None, true, &author).unwrap(),
+                Some(ReviewStatus::Draft)
+            );
+            assert_eq!(review_after_update(&ENABLED, &existing, None, false, &author).unwrap(), None);
+        }
+        for status in [ReviewStatus::Draft, ReviewStatus::ChangesRequested] {
+            assert_eq!(
+                review_after_update(&ENABLED, &article(status), None, true, &author).unwrap(),
+                None
+            );
+        }
+    }
+
+    #[test]
+    fn only_editors_change_published_content() {
+        let published = article(ReviewStatus::Published);
+        assert!(review_after_update(&ENABLED, &published, None, true, &user(UserRole::User)).is_err());
+        assert_eq!(
+            review_after_update(&ENABLED, &published, None, true, &user(UserRole::Editor)).unwrap(),
+            None
+        );
+        assert_eq!(
+            review_after_update(&ENABLED, &published, Some(true), false, &user(UserRole::User))
+                .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn translation_publishing_requires_review() {
+        let author = user(UserRole::User);
+        let editor = user(UserRole::Editor);
+
+        assert!(check_translation_publish(&DISABLED, &article(ReviewStatus::Draft), true, &author).is_ok());
+        assert!(check_translation_publish(&ENABLED, &article(ReviewStatus::Draft), false, &author).is_ok());
+        for status in [ReviewStatus::Draft, ReviewStatus::Submitted, ReviewStatus::ChangesRequested] {
+            assert!(check_translation_publish(&ENABLED, &article(status), true, &editor).is_err());
+        }
+        assert!(check_translation_publish(&ENABLED, &article(ReviewStatus::Approved), true, &author).is_ok());
+
+        let published = article(ReviewStatus::Published);
+        assert!(check_translation_publish(&ENABLED, &published, true, &author).is_err());
+        assert!(check_translation_publish(&ENABLED, &published, true, &editor).is_ok());
+    }
+
+    #[test]
+    fn comments_are_trimmed_and_limited() {
+        assert_eq!(validate_comment(None).unwrap(), None);
+        assert_eq!(validate_comment(Some("   ".to_string())).unwrap(), None);
+        assert_eq!(validate_comment(Some(" 请补充 ".to_string())).unwrap().as_deref(), Some("请补充"));
+        assert!(validate_comment(Some("字".repeat(MAX_COMMENT_CHARS))).is_ok());
+        assert!(validate_comment(Some("字".repeat(MAX_COMMENT_CHARS + 1))).is_err());
+    }
+}
@@ -0,0 +1,219 @@
+use sea_orm::DatabaseConnection;
+use similar::{ChangeTag, TextDiff};
+use uuid::Uuid;
+use crate::config::EditorialConfig;
+use crate::content_filter::{ContentFilterPipeline, ContentKind, FilterInput};
+use crate::entities::article::{ActiveModel as ArticleActiveModel, ContentFormat, Model as ArticleModel};
+use crate::entities::content_report::ReportTarget;
+use crate::entities::article_revision::{ActiveModel, Model};
+use crate::errors::{AppError, Result};
+use crate::extractors::{AuthUser, IfMatch};
+use crate::models::{
+    ArticleResponse, DiffLine, RevisionDiffResponse, RevisionResponse, RevisionSummary, TitleChange,
+};
+use crate::repositories::{article_repository, revision_repository};
+use crate::services::{article_service, moderation_service, review_service};
+
+/// 构建一条待追加的修订（修订号由仓储层在写入时分配）
+pub fn new_revision(
+    article_id: Uuid,
+    title: String,
+    content: String,
+    content_format: ContentFormat,
+    author_id: Uuid,
+    restored_from: Option<i32>,
+) -> ActiveModel {
+    ActiveModel {
+        id: sea_orm::Set(Uuid::new_v4()),
+        article_id: sea_orm::Set(article_id),
+        revision_number: sea_orm::NotSet,
+        title: sea_orm::Set(title),
+        content: sea_orm::Set(content),
+        content_format: sea_orm::Set(content_format),
+        author_id: sea_orm::Set(Some(author_id)),
+        restored_from: sea_orm::Set(restored_from),
+        created_at: sea_orm::Set(chrono::Utc::now()),
+    }
+}
+
+/// 以文章当前内容构建基线修订
+///
+/// 由仓储层在更新文章的事务中写入，且只在历史文章还没有任何修订时写入；
+/// 作者已被彻底删除的文章不补记
+pub fn baseline_revision(article: &ArticleModel) -> Option<ActiveModel> {
+    let author_id = article.user_id?;
+
+    let mut baseline = new_revision(
+        article.id,
+        article.title.clone(),
+        article.content.clone(),
+        article.content_format,
+        author_id,
+        None,
+    );
+    if let Some(created_at) = article.created_at {
+        baseline.created_at = sea_orm::Set(created_at);
+    }
+    Some(baseline)
+}
+
+/// 获取文章的修订列表（作者本人或管理员）
+pub async fn list_revisions(
+    db: &DatabaseConnection,
+    article_id: Uuid,
+    auth_user: &AuthUser,
+) -> Result<Vec<RevisionSummary>> {
+    article_service::find_editable_article(db, article_id, auth_user).await?;
+
+    let revisions = revision_repository::find_by_article(db, article_id).await?;
+
+    Ok(revisions.into_iter().map(RevisionSummary::from).collect())
+}
+
+/// 获取指定修订（作者本人或管理员）
+pub async fn get_revision(
+    db: &DatabaseConnection,
+    article_id: Uuid,
+    revision_number: i32,
+    auth_user: &AuthUser,
+) -> Result<RevisionResponse> {
+    article_service::find_editable_article(db, article_id, auth_user).await?;
+
+    let revision = find_revision(db, article_id, revision_number).await?;
+
+    Ok(RevisionResponse::from(revision))
+}
+
+/// 对比两个修订的差异（作者本人或管理员）
+pub async fn diff_revisions(
+    db: &DatabaseConnection,
+    article_id: Uuid,
+    from: i32,
+    to: i32,
+    auth_user: &AuthUser,
+) -> Result<RevisionDiffResponse> {
+    article_service::find_editable_article(db, article_id, auth_user).await?;
+
+    let old = find_revision(db, article_id, from).await?;
+    let new = find_revision(db, article_id, to).await?;
+
+    let diff = TextDiff::from_lines(&old.content, &new.content);
+    let changes = diff
+        .iter_all_changes()
+        .map(|change| DiffLine {
+            tag: match change.tag() {
+                ChangeTag::Equal => "equal",
+                ChangeTag::Insert => "insert",
+                ChangeTag::Delete => "delete",
+            },
+            old_line: change.old_index().map(|i| i + 1),
+            new_line: change.new_index().map(|i| i + 1),
+            content: change.to_string_lossy().trim_end_matches(['\r', '\n']).to_string(),
+        })
+        .collect();
+    let unified = diff
+        .unified_diff()
+        .context_radius(3)
+        .header(&format!("r{}", from), &format!("r{}", to))
+        .to_string();
+
+    let title = (old.title != new.title).then_some(TitleChange {
+        old: old.title,
+        new: new.title,
+    });
+
+    Ok(RevisionDiffResponse {
+        from,
+        to,
+        title,
+        changes,
+        unified,
+    })
+}
+
+/// 恢复到旧修订（作者本人或管理员）
+///
+/// 不会删除或改写任何修订，而是以旧修订的标题、正文和正文格式追加一条新修订；
+/// 与更新文章相同，需要 `If-Match` 匹配当前版本，恢复的内容同样经过内容过滤，
+/// 恢复后的审核状态按编辑审核规则变化
+pub async fn restore_revision(
+    db: &DatabaseConnection,
+    filter: &ContentFilterPipeline,
+    editorial: &EditorialConfig,
+    article_id: Uuid,
+    revision_number: i32,
+    auth_user: &AuthUser,
+    if_match: &IfMatch,
+) -> Result<ArticleResponse> {
+    let existing_article = article_service::find_editable_article(db, article_id, auth_user).await?;
+    if !if_match.matches(existing_article.version) {
+        return Err(AppError::version_conflict());
+    }
+    let revision = find_revision(db, article_id, revision_number).await?;
+    let previous_status = existing_article.review_status;
+    let review_status = review_service::review_after_update(
+        editorial,
+        &existing_article,
+        None,
+        revision.title != existing_article.title
+            || revision.content != existing_article.content
+            || revision.content_format != existing_article.content_format,
+        auth_user,
+    )?;
+    let flagged = moderation_service::screen_content(
+        db,
+        filter,
+        &FilterInput {
+            kind: ContentKind::Article,
+            id: Some(article_id),
+            author_id: existing_article.user_id.unwrap_or(auth_user.user_id),
+            title: Some(&revision.title),
+            body: &revision.content,
+        },
+    )
+    .await?;
+
+    let new_revision = new_revision(
+        article_id,
+        revision.title.clone(),
+        revision.content.clone(),
+        revision.content_format,
+        auth_user.user_id,
+        Some(revision_number),
+    );
+
+    let mut article: ArticleActiveModel = existing_article.into();
+    article.title = sea_orm::Set(revision.title);
+    article_service::set_content(&mut article, revision.content, revision.content_format);
+    if let Some(review_status) = review_status {
+        article.review_status = sea_orm::Set(review_status);
+    }
+
+    let updated_article =
+        article_repository::update_with_revision(db, article_id, article, None, new_revision)
+            .await?;
+    if let Some(review_status) = review_status {
+        review_service::record_status_change(
+            db,
+            article_id,
+            auth_user.user_id,
+            previous_status,
+            review_status,
+        )
+        .await?;
+    }
+    if let Some(reason) = flagged {
+        moderation_service::flag_content(db, ReportTarget::Article, article_id, reason).await;
+    }
+
+    Ok(ArticleResponse::from(updated_article))
+}
+
+async fn find_revision(
+    db: &DatabaseConnection,
+    article_id: Uuid,
+    revision_number: i32,
+) -> Result<Model> {
+    revision_repository::find_by_number(db, article_id, revision_number).await?
+        .ok_or(AppError::NotFound)
+}
@@ -0,0 +1,165 @@
+use sea_orm::DatabaseConnection;
+use std::collections::HashMap;
+use std::ops::Range;
+use uuid::Uuid;
+use crate::errors::{AppError, Result};
+use crate::extractors::Pagination;
+use crate::models::ArticleSearchHit;
+use crate::repositories::article_repository;
+use crate::services::user_service::{PagedResult, PaginationInfo};
+use crate::utils::escape_html;
+
+/// 关键词最小长度（字符数，与 MySQL ngram_token_size 默认值一致）
+const MIN_KEYWORD_LENGTH: usize = 2;
+/// 关键词最大长度（字符数）
+const MAX_KEYWORD_LENGTH: usize = 100;
+/// 摘要片段长度（字符数）
+const SNIPPET_LENGTH: usize = 120;
+
+/// 全文搜索文章（公开文章 + 当前用户自己的文章）
+pub async fn search_articles(
+    db: &DatabaseConnection,
+    keyword: &str,
+    pagination: &Pagination,
+    user_id: Option<Uuid>,
+) -> Result<PagedResult<Vec<ArticleSearchHit>>> {
+    let keyword = keyword.trim();
+    let length = keyword.chars().count();
+    if !(MIN_KEYWORD_LENGTH..=MAX_KEYWORD_LENGTH).contains(&length) {
+        return Err(AppError::Validation(format!(
+            "搜索关键词长度必须在 {}-{} 个字符之间",
+            MIN_KEYWORD_LENGTH, MAX_KEYWORD_LENGTH
+        )));
+    }
+
+    let limit = pagination.limit();
+    let (hits, total) =
+        article_repository::search(db, keyword, user_id, pagination.offset(), limit).await?;
+
+    let ids: Vec<Uuid> = hits.iter().map(|(id, _)| *id).collect();
+    let mut articles: HashMap<Uuid, _> = article_repository::find_by_ids(db, &ids)
+        .await?
+        .into_iter()
+        .map(|a| (a.id, a))
+        .collect();
+
+    let terms = highlight_terms(keyword);
+    let list = hits
+        .into_iter()
+        .filter_map(|(id, relevance)| {
+            let article = articles.remove(&id)?;
+            Some(ArticleSearchHit {
+                id: article.id,
+                title_highlight: highlight(&article.title, &terms, None),
+                snippet: highlight(&article.content, &terms, Some(SNIPPET_LENGTH)),
+                title: article.title,
+                user_id: article.user_id,
+                category_id: article.category_id,
+                created_at: article.created_at,
+                relevance,
+            })
+        })
+        .collect();
+
+    Ok(PagedResult {
+        list,
+        pagination: PaginationInfo::new(pagination, total),
+    })
+}
+
+/// 生成用于高亮的词项
+///
+/// 按空白拆分关键词；中日韩文本没有空格分词，额外拆出相邻两字（与 ngram 索引一致），
+/// 重叠的命中区间在高亮时会合并，因此完整短语仍会被整体标记
+fn highlight_terms(keyword: &str) -> Vec<Vec<char>> {
+    let mut terms: Vec<Vec<char>> = Vec::new();
+    for word in keyword.split_whitespace() {
+        let chars: Vec<char> = word.chars().map(fold_case).collect();
+        if chars.iter().any(|c| is_cjk(*c)) && chars.len() > 2 {
+            terms.extend(chars.windows(2).map(|w| w.to_vec()));
+        }
+        terms.push(chars);
+    }
+    terms.sort();
+    terms.dedup();
+    terms
+}
+
+/// 对文本做高亮处理，返回转义后的 HTML
+///
+/// `window` 为 `Some(n)` 时只截取首个命中位置附近的 `n` 个字符作为摘要
+fn highlight(text: &str, terms: &[Vec<char>], window: Option<usize>) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let folded: Vec<char> = chars.iter().copied().map(fold_case).collect();
+
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    for term in terms.iter().filter(|t| !t.is_empty()) {
+        let mut start = 0;
+        while start + term.len() <= folded.len() {
+            if folded[start..start + term.len()] == term[..] {
+                ranges.push(start..start + term.len());
+            }
+            start += 1;
+        }
+    }
+    ranges.sort_by_key(|r| r.start);
+    let ranges = merge_ranges(ranges);
+
+    let (from, to) = match window {
+        Some(size) if chars.len() > size => {
+            let anchor = ranges.first().map(|r| r.start).unwrap_or(0);
+            let from = anchor.saturating_sub(size / 4).min(chars.len() - size);
+            (from, from + size)
+        }
+        _ => (0, chars.len()),
+    };
+
+    let mut html = String::new();
+    if from > 0 {
+        html.push('…');
+    }
+    let mut cursor = from;
+    for range in ranges.iter().filter(|r| r.end > from && r.start < to) {
+        let start = range.start.max(from);
+        let end = range.end.min(to);
+        html.push_str(&escape_html(&chars[cursor..start].iter().collect::<String>()));
+        html.push_str("<mark>");
+        html.push_str(&escape_html(&chars[start..end].iter().collect::<String>()));
+        html.push_str("</mark>");
+        cursor = end;
+    }
+    html.push_str(&escape_html(&chars[cursor..to].iter().collect::<String>()));
+    if to < chars.len() {
+        html.push('…');
+    }
+    html
+}
+
+/// 合并重叠或相邻的区间（输入需按起点排序）
+fn merge_ranges(ranges: Vec<Range<usize>>) -> Vec<Range<usize>> {
+    let mut merged: Vec<Range<usize>> = Vec::new();
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+/// 忽略大小写比较时使用的字符（保持一一对应，便于定位原文位置）
+fn fold_case(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+/// 是否为中日韩字符
+fn is_cjk(c: char) -> bool {
+    matches!(c as u32,
+        0x3040..=0x30FF     // 平假名、片假名
+        | 0x3400..=0x4DBF   // CJK 扩展 A
+        | 0x4E00..=0x9FFF   // CJK 统一表意文字
+        | 0xAC00..=0xD7AF   // 韩文音节
+        | 0xF900..=0xFAFF   // CJK 兼容表意文字
+        | 0x20000..=0x2FA1F // CJK 扩展 B 及以后
+    )
+}
@@ -0,0 +1,272 @@
+use sea_orm::DatabaseConnection;
+use uuid::Uuid;
+use crate::entities::article::Model as ArticleModel;
+use crate::entities::series::{ActiveModel, Model};
+use crate::errors::{AppError, Result};
+use crate::extractors::AuthUser;
+use crate::models::{
+    ArticleResponse, ArticleSeriesInfo, AssignSeriesRequest, BodyFormat, CreateSeriesRequest,
+    ReorderSeriesRequest, SeriesArticleItem, SeriesDetailResponse, SeriesNeighbor, SeriesResponse,
+    UpdateSeriesRequest,
+};
+use crate::repositories::series_repository::{self, SeriesMember};
+use crate::repositories::user_repository;
+use crate::services::article_service;
+
+/// 系列标题最大长度
+const MAX_TITLE_CHARS: usize = 100;
+
+/// 获取作者的系列列表
+pub async fn list_user_series(db: &DatabaseConnection, user_id: Uuid) -> Result<Vec<SeriesResponse>> {
+    user_repository::find_by_id(db, user_id).await?
+        .ok_or(AppError::NotFound)?;
+
+    let series = series_repository::find_by_user(db, user_id).await?;
+
+    Ok(series.into_iter().map(SeriesResponse::from).collect())
+}
+
+/// 获取系列详情（只列出当前用户可读的文章）
+pub async fn get_series(
+    db: &DatabaseConnection,
+    series_id: Uuid,
+    viewer: Option<Uuid>,
+) -> Result<SeriesDetailResponse> {
+    let series = find_series(db, series_id).await?;
+    series_detail(db, series, viewer).await
+}
+
+/// 创建系列
+pub async fn create_series(
+    db: &DatabaseConnection,
+    user_id: Uuid,
+    payload: CreateSeriesRequest,
+) -> Result<SeriesResponse> {
+    let now = chrono::Utc::now();
+    let series = ActiveModel {
+        id: sea_orm::Set(Uuid::new_v4()),
+        user_id: sea_orm::Set(user_id),
+        title: sea_orm::Set(validate_title(&payload.title)?),
+        description: sea_orm::Set(payload.description),
+        created_at: sea_orm::Set(now),
+        updated_at: sea_orm::Set(now),
+    };
+
+    let created = series_repository::create(db, series).await?;
+
+    Ok(SeriesResponse::from(created))
+}
+
+/// 更新系列（作者本人或管理员）
+pub async fn update_series(
+    db: &DatabaseConnection,
+    series_id: Uuid,
+    auth_user: &AuthUser,
+    payload: UpdateSeriesRequest,
+) -> Result<SeriesResponse> {
+    let existing = find_editable_series(db, series_id, auth_user).await?;
+
+    let mut series: ActiveModel = existing.into();
+    if let Some(title) = payload.title {
+        series.title = sea_orm::Set(validate_title(&title)?);
+    }
+    if let Some(description) = payload.description {
+        series.description = sea_orm::Set(description);
+    }
+    series.updated_at = sea_orm::Set(chrono::Utc::now());
+
+    let updated = series_repository::update(db, series_id, series).await?;
+
+    Ok(SeriesResponse::from(updated))
+}
+
+/// 删除系列（作者本人或管理员），成员文章移出系列但不删除
+pub async fn delete_series(db: &DatabaseConnection, series_id: Uuid, auth_user: &AuthUser) -> Result<()> {
+    find_editable_series(db, series_id, auth_user).await?;
+    series_repository::delete(db, series_id).await
+}
+
+/// 调整系列中文章的顺序（作者本人或管理员）
+///
+/// `article_ids` 必须恰好是系列中的全部文章
+pub async fn reorder_series(
+    db: &DatabaseConnection,
+    series_id: Uuid,
+    auth_user: &AuthUser,
+    payload: ReorderSeriesRequest,
+) -> Result<SeriesDetailResponse> {
+    let series = find_editable_series(db, series_id, auth_user).await?;
+
+    let mut current: Vec<Uuid> = series_repository::find_members(db, series_id)
+        .await?
+        .into_iter()
+        .map(|member| member.id)
+        .collect();
+    let mut requested = payload.article_ids.clone();
+    current.sort();
+    requested.sort();
+    requested.dedup();
+    if current != requested || requested.len() != payload.article_ids.len() {
+        return Err(AppError::Validation(
+            "article_ids 必须包含系列中的全部文章且不能重复".to_string(),
+        ));
+    }
+
+    series_repository::set_members(db, series_id, &payload.article_ids, &[]).await?;
+
+    series_detail(db, series, Some(auth_user.user_id)).await
+}
+
+/// 设置文章所属系列（文章作者本人或管理员）
+///
+/// 文章只能加入作者本人的系列；加入新系列时自动移出原系列，原系列的顺序保持连续
+pub async fn assign_article_series(
+    db: &DatabaseConnection,
+    article_id: Uuid,
+    auth_user: &AuthUser,
+    payload: AssignSeriesRequest,
+) -> Result<ArticleResponse> {
+    let article = article_service::find_editable_article(db, article_id, auth_user).await?;
+    let old_series = article.series_id;
+
+    if let Some(series_id) = payload.series_id {
+        let series = find_editable_series(db, series_id, auth_user).await?;
+        if article.user_id != Some(series.user_id) {
+            return Err(AppError::Validation("只能把文章加入作者本人的系列".to_string()));
+        }
+
+        let mut members: Vec<Uuid> = series_repository::find_members(db, series_id)
+            .await?
+            .into_iter()
+            .map(|member| member.id)
+            .filter(|id| *id != article_id)
+            .collect();
+        let index = match payload.position {
+            Some(0) => return Err(AppError::Validation("position 必须大于 0".to_string())),
+            Some(position) => (position as usize - 1).min(members.len()),
+            None => members.len(),
+        };
+        members.insert(index, article_id);
+
+        series_repository::set_members(db, series_id, &members, &[]).await?;
+    }
+
+    if let Some(old_id) = old_series.filter(|old_id| Some(*old_id) != payload.series_id) {
+        let members: Vec<Uuid> = series_repository::find_members(db, old_id)
+            .await?
+            .into_iter()
+            .map(|member| member.id)
+            .filter(|id| *id != article_id)
+            .collect();
+        series_repository::set_members(db, old_id, &members, &[article_id]).await?;
+    }
+
+    article_service::get_article_by_id(
+        db,
+        article_id,
+        BodyFormat::Raw,
+        &Default::default(),
+        Some(auth_user.user_id),
+    )
+    .await
+}
+
+/// 计算文章所在系列的信息，上一篇、下一篇跳过 `viewer` 不可读的文章
+pub async fn series_info(
+    db: &DatabaseConnection,
+    article: &ArticleModel,
+    viewer: Option<Uuid>,
+) -> Result<Option<ArticleSeriesInfo>> {
+    let Some(series_id) = article.series_id else {
+        return Ok(None);
+    };
+    let Some(series) = series_repository::find_by_id(db, series_id).await? else {
+        return Ok(None);
+    };
+
+    let members: Vec<SeriesMember> = series_repository::find_members(db, series_id)
+        .await?
+        .into_iter()
+        .filter(|member| member.id == article.id || is_member_readable(member, viewer))
+        .collect();
+    let Some(index) = members.iter().position(|member| member.id == article.id) else {
+        return Ok(None);
+    };
+
+    let neighbor = |member: &SeriesMember| SeriesNeighbor {
+        id: member.id,
+        title: member.title.clone(),
+        url: format!("/api/articles/{}", member.id),
+    };
+
+    Ok(Some(ArticleSeriesInfo {
+        id: series.id,
+        title: series.title,
+        position: article.series_position.unwrap_or(index as i32 + 1),
+        total: members.len(),
+        prev: index.checked_sub(1).map(|i| neighbor(&members[i])),
+        next: members.get(index + 1).map(neighbor),
+    }))
+}
+
+/// 查找系列，不存在时返回 404
+async fn find_series(db: &DatabaseConnection, series_id: Uuid) -> Result<Model> {
+    series_repository::find_by_id(db, series_id).await?
+        .ok_or(AppError::NotFound)
+}
+
+/// 查找当前用户可编辑的系列（作者本人或管理员）
+async fn find_editable_series(
+    db: &DatabaseConnection,
+    series_id: Uuid,
+    auth_user: &AuthUser,
+) -> Result<Model> {
+    let series = find_series(db, series_id).await?;
+
+    if series.user_id != auth_user.user_id && !auth_user.is_admin() {
+        return Err(AppError::Forbidden);
+    }
+
+    Ok(series)
+}
+
+/// 组装系列详情
+async fn series_detail(
+    db: &DatabaseConnection,
+    series: Model,
+    viewer: Option<Uuid>,
+) -> Result<SeriesDetailResponse> {
+    let articles = series_repository::find_members(db, series.id)
+        .await?
+        .into_iter()
+        .filter(|member| is_member_readable(member, viewer))
+        .enumerate()
+        .map(|(index, member)| SeriesArticleItem {
+            id: member.id,
+            title: member.title,
+            position: member.series_position.unwrap_or(index as i32 + 1),
+        })
+        .collect();
+
+    Ok(SeriesDetailResponse {
+        series: SeriesResponse::from(series),
+        articles,
+    })
+}
+
+/// 被管理员隐藏的文章对所有人不可见
+fn is_member_readable(member: &SeriesMember, viewer: Option<Uuid>) -> bool {
+    member.hidden_at.is_none()
+        && article_service::is_readable(member.user_id, member.is_public, viewer)
+}
+
+fn validate_title(title: &str) -> Result<String> {
+    let title = title.trim();
+    if title.is_empty() {
+        return Err(AppError::Validation("系列标题不能为空".to_string()));
+    }
+    if title.chars().count() > MAX_TITLE_CHARS {
+        return Err(AppError::Validation(format!("系列标题不能超过 {} 个字符", MAX_TITLE_CHARS)));
+    }
+    Ok(title.to_string())
+}
@@ -0,0 +1,128 @@
+use sea_orm::DatabaseConnection;
+use uuid::Uuid;
+use crate::config::Config;
+use crate::entities::article_share_link::ActiveModel;
+use crate::errors::{AppError, Result};
+use crate::extractors::{AuthUser, PreferredLanguages};
+use crate::jwt::{verify_share_token, ShareClaims};
+use crate::models::{ArticleResponse, BodyFormat, CreateShareLinkRequest, ShareLinkResponse};
+use crate::repositories::{article_repository, share_link_repository};
+use crate::services::article_service;
+
+/// 备注的最大长度
+const MAX_LABEL_CHARS: usize = 100;
+
+/// 创建分享链接（作者本人或管理员）
+///
+/// 令牌只在创建时返回一次；有效期缺省和上限由配置决定
+pub async fn create_share_link(
+    db: &DatabaseConnection,
+    config: &Config,
+    article_id: Uuid,
+    auth_user: &AuthUser,
+    payload: CreateShareLinkRequest,
+) -> Result<ShareLinkResponse> {
+    article_service::find_editable_article(db, article_id, auth_user).await?;
+
+    let max_hours = config.share.max_ttl.as_secs() / 3600;
+    let ttl_hours = payload
+        .expires_in_hours
+        .unwrap_or(config.share.default_ttl.as_secs() / 3600);
+    if ttl_hours == 0 || ttl_hours > max_hours {
+        return Err(AppError::Validation(format!(
+            "有效期必须在 1-{} 小时之间",
+            max_hours
+        )));
+    }
+
+    let label = payload
+        .label
+        .map(|label| label.trim().to_string())
+        .filter(|label| !label.is_empty());
+    if label.as_ref().is_some_and(|label| label.chars().count() > MAX_LABEL_CHARS) {
+        return Err(AppError::Validation(format!("备注不能超过 {} 个字符", MAX_LABEL_CHARS)));
+    }
+
+    let now = chrono::Utc::now();
+    let link = ActiveModel {
+        id: sea_orm::Set(Uuid::new_v4()),
+        article_id: sea_orm::Set(article_id),
+        created_by: sea_orm::Set(auth_user.user_id),
+        label: sea_orm::Set(label),
+        expires_at: sea_orm::Set(now + chrono::Duration::hours(ttl_hours as i64)),
+        revoked_at: sea_orm::Set(None),
+        created_at: sea_orm::Set(now),
+        last_used_at: sea_orm::Set(None),
+        use_count: sea_orm::Set(0),
+    };
+    let link = share_link_repository::create(db, link).await?;
+
+    let token = ShareClaims::new(link.id, article_id, link.expires_at).to_token(&config.jwt.secret)?;
+    let url = format!("{}/api/articles/{}?share={}", config.server.public_url, article_id, token);
+
+    let mut response = ShareLinkResponse::from(link);
+    response.token = Some(token);
+    response.url = Some(url);
+    Ok(response)
+}
+
+/// 获取文章的分享链接列表（作者本人或管理员，不包含令牌）
+pub async fn list_share_links(
+    db: &DatabaseConnection,
+    article_id: Uuid,
+    auth_user: &AuthUser,
+) -> Result<Vec<ShareLinkResponse>> {
+    article_service::find_editable_article(db, article_id, auth_user).await?;
+
+    Ok(share_link_repository::find_by_article(db, article_id)
+        .await?
+        .into_iter()
+        .map(ShareLinkResponse::from)
+        .collect())
+}
+
+/// 撤销分享链接（作者本人或管理员，幂等）
+pub async fn revoke_share_link(
+    db: &DatabaseConnection,
+    article_id: Uuid,
+    share_id: Uuid,
+    auth_user: &AuthUser,
+) -> Result<()> {
+    article_service::find_editable_article(db, article_id, auth_user).await?;
+
+    share_link_repository::find_by_id(db, share_id)
+        .await?
+        .filter(|link| link.article_id == article_id)
+        .ok_or(AppError::NotFound)?;
+
+    share_link_repository::revoke(db, share_id, chrono::Utc::now()).await
+}
+
+/// 通过分享令牌读取文章（不检查公开状态，令牌只对签发时的文章有效）
+///
+/// 令牌签名无效、属于其他文章、已过期或已撤销时返回 403；成功时记录一次使用
+pub async fn get_shared_article(
+    db: &DatabaseConnection,
+    config: &Config,
+    article_id: Uuid,
+    token: &str,
+    format: BodyFormat,
+    languages: &PreferredLanguages,
+) -> Result<ArticleResponse> {
+    let claims = verify_share_token(token, &config.jwt.secret).map_err(|_| AppError::Forbidden)?;
+    if claims.aid != article_id {
+        return Err(AppError::Forbidden);
+    }
+
+    let now = chrono::Utc::now();
+    let link = share_link_repository::find_by_id(db, claims.sid)
+        .await?
+        .filter(|link| link.article_id == article_id && link.is_active_at(now))
+        .ok_or(AppError::Forbidden)?;
+
+    let article = article_repository::find_by_id(db, article_id).await?
+        .ok_or(AppError::NotFound)?;
+    share_link_repository::record_use(db, link.id, now).await?;
+
+    article_service::article_detail(db, article, format, languages, None).await
+}
@@ -0,0 +1,122 @@
+use chrono::{DateTime, Utc};
+use sea_orm::DatabaseConnection;
+use std::collections::HashMap;
+use uuid::Uuid;
+use crate::errors::Result;
+use crate::repositories::{article_repository, user_repository};
+use crate::utils::escape_html;
+
+/// 单个站点地图文件最多包含的 URL 数（sitemaps.org 协议限制）
+pub const MAX_URLS_PER_SITEMAP: usize = 50_000;
+
+/// 生成好的站点地图
+///
+/// URL 数不超过 [`MAX_URLS_PER_SITEMAP`] 时只有一个文件，直接作为 `/sitemap.xml`；
+/// 超过时拆分为多个文件，`/sitemap.xml` 为指向 `/sitemaps/sitemap-N.xml` 的索引
+#[derive(Debug)]
+pub struct Sitemap {
+    pub generated_at: DateTime<Utc>,
+    /// 站点地图索引，只有一个文件时为空
+    pub index: Option<String>,
+    /// 各个站点地图文件，编号从 1 开始
+    pub pages: Vec<String>,
+}
+
+impl Sitemap {
+    /// `/sitemap.xml` 的内容
+    pub fn root(&self) -> &str {
+        self.index.as_deref().unwrap_or(&self.pages[0])
+    }
+
+    /// 第 `number` 个站点地图文件（从 1 开始）
+    pub fn page(&self, number: usize) -> Option<&str> {
+        number
+            .checked_sub(1)
+            .and_then(|i| self.pages.get(i))
+            .map(String::as_str)
+    }
+}
+
+struct SitemapUrl {
+    loc: String,
+    lastmod: Option<DateTime<Utc>>,
+}
+
+/// 从文章表和用户表生成站点地图
+///
+/// 包含所有公开文章，以及发表过公开文章的作者主页；
+/// 文章的 `lastmod` 取修改时间（缺省为创建时间），作者的 `lastmod` 取资料修改时间与其最新公开文章中较晚者
+pub async fn generate(db: &DatabaseConnection, base_url: &str) -> Result<Sitemap> {
+    let articles = article_repository::find_public_timestamps(db).await?;
+    let authors = user_repository::find_public_authors(db).await?;
+
+    let mut latest_by_author: HashMap<Uuid, DateTime<Utc>> = HashMap::new();
+    let mut urls: Vec<SitemapUrl> = Vec::with_capacity(articles.len() + authors.len());
+
+    for (id, user_id, created_at, updated_at) in articles {
+        let lastmod = updated_at.or(created_at);
+        if let (Some(user_id), Some(lastmod)) = (user_id, lastmod) {
+            let latest = latest_by_author.entry(user_id).or_insert(lastmod);
+            *latest = (*latest).max(lastmod);
+        }
+        urls.push(SitemapUrl {
+            loc: format!("{}/api/articles/{}", base_url, id),
+            lastmod,
+        });
+    }
+    for (id, updated_at) in authors {
+        let lastmod = latest_by_author
+            .get(&id)
+            .map_or(updated_at, |latest| (*latest).max(updated_at));
+        urls.push(SitemapUrl {
+            loc: format!("{}/api/users/{}", base_url, id),
+            lastmod: Some(lastmod),
+        });
+    }
+
+    let generated_at = Utc::now();
+    if urls.len() <= MAX_URLS_PER_SITEMAP {
+        return Ok(Sitemap {
+            generated_at,
+            index: None,
+            pages: vec![render_urlset(&urls)],
+        });
+    }
+
+    let chunks: Vec<&[SitemapUrl]> = urls.chunks(MAX_URLS_PER_SITEMAP).collect();
+    let mut index = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    index.push_str("<sitemapindex xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n");
+    for (i, chunk) in chunks.iter().enumerate() {
+        let loc = format!("{}/sitemaps/sitemap-{}.xml", base_url, i + 1);
+        let lastmod = chunk.iter().filter_map(|url| url.lastmod).max();
+        push_entry(&mut index, "sitemap", &loc, lastmod);
+    }
+    index.push_str("</sitemapindex>\n");
+
+    Ok(Sitemap {
+        generated_at,
+        index: Some(index),
+        pages: chunks.into_iter().map(render_urlset).collect(),
+    })
+}
+
+fn render_urlset(urls: &[SitemapUrl]) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str("<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n");
+    for url in urls {
+        push_entry(&mut xml, "url", &url.loc, url.lastmod);
+    }
+    xml.push_str("</urlset>\n");
+    xml
+}
+
+fn push_entry(xml: &mut String, tag: &str, loc: &str, lastmod: Option<DateTime<Utc>>) {
+    xml.push_str(&format!("<{}><loc>{}</loc>", tag, escape_html(loc)));
+    if let Some(lastmod) = lastmod {
+        xml.push_str(&format!(
+            "<lastmod>{}</lastmod>",
+            lastmod.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+        ));
+    }
+    xml.push_str(&format!("</{}>\n", tag));
+}
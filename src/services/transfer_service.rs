@@ -0,0 +1,565 @@
+use axum::body::Bytes;
+use futures_util::stream::{self, Stream};
+use sea_orm::DatabaseConnection;
+use serde::Serialize;
+use std::collections::HashMap;
+use uuid::Uuid;
+use crate::config::{EditorialConfig, ImportConfig};
+use crate::content_filter::{ContentFilterPipeline, ContentKind, Decision, FilterInput};
+use crate::entities::article::{ActiveModel, ContentFormat, ReviewStatus};
+use crate::entities::content_report::ReportTarget;
+use crate::errors::{AppError, Result};
+use crate::extractors::AuthUser;
+use crate::models::{ArticleRecord, ImportReport, ImportRowError, TransferFormat};
+use crate::repositories::{article_repository, category_repository};
+use crate::services::{article_service, moderation_service, revision_service};
+
+/// 标题最大长度（与 `articles.title` 列一致）
+const MAX_TITLE_CHARS: usize = 100;
+/// 正文的最大字节数（MySQL `TEXT` 列的上限）
+const MAX_CONTENT_BYTES: usize = 65_535;
+/// 导出时每批读取的文章数
+const EXPORT_BATCH_SIZE: u64 = 200;
+/// CSV 表头（与 `ArticleRecord` 字段顺序一致）
+const CSV_HEADERS: [&str; 9] = [
+    "id",
+    "title",
+    "content",
+    "content_format",
+    "is_public",
+    "category_id",
+    "author_id",
+    "created_at",
+    "updated_at",
+];
+
+/// 待导入的文件
+#[derive(Debug)]
+pub struct ImportFile {
+    pub filename: Option<String>,
+    pub format: TransferFormat,
+    pub content: String,
+}
+
+/// 解析出的一行：(行号, 文章或错误信息)
+type ParsedRow = (usize, std::result::Result<ArticleRecord, String>);
+
+/// 批量导入文章（归属当前用户）
+///
+/// 先解析并校验全部文件（包括内容过滤），任一行有错误时不写入任何数据；
+/// 全部通过且不是试运行时在同一事务中写入，需要审核的文章写入后放入待处理队列。
+/// 启用编辑审核流程时不能导入公开文章
+pub async fn import_articles(
+    db: &DatabaseConnection,
+    config: &ImportConfig,
+    filter: &ContentFilterPipeline,
+    editorial: &EditorialConfig,
+    auth_user: &AuthUser,
+    files: Vec<ImportFile>,
+    dry_run: bool,
+) -> Result<ImportReport> {
+    let mut rows = Vec::new();
+    for file in &files {
+        for (line, parsed) in parse_records(file.format, &file.content) {
+            rows.push((file.filename.clone(), line, parsed));
+        }
+    }
+    if rows.len() > config.max_rows {
+        return Err(AppError::Validation(format!(
+            "单次最多导入 {} 篇文章，本次包含 {} 篇",
+            config.max_rows,
+            rows.len()
+        )));
+    }
+
+    let total = rows.len();
+    let mut categories: HashMap<Uuid, bool> = HashMap::new();
+    let mut records = Vec::with_capacity(total);
+    let mut flags = Vec::with_capacity(total);
+    let mut errors = Vec::new();
+    for (file, line, parsed) in rows {
+        let checked = match parsed {
+            Ok(record) => validate_record(db, &mut categories, record).await?,
+            Err(message) => Err(message),
+        };
+        let checked = checked.and_then(|record| {
+            if editorial.enabled && record.is_public == Some(true) {
+                Err("已启用编辑审核，不能导入公开文章，请导入后提交审核".to_string())
+            } else {
+                Ok(record)
+            }
+        });
+        let screened = match checked {
+            Ok(record) => screen_record(db, filter, auth_user, record).await?,
+            Err(message) => Err(message),
+        };
+        match screened {
+            Ok((record, flag)) => {
+                records.push(record);
+                flags.push(flag);
+            }
+            Err(message) => errors.push(ImportRowError { file, line, message }),
+        }
+    }
+
+    let valid = records.len();
+    let flagged = flags.iter().filter(|flag| flag.is_some()).count();
+    if dry_run || !errors.is_empty() {
+        return Ok(ImportReport {
+            dry_run,
+            total,
+            valid,
+            flagged,
+            imported: 0,
+            ids: Vec::new(),
+            errors,
+        });
+    }
+
+    let now = chrono::Utc::now();
+    let articles = records
+        .into_iter()
+        .map(|record| {
+            let article_id = Uuid::new_v4();
+            let created_at = record.created_at.unwrap_or(now);
+            let content_format = record.content_format.unwrap_or_default();
+            let revision = revision_service::new_revision(
+                article_id,
+                record.title.clone(),
+                record.content.clone(),
+                content_format,
+                auth_user.user_id,
+                None,
+            );
+            let mut article = ActiveModel {
+                id: sea_orm::Set(article_id),
+                title: sea_orm::Set(record.title),
+                user_id: sea_orm::Set(Some(auth_user.user_id)),
+                is_public: sea_orm::Set(record.is_public),
+                review_status: sea_orm::Set(if record.is_public == Some(true) {
+                    ReviewStatus::Published
+                } else {
+                    ReviewStatus::Draft
+                }),
+                category_id: sea_orm::Set(record.category_id),
+                created_at: sea_orm::Set(Some(created_at)),
+                updated_at: sea_orm::Set(Some(record.updated_at.unwrap_or(created_at))),
+                version: sea_orm::Set(1),
+                ..Default::default()
+            };
+            article_service::set_content(&mut article, record.content, content_format);
+            (article, revision)
+        })
+        .collect();
+
+    let ids = article_repository::create_many_with_revisions(db, articles).await?;
+    for (id, flag) in ids.iter().zip(flags) {
+        if let Some(reason) = flag {
+            moderation_service::flag_content(db, ReportTarget::Article, *id, reason).await;
+        }
+    }
+
+    Ok(ImportReport {
+        dry_run,
+        total,
+        valid,
+        flagged,
+        imported: ids.len(),
+        ids,
+        errors,
+    })
+}
+
+/// 对单篇文章运行内容过滤，返回文章和需要审核的原因；内层错误为拒绝原因
+async fn screen_record(
+    db: &DatabaseConnection,
+    filter: &ContentFilterPipeline,
+    auth_user: &AuthUser,
+    record: ArticleRecord,
+) -> Result<std::result::Result<(ArticleRecord, Option<String>), String>> {
+    let input = FilterInput {
+        kind: ContentKind::Article,
+        id: None,
+        author_id: auth_user.user_id,
+        title: Some(&record.title),
+        body: &record.content,
+    };
+    let flag = match filter.check(db, &input).await? {
+        Decision::Accept => None,
+        Decision::Flag(reasons) => Some(reasons.join("；")),
+        Decision::Reject(reason) => return Ok(Err(format!("内容未通过审核：{}", reason))),
+    };
+    Ok(Ok((record, flag)))
+}
+
+/// 校验单篇文章，外层错误为数据库错误，内层错误为该行的校验失败原因
+async fn validate_record(
+    db: &DatabaseConnection,
+    categories: &mut HashMap<Uuid, bool>,
+    mut record: ArticleRecord,
+) -> Result<std::result::Result<ArticleRecord, String>> {
+    record.title = record.title.trim().to_string();
+    if record.title.is_empty() {
+        return Ok(Err("标题不能为空".to_string()));
+    }
+    if record.title.chars().count() > MAX_TITLE_CHARS {
+        return Ok(Err(format!("标题不能超过 {} 个字符", MAX_TITLE_CHARS)));
+    }
+    if record.content.trim().is_empty() {
+        return Ok(Err("正文不能为空".to_string()));
+    }
+    if record.content.len() > MAX_CONTENT_BYTES {
+        return Ok(Err(format!("正文不能超过 {} 字节", MAX_CONTENT_BYTES)));
+    }
+    if let Some(category_id) = record.category_id {
+        let exists = match categories.get(&category_id) {
+            Some(exists) => *exists,
+            None => {
+                let exists = category_repository::find_by_id(db, category_id).await?.is_some();
+                categories.insert(category_id, exists);
+                exists
+            }
+        };
+        if !exists {
+            return Ok(Err(format!("分类不存在: {}", category_id)));
+        }
+    }
+    Ok(Ok(record))
+}
+
+/// 按格式解析文件内容
+pub fn parse_records(format: TransferFormat, content: &str) -> Vec<ParsedRow> {
+    match format {
+        TransferFormat::Jsonl => parse_jsonl(content),
+        TransferFormat::Csv => parse_csv(content),
+        TransferFormat::Markdown => parse_markdown(content),
+    }
+}
+
+/// JSON Lines：每个非空行是一篇文章
+fn parse_jsonl(content: &str) -> Vec<ParsedRow> {
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            let parsed = serde_json::from_str::<ArticleRecord>(line).map_err(|e| e.to_string());
+            (index + 1, parsed)
+        })
+        .collect()
+}
+
+/// CSV：首行为表头，字段名与导出一致，只有 `title` 和 `content` 必填
+fn parse_csv(content: &str) -> Vec<ParsedRow> {
+    let mut reader = csv::ReaderBuilder::new()
+        .flexible(true)
+        .from_reader(content.as_bytes());
+    let headers = match reader.headers() {
+        Ok(headers) => headers.clone(),
+        Err(e) => return vec![(1, Err(format!("表头无效: {}", e)))],
+    };
+
+    let mut rows = Vec::new();
+    let mut record = csv::StringRecord::new();
+    loop {
+        match reader.read_record(&mut record) {
+            Ok(false) => break,
+            Ok(true) => {
+                let line = record.position().map_or(0, |p| p.line() as usize);
+                let parsed = record
+                    .deserialize::<ArticleRecord>(Some(&headers))
+                    .map_err(|e| e.to_string());
+                rows.push((line, parsed));
+            }
+            Err(e) => {
+                let line = e.position().map_or(0, |p| p.line() as usize);
+                rows.push((line, Err(e.to_string())));
+                if !matches!(e.kind(), csv::ErrorKind::Utf8 { .. }) {
+                    break;
+                }
+            }
+        }
+    }
+    rows
+}
+
+/// Markdown：一个文件可以包含多篇文章，每篇以 `---` 包围的 YAML front matter 开头，
+/// 之后直到下一个 front matter 为止都是正文
+///
+/// 正文中的 `---` 只有在前面是空行、且到下一个 `---` 之间能解析为带 `title` 的 YAML 时
+/// 才会被当作下一篇文章的开始
+fn parse_markdown(content: &str) -> Vec<ParsedRow> {
+    let lines: Vec<&str> = content.lines().collect();
+    let is_fence = |i: usize| lines[i].trim_end() == "---";
+    let closing_fence = |open: usize| (open + 1..lines.len()).find(|&i| is_fence(i));
+    let front_matter = |open: usize, close: usize| {
+        serde_yaml::from_str::<ArticleRecord>(&lines[open + 1..close].join("\n"))
+    };
+
+    let Some(first) = lines.iter().position(|line| !line.trim().is_empty()) else {
+        return Vec::new();
+    };
+
+    // (front matter 起始行, 正文起始行, 解析结果)
+    let mut documents: Vec<(usize, usize, std::result::Result<ArticleRecord, String>)> =
+        Vec::new();
+    let mut next = first;
+    match closing_fence(first).filter(|_| is_fence(first)) {
+        Some(close) => {
+            documents.push((first, close + 1, front_matter(first, close).map_err(|e| e.to_string())));
+            next = close + 1;
+        }
+        None => documents.push((first, first, Err("缺少 YAML front matter".to_string()))),
+    }
+
+    // 没有闭合的 `---` 不是 front matter，按正文处理；从第二行开始扫描，保证 `i - 1` 不越界
+    let mut body_ends = Vec::new();
+    let mut i = next.max(first + 1);
+    while i < lines.len() {
+        if is_fence(i) && lines[i - 1].trim().is_empty() {
+            if let Some(close) = closing_fence(i) {
+                if let Ok(record) = front_matter(i, close) {
+                    body_ends.push(i);
+                    documents.push((i, close + 1, Ok(record)));
+                    i = close + 1;
+                    continue;
+                }
+            }
+        }
+        i += 1;
+    }
+    body_ends.push(lines.len());
+
+    documents
+        .into_iter()
+        .zip(body_ends)
+        .map(|((open, body_start, parsed), body_end)| {
+            let parsed = parsed.map(|mut record| {
+                let body = lines[body_start.min(body_end)..body_end].join("\n");
+                record.content = body.trim_matches(['\n', '\r']).to_string();
+                record
+            });
+            (open + 1, parsed)
+        })
+        .collect()
+}
+
+/// Markdown 导出时的 front matter（不含正文）
+#[derive(Serialize)]
+struct FrontMatter<'a> {
+    id: Option<Uuid>,
+    title: &'a str,
+    content_format: Option<ContentFormat>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_public: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    category_id: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    author_id: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// 流式导出文章：管理员导出全部文章，其他用户导出自己的文章
+///
+/// 按 ID 分批读取，每批渲染后立即输出，内存占用与文章总数无关
+pub fn export_articles(
+    db: DatabaseConnection,
+    auth_user: &AuthUser,
+    format: TransferFormat,
+) -> impl Stream<Item = Result<Bytes>> + Send + 'static {
+    let author_id = (!auth_user.is_admin()).then_some(auth_user.user_id);
+
+    // (上一批最后一篇文章的 ID, 是否为第一批, 是否已结束)
+    stream::unfold((None, true, false), move |(after, first, done)| {
+        let db = db.clone();
+        async move {
+            if done {
+                return None;
+            }
+            let batch =
+                match article_repository::find_export_batch(&db, author_id, after, EXPORT_BATCH_SIZE)
+                    .await
+                {
+                    Ok(batch) => batch,
+                    Err(e) => {
+                        tracing::error!("导出文章失败: {}", e);
+                        return Some((Err(e), (after, first, true)));
+                    }
+                };
+            if batch.is_empty() && !first {
+                return None;
+            }
+
+            let last = batch.last().map(|article| article.id).or(after);
+            let finished = (batch.len() as u64) < EXPORT_BATCH_SIZE;
+            let records: Vec<ArticleRecord> = batch.into_iter().map(ArticleRecord::from).collect();
+            let chunk = render_records(format, &records, first).map(Bytes::from);
+            let failed = chunk.is_err();
+            Some((chunk, (last, false, finished || failed)))
+        }
+    })
+}
+
+/// 将一批文章渲染为指定格式，`first` 表示是否为导出的第一批（CSV 需要输出表头）
+pub fn render_records(format: TransferFormat, records: &[ArticleRecord], first: bool) -> Result<Vec<u8>> {
+    let mut out = Vec::new();
+    match format {
+        TransferFormat::Jsonl => {
+            for record in records {
+                serde_json::to_writer(&mut out, record)
+                    .map_err(|e| AppError::Internal(e.into()))?;
+                out.push(b'\n');
+            }
+        }
+        TransferFormat::Csv => {
+            let mut writer = csv::WriterBuilder::new()
+                .has_headers(false)
+                .from_writer(&mut out);
+            let csv_error = |e: csv::Error| AppError::Internal(e.into());
+            if first {
+                writer.write_record(CSV_HEADERS).map_err(csv_error)?;
+            }
+            for record in records {
+                writer.serialize(record).map_err(csv_error)?;
+            }
+            writer.flush().map_err(|e| AppError::Internal(e.into()))?;
+        }
+        TransferFormat::Markdown => {
+            for (index, record) in records.iter().enumerate() {
+                let front_matter = serde_yaml::to_string(&FrontMatter {
+                    id: record.id,
+                    title: &record.title,
+                    content_format: record.content_format,
+                    is_public: record.is_public,
+                    category_id: record.category_id,
+                    author_id: record.author_id,
+                    created_at: record.created_at,
+                    updated_at: record.updated_at,
+                })
+                .map_err(|e| AppError::Internal(e.into()))?;
+                // 文章之间空一行，导入时据此识别下一篇的 front matter
+                if !(first && index == 0) {
+                    out.push(b'\n');
+                }
+                out.extend_from_slice(b"---\n");
+                out.extend_from_slice(front_matter.as_bytes());
+                out.extend_from_slice(b"---\n\n");
+                out.extend_from_slice(record.content.as_bytes());
+                out.push(b'\n');
+            }
+        }
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn markdown_empty_file_has_no_rows() {
+        assert!(parse_markdown("").is_empty());
+        assert!(parse_markdown("\n  \n").is_empty());
+    }
+
+    #[test]
+    fn markdown_without_front_matter_is_an_error_row() {
+        let rows = parse_markdown("hello\nworld");
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].0, 1);
+        assert!(rows[0].1.is_err());
+    }
+
+    #[test]
+    fn markdown_unclosed_front_matter_does_not_panic() {
+        let rows = parse_markdown("---\ntitle: x");
+        assert_eq!(rows.len(), 1);
+        assert!(rows[0].1.is_err());
+
+        let rows = parse_markdown("\n---\ntitle: x\n\nbody");
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].0, 2);
+        assert!(rows[0].1.is_err());
+    }
+
+    #[test]
+    fn markdown_unclosed_fence_in_body_stays_in_body() {
+        let rows = parse_markdown("---\ntitle: a\n---\nfirst\n\n---\nnot yaml");
+        assert_eq!(rows.len(), 1);
+        let record = rows[0].1.as_ref().unwrap();
+        assert_eq!(record.title, "a");
+        assert_eq!(record.content, "first\n\n---\nnot yaml");
+    }
+
+    #[test]
+    fn markdown_splits_multiple_documents() {
+        let content = "---\ntitle: a\nis_public: true\n---\nbody a\n\n---\ntitle: b\n---\nbody b\n";
+        let rows = parse_markdown(content);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].0, 1);
+        assert_eq!(rows[1].0, 7);
+
+        let a = rows[0].1.as_ref().unwrap();
+        assert_eq!((a.title.as_str(), a.content.as_str(), a.is_public), ("a", "body a", Some(true)));
+        let b = rows[1].1.as_ref().unwrap();
+        assert_eq!((b.title.as_str(), b.content.as_str()), ("b", "body b"));
+    }
+
+    #[test]
+    fn csv_empty_file_has_no_rows() {
+        assert!(parse_csv("").is_empty());
+        assert!(parse_csv("title,content\n").is_empty());
+    }
+
+    #[test]
+    fn csv_rows_report_their_line_numbers() {
+        let rows = parse_csv("title,content,is_public\na,\"x, y\",true\n,missing title\n");
+        assert_eq!(rows.len(), 2);
+
+        assert_eq!(rows[0].0, 2);
+        let a = rows[0].1.as_ref().unwrap();
+        assert_eq!((a.title.as_str(), a.content.as_str(), a.is_public), ("a", "x, y", Some(true)));
+
+        assert_eq!(rows[1].0, 3);
+        assert_eq!(rows[1].1.as_ref().unwrap().title, "");
+    }
+
+    #[test]
+    fn csv_without_title_column_is_an_error_row() {
+        let rows = parse_csv("content\nbody\n");
+        assert_eq!(rows.len(), 1);
+        assert!(rows[0].1.is_err());
+    }
+
+    #[test]
+    fn jsonl_skips_blank_lines() {
+        let rows = parse_jsonl("{\"title\":\"a\"}\n\nnot json\n");
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].0, 1);
+        assert!(rows[0].1.is_ok());
+        assert_eq!(rows[1].0, 3);
+        assert!(rows[1].1.is_err());
+    }
+
+    fn record(content: &str) -> ArticleRecord {
+        let line = serde_json::json!({ "title": "a", "content": content }).to_string();
+        parse_jsonl(&line).remove(0).1.unwrap()
+    }
+
+    #[tokio::test]
+    async fn oversized_content_is_a_row_error() {
+        let db = DatabaseConnection::Disconnected;
+        let mut categories = HashMap::new();
+
+        let fits = record(&"字".repeat(MAX_CONTENT_BYTES / 3));
+        assert!(validate_record(&db, &mut categories, fits).await.unwrap().is_ok());
+
+        // 按字节而不是字符计算：21846 个汉字超过 65535 字节
+        let oversized = record(&"字".repeat(MAX_CONTENT_BYTES / 3 + 1));
+        let error = validate_record(&db, &mut categories, oversized).await.unwrap().unwrap_err();
+        assert!(error.contains("正文不能超过"));
+    }
+}
@@ -0,0 +1,306 @@
+use sea_orm::DatabaseConnection;
+use std::collections::HashMap;
+use uuid::Uuid;
+use crate::config::EditorialConfig;
+use crate::content_filter::{ContentFilterPipeline, ContentKind, FilterInput};
+use crate::entities::article::Model as ArticleModel;
+use crate::entities::article_translation::{ActiveModel, Model};
+use crate::entities::content_report::ReportTarget;
+use crate::errors::{AppError, Result};
+use crate::extractors::{AuthUser, PreferredLanguages};
+use crate::models::{Localization, TranslationResponse, UpsertTranslationRequest};
+use crate::render::{content_stats, render_content};
+use crate::repositories::translation_repository;
+use crate::services::{article_service, moderation_service, review_service};
+use crate::utils::normalize_language_tag;
+
+/// 译文标题的最大长度
+const MAX_TITLE_CHARS: usize = 100;
+/// slug 的最大长度
+const MAX_SLUG_CHARS: usize = 120;
+
+/// 为文章选择返回的语言
+///
+/// 从原文和已发布的译文中选出最符合 `languages` 的一种，选中译文时用译文覆盖标题和正文；
+/// 没有匹配的语言时返回原文
+pub async fn localize(
+    db: &DatabaseConnection,
+    articles: Vec<ArticleModel>,
+    languages: &PreferredLanguages,
+) -> Result<Vec<(ArticleModel, Localization)>> {
+    let article_ids: Vec<Uuid> = articles.iter().map(|article| article.id).collect();
+    let mut translations: HashMap<Uuid, Vec<Model>> = HashMap::new();
+    for translation in translation_repository::find_published_by_articles(db, &article_ids).await? {
+        translations.entry(translation.article_id).or_default().push(translation);
+    }
+
+    Ok(articles
+        .into_iter()
+        .map(|mut article| {
+            let mut translations = translations.remove(&article.id).unwrap_or_default();
+            translations.retain(|translation| translation.language != article.language);
+
+            let available: Vec<String> = std::iter::once(article.language.clone())
+                .chain(translations.iter().map(|translation| translation.language.clone()))
+                .collect();
+            let chosen = languages
+                .best_match(&available.iter().map(String::as_str).collect::<Vec<_>>())
+                .map(str::to_string);
+
+            let mut slug = None;
+            let language = match chosen
+                .and_then(|language| translations.into_iter().find(|t| t.language == language))
+            {
+                Some(translation) => {
+                    slug = Some(translation.slug.clone());
+                    let language = translation.language.clone();
+                    apply_translation(&mut article, translation);
+                    language
+                }
+                None => article.language.clone(),
+            };
+
+            (article, Localization { language, languages: available, slug })
+        })
+        .collect())
+}
+
+/// 用译文覆盖文章的标题和正文相关字段
+fn apply_translation(article: &mut ArticleModel, translation: Model) {
+    article.title = translation.title;
+    article.content = translation.content;
+    article.content_format = translation.content_format;
+    article.content_html = translation.content_html;
+    article.excerpt = translation.excerpt;
+    article.word_count = translation.word_count;
+    article.reading_minutes = translation.reading_minutes;
+}
+
+/// 获取文章的译文列表
+///
+/// 作者本人和管理员可以看到未发布的译文，其他用户只能看到已发布的译文
+pub async fn list_translations(
+    db: &DatabaseConnection,
+    article_id: Uuid,
+    auth_user: Option<&AuthUser>,
+) -> Result<Vec<TranslationResponse>> {
+    let user_id = auth_user.map(|user| user.user_id);
+    let article = article_service::find_readable_article(db, article_id, user_id).await?;
+    let can_edit = auth_user.is_some_and(|user| user.is_admin() || article.user_id == Some(user.user_id));
+
+    Ok(translation_repository::find_by_article(db, article_id)
+        .await?
+        .into_iter()
+        .filter(|translation| can_edit || translation.is_published)
+        .map(TranslationResponse::from)
+        .collect())
+}
+
+/// 创建或更新译文（作者本人或管理员）
+///
+/// 语言不能与原文相同；译文同样经过内容过滤，需要审核时把文章放入待处理队列；
+/// 启用编辑审核时，文章审核通过前译文不能发布
+pub async fn upsert_translation(
+    db: &DatabaseConnection,
+    filter: &ContentFilterPipeline,
+    editorial: &EditorialConfig,
+    article_id: Uuid,
+    language: &str,
+    auth_user: &AuthUser,
+    payload: UpsertTranslationRequest,
+) -> Result<TranslationResponse> {
+    let article = article_service::find_editable_article(db, article_id, auth_user).await?;
+    let language = validate_language(language)?;
+    if language == article.language {
+        return Err(AppError::Validation("译文语言不能与原文语言相同".to_string()));
+    }
+
+    let title = validate_title(&payload.title)?;
+    if payload.content.trim().is_empty() {
+        return Err(AppError::Validation("正文不能为空".to_string()));
+    }
+    let slug = match payload.slug {
+        Some(slug) => validate_slug(&slug)?,
+        None => slugify(&title).ok_or_else(|| {
+            AppError::Validation("无法从标题生成 slug，请填写 slug".to_string())
+        })?,
+    };
+
+    let existing =
+        translation_repository::find_by_article_and_language(db, article_id, &language).await?;
+    let is_published = payload
+        .is_published
+        .unwrap_or(existing.as_ref().is_some_and(|translation| translation.is_published));
+    review_service::check_translation_publish(editorial, &article, is_published, auth_user)?;
+    if let Some(other) = translation_repository::find_by_slug(db, &language, &slug).await? {
+        if Some(other.id) != existing.as_ref().map(|translation| translation.id) {
+            return Err(AppError::Validation("slug 已存在".to_string()));
+        }
+    }
+
+    let flagged = moderation_service::screen_content(
+        db,
+        filter,
+        &FilterInput {
+            kind: ContentKind::Article,
+            id: Some(article_id),
+            author_id: article.user_id.unwrap_or(auth_user.user_id),
+            title: Some(&title),
+            body: &payload.content,
+        },
+    )
+    .await?;
+
+    let now = chrono::Utc::now();
+    let content_format = payload.content_format.unwrap_or(article.content_format);
+    let stats = content_stats(&payload.content, content_format);
+    let content_html = render_content(&payload.content, content_format);
+
+    let saved = match existing {
+        Some(existing) => {
+            let mut translation: ActiveModel = existing.into();
+            translation.title = sea_orm::Set(title);
+            translation.slug = sea_orm::Set(slug);
+            translation.content = sea_orm::Set(payload.content);
+            translation.content_format = sea_orm::Set(content_format);
+            translation.content_html = sea_orm::Set(Some(content_html));
+            translation.excerpt = sea_orm::Set(Some(stats.excerpt));
+            translation.word_count = sea_orm::Set(Some(stats.word_count));
+            translation.reading_minutes = sea_orm::Set(Some(stats.reading_minutes));
+            translation.is_published = sea_orm::Set(is_published);
+            translation.updated_at = sea_orm::Set(now);
+            translation_repository::update(db, translation).await?
+        }
+        None => {
+            let translation = ActiveModel {
+                id: sea_orm::Set(Uuid::new_v4()),
+                article_id: sea_orm::Set(article_id),
+                language: sea_orm::Set(language),
+                title: sea_orm::Set(title),
+                slug: sea_orm::Set(slug),
+                content: sea_orm::Set(payload.content),
+                content_format: sea_orm::Set(content_format),
+                content_html: sea_orm::Set(Some(content_html)),
+                excerpt: sea_orm::Set(Some(stats.excerpt)),
+                word_count: sea_orm::Set(Some(stats.word_count)),
+                reading_minutes: sea_orm::Set(Some(stats.reading_minutes)),
+                is_published: sea_orm::Set(is_published),
+                created_at: sea_orm::Set(now),
+                updated_at: sea_orm::Set(now),
+            };
+            translation_repository::create(db, translation).await?
+        }
+    };
+
+    if let Some(reason) = flagged {
+        moderation_service::flag_content(db, ReportTarget::Article, article_id, reason).await;
+    }
+
+    Ok(TranslationResponse::from(saved))
+}
+
+/// 删除译文（作者本人或管理员）
+pub async fn delete_translation(
+    db: &DatabaseConnection,
+    article_id: Uuid,
+    language: &str,
+    auth_user: &AuthUser,
+) -> Result<()> {
+    article_service::find_editable_article(db, article_id, auth_user).await?;
+    let language = validate_language(language)?;
+
+    translation_repository::find_by_article_and_language(db, article_id, &language)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+    translation_repository::delete(db, article_id, &language).await
+}
+
+/// 校验并规范化语言标签
+pub fn validate_language(language: &str) -> Result<String> {
+    normalize_language_tag(language)
+        .ok_or_else(|| AppError::Validation(format!("无效的语言标签: {}", language)))
+}
+
+fn validate_title(title: &str) -> Result<String> {
+    let title = title.trim();
+    if title.is_empty() {
+        return Err(AppError::Validation("标题不能为空".to_string()));
+    }
+    if title.chars().count() > MAX_TITLE_CHARS {
+        return Err(AppError::Validation(format!("标题不能超过 {} 个字符", MAX_TITLE_CHARS)));
+    }
+    Ok(title.to_string())
+}
+
+fn validate_slug(slug: &str) -> Result<String> {
+    let slug = slug.trim().to_lowercase();
+    let valid = !slug.is_empty()
+        && slug.len() <= MAX_SLUG_CHARS
+        && slug.chars().all(|c| c.is_ascii_alphanumeric() || c == '-');
+    if !valid {
+        return Err(AppError::Validation(format!(
+            "slug 只能包含小写字母、数字和连字符，长度 1-{}",
+            MAX_SLUG_CHARS
+        )));
+    }
+    Ok(slug)
+}
+
+/// 由标题生成 slug：保留拉丁字母和数字，其余字符合并为连字符
+fn slugify(title: &str) -> Option<String> {
+    let mut slug = String::new();
+    for c in title.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.truncate(MAX_SLUG_CHARS);
+    let slug = slug.trim_end_matches('-');
+    (!slug.is_empty()).then(|| slug.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slugify_lowercases_and_joins_words() {
+        assert_eq!(slugify("Hello, World!").as_deref(), Some("hello-world"));
+        assert_eq!(slugify("  Rust 1.80 -- Released  ").as_deref(), Some("rust-1-80-released"));
+    }
+
+    #[test]
+    fn slugify_drops_non_latin_characters() {
+        assert_eq!(slugify("使用 Rust 编写服务").as_deref(), Some("rust"));
+        assert_eq!(slugify("Café résumé").as_deref(), Some("caf-r-sum"));
+        assert_eq!(slugify("你好世界"), None);
+        assert_eq!(slugify("  !!  "), None);
+    }
+
+    #[test]
+    fn slugify_truncates_without_trailing_hyphen() {
+        let title = format!("{} tail", "a".repeat(MAX_SLUG_CHARS - 1));
+        let slug = slugify(&title).unwrap();
+        assert_eq!(slug, "a".repeat(MAX_SLUG_CHARS - 1));
+    }
+
+    #[test]
+    fn validate_slug_normalizes_case_and_rejects_invalid_characters() {
+        assert_eq!(validate_slug(" My-Slug-2 ").unwrap(), "my-slug-2");
+        assert!(validate_slug("").is_err());
+        assert!(validate_slug("with space").is_err());
+        assert!(validate_slug("中文").is_err());
+        assert!(validate_slug(&"a".repeat(MAX_SLUG_CHARS + 1)).is_err());
+    }
+
+    #[test]
+    fn validate_language_normalizes_tags() {
+        assert_eq!(validate_language("zh_cn").unwrap(), "zh-CN");
+        assert_eq!(validate_language("zh-hant-tw").unwrap(), "zh-Hant-TW");
+        assert!(validate_language("english").is_err());
+        assert!(validate_language("").is_err());
+    }
+}
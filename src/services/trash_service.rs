@@ -0,0 +1,225 @@
+use sea_orm::DatabaseConnection;
+use std::time::Duration;
+use uuid::Uuid;
+use crate::errors::{AppError, Result};
+use crate::extractors::{AuthUser, Pagination};
+use crate::models::{ArticleResponse, TrashedArticle, TrashedUser, UserResponse};
+use crate::repositories::{article_repository, attachment_repository, user_repository};
+use crate::services::user_service::{PagedResult, PaginationInfo};
+use crate::storage::Storage;
+
+/// 每次清理最多彻底删除的文章数和用户数（剩余的留到下一次）
+const PURGE_BATCH_SIZE: u64 = 200;
+
+/// 获取回收站中的文章（带分页，管理员查看全部，其他用户查看自己的）
+pub async fn list_trashed_articles(
+    db: &DatabaseConnection,
+    retention: Duration,
+    auth_user: &AuthUser,
+    pagination: &Pagination,
+) -> Result<PagedResult<Vec<TrashedArticle>>> {
+    let author_id = (!auth_user.is_admin()).then_some(auth_user.user_id);
+    let (articles, total) = article_repository::find_deleted_with_pagination(
+        db,
+        author_id,
+        pagination.offset(),
+        pagination.limit(),
+    )
+    .await?;
+
+    let list = articles
+        .into_iter()
+        .filter_map(|article| {
+            let deleted_at = article.deleted_at?;
+            Some(TrashedArticle {
+                article: ArticleResponse::summary(article),
+                deleted_at,
+                purge_at: purge_at(deleted_at, retention),
+            })
+        })
+        .collect();
+
+    Ok(PagedResult {
+        list,
+        pagination: PaginationInfo::new(pagination, total),
+    })
+}
+
+/// 从回收站恢复文章（作者本人或管理员）
+///
+/// 作者账户也在回收站中时需要先恢复作者
+pub async fn restore_article(
+    db: &DatabaseConnection,
+    article_id: Uuid,
+    auth_user: &AuthUser,
+) -> Result<ArticleResponse> {
+    let article = article_repository::find_deleted_by_id(db, article_id).await?
+        .ok_or(AppError::NotFound)?;
+    if article.user_id != Some(auth_user.user_id) && !auth_user.is_admin() {
+        return Err(AppError::NotFound);
+    }
+    if let Some(author_id) = article.user_id {
+        if user_repository::find_by_id(db, author_id).await?.is_none() {
+            return Err(AppError::Validation("作者账户已删除，请先恢复作者".to_string()));
+        }
+    }
+
+    article_repository::restore(db, article_id).await?;
+
+    let article = article_repository::find_by_id_including_hidden(db, article_id).await?
+        .ok_or(AppError::NotFound)?;
+    Ok(ArticleResponse::summary(article))
+}
+
+/// 获取回收站中的用户（仅管理员，带分页）
+pub async fn list_trashed_users(
+    db: &DatabaseConnection,
+    retention: Duration,
+    auth_user: &AuthUser,
+    pagination: &Pagination,
+) -> Result<PagedResult<Vec<TrashedUser>>> {
+    auth_user.require_admin()?;
+
+    let (users, total) =
+        user_repository::find_deleted_with_pagination(db, pagination.offset(), pagination.limit())
+            .await?;
+
+    let list = users
+        .into_iter()
+        .filter_map(|user| {
+            let deleted_at = user.deleted_at?;
+            Some(TrashedUser {
+                user: UserResponse::from(user),
+                deleted_at,
+                purge_at: purge_at(deleted_at, retention),
+            })
+        })
+        .collect();
+
+    Ok(PagedResult {
+        list,
+        pagination: PaginationInfo::new(pagination, total),
+    })
+}
+
+/// 从回收站恢复用户（仅管理员），随用户一起删除的文章同时恢复
+pub async fn restore_user(
+    db: &DatabaseConnection,
+    user_id: Uuid,
+    auth_user: &AuthUser,
+) -> Result<UserResponse> {
+    auth_user.require_admin()?;
+
+    let user = user_repository::find_deleted_by_id(db, user_id).await?
+        .ok_or(AppError::NotFound)?;
+    let deleted_at = user.deleted_at.ok_or(AppError::NotFound)?;
+
+    user_repository::restore(db, user_id, deleted_at).await?;
+
+    let user = user_repository::find_by_id(db, user_id).await?
+        .ok_or(AppError::NotFound)?;
+    Ok(UserResponse::from(user))
+}
+
+/// 一轮回收站清理的结果
+#[derive(Debug, Default)]
+pub struct PurgeReport {
+    /// 彻底删除的文章数（包含随用户删除的文章）
+    pub articles: usize,
+    /// 彻底删除的用户数
+    pub users: usize,
+    /// 到期但删除失败的文章，下一轮清理时重试
+    pub failed_articles: Vec<Uuid>,
+    /// 到期但删除失败的用户，下一轮清理时重试
+    pub failed_users: Vec<Uuid>,
+}
+
+impl PurgeReport {
+    /// 是否有到期条目删除失败
+    pub fn has_failures(&self) -> bool {
+        !self.failed_articles.is_empty() || !self.failed_users.is_empty()
+    }
+}
+
+/// 彻底删除超过保留期的文章和用户
+///
+/// 按批逐条删除，直到没有到期的条目；单条失败时在本轮后续批次中跳过，
+/// 不会阻塞其余条目，失败的条目记入返回结果，下一轮清理时重试
+pub async fn purge_expired(
+    db: &DatabaseConnection,
+    storage: &dyn Storage,
+    retention: Duration,
+) -> Result<PurgeReport> {
+    let before = chrono::Utc::now() - chrono::Duration::from_std(retention).unwrap_or_default();
+    let mut report = PurgeReport::default();
+
+    loop {
+        let batch = article_repository::find_deleted_before(
+            db,
+            before,
+            &report.failed_articles,
+            PURGE_BATCH_SIZE,
+        )
+        .await?;
+        if batch.is_empty() {
+            break;
+        }
+        for article_id in batch {
+            match purge_article(db, storage, article_id).await {
+                Ok(()) => report.articles += 1,
+                Err(e) => {
+                    tracing::warn!("彻底删除文章失败 {}: {}", article_id, e);
+                    report.failed_articles.push(article_id);
+                }
+            }
+        }
+    }
+
+    loop {
+        let batch =
+            user_repository::find_deleted_before(db, before, &report.failed_users, PURGE_BATCH_SIZE)
+                .await?;
+        if batch.is_empty() {
+            break;
+        }
+        for user_id in batch {
+            let result = async {
+                for article_id in article_repository::find_ids_by_author(db, user_id).await? {
+                    purge_article(db, storage, article_id).await?;
+                    report.articles += 1;
+                }
+                user_repository::purge(db, user_id).await
+            }
+            .await;
+            match result {
+                Ok(()) => report.users += 1,
+                Err(e) => {
+                    tracing::warn!("彻底删除用户失败 {}: {}", user_id, e);
+                    report.failed_users.push(user_id);
+                }
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+/// 彻底删除文章及其附件文件
+async fn purge_article(db: &DatabaseConnection, storage: &dyn Storage, article_id: Uuid) -> Result<()> {
+    let attachments = attachment_repository::find_by_article(db, article_id).await?;
+
+    article_repository::purge(db, article_id).await?;
+
+    // 附件记录已随文章级联删除，文件删除失败只会留下无引用的文件
+    for attachment in attachments {
+        if let Err(e) = storage.delete(&attachment.storage_key).await {
+            tracing::warn!("删除附件文件失败 {}: {}", attachment.storage_key, e);
+        }
+    }
+    Ok(())
+}
+
+/// 彻底删除的时间
+fn purge_at(deleted_at: chrono::DateTime<chrono::Utc>, retention: Duration) -> chrono::DateTime<chrono::Utc> {
+    deleted_at + chrono::Duration::from_std(retention).unwrap_or_default()
+}
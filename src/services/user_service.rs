@@ -2,8 +2,8 @@ use sea_orm::DatabaseConnection;
 use uuid::Uuid;
 use crate::entities::user::ActiveModel;
 use crate::errors::{AppError, Result};
-use crate::extractors::Pagination;
-use crate::models::{UpdateUserRequest, UserResponse};
+use crate::extractors::{Cursor, CursorPagination, IfMatch, ListField, ListQuery, Pagination};
+use crate::models::{UpdateUserRequest, UserField, UserResponse};
 use crate::repositories::user_repository;
 
 /// 分页结果
@@ -21,25 +21,97 @@ pub struct PaginationInfo {
     pub total_pages: u64,
 }
 
+impl PaginationInfo {
+    pub fn new(pagination: &Pagination, total: u64) -> Self {
+        Self {
+            page: pagination.page,
+            page_size: pagination.page_size,
+            total,
+            total_pages: pagination.total_pages(total),
+        }
+    }
+}
+
+/// 游标分页结果
+#[derive(Debug, serde::Serialize)]
+pub struct CursorPagedResult<T> {
+    pub list: T,
+    pub cursor: CursorInfo,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct CursorInfo {
+    pub limit: u64,
+    /// 下一页的游标，没有下一页时为 null
+    pub next_cursor: Option<String>,
+    /// 总数，仅在请求 `include_total=true` 时返回
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<u64>,
+}
+
+/// 列表结果：页码分页或游标分页
+#[derive(Debug, serde::Serialize)]
+#[serde(untagged)]
+pub enum ListPage<T> {
+    Paged(PagedResult<T>),
+    Cursor(CursorPagedResult<T>),
+}
+
+/// 校验游标分页只使用缺省排序
+pub fn ensure_cursor_sort<F: ListField>(list_query: &ListQuery<F>) -> Result<()> {
+    if list_query.is_default_sort() {
+        Ok(())
+    } else {
+        Err(AppError::Validation(
+            "游标分页只支持按创建时间倒序，不能与 sort 参数同时使用".to_string(),
+        ))
+    }
+}
+
 /// 获取用户列表（带分页）
 pub async fn list_users(
     db: &DatabaseConnection,
-    pagination: Pagination,
+    pagination: &Pagination,
+    list_query: ListQuery<UserField>,
 ) -> Result<PagedResult<Vec<UserResponse>>> {
-    let page = pagination.page;
-    let page_size = pagination.limit();
-    
-    let (users, total) = user_repository::find_all_with_pagination(db, page, page_size).await?;
+    // fetch_page 的页码从 0 开始
+    let (users, total) = user_repository::find_all_with_pagination(
+        db,
+        &list_query,
+        pagination.page_index(),
+        pagination.limit(),
+    )
+    .await?;
     
     let users_response: Vec<UserResponse> = users.into_iter().map(UserResponse::from).collect();
     
     Ok(PagedResult {
         list: users_response,
-        pagination: PaginationInfo {
-            page: pagination.page,
-            page_size: pagination.page_size,
+        pagination: PaginationInfo::new(pagination, total),
+    })
+}
+
+/// 游标分页获取用户列表
+pub async fn list_users_after_cursor(
+    db: &DatabaseConnection,
+    pagination: &CursorPagination,
+    list_query: ListQuery<UserField>,
+) -> Result<CursorPagedResult<Vec<UserResponse>>> {
+    ensure_cursor_sort(&list_query)?;
+
+    let (users, has_more, total) =
+        user_repository::find_after_cursor(db, &list_query, pagination).await?;
+
+    let next_cursor = has_more
+        .then(|| users.last().map(|u| Cursor { created_at: u.created_at, id: u.id }.encode()))
+        .flatten();
+
+    Ok(CursorPagedResult {
+        list: users.into_iter().map(UserResponse::from).collect(),
+        cursor: CursorInfo {
+            limit: pagination.limit,
+            next_cursor,
             total,
-            total_pages: (total as f64 / page_size as f64).ceil() as u64,
         },
     })
 }
@@ -56,14 +128,20 @@ pub async fn get_user_by_id(
 }
 
 /// 更新用户信息
+///
+/// `if_match` 与用户当前版本不一致时返回 412
 pub async fn update_user(
     db: &DatabaseConnection,
     user_id: Uuid,
     payload: UpdateUserRequest,
+    if_match: &IfMatch,
 ) -> Result<UserResponse> {
     // 检查用户是否存在
     let existing_user = user_repository::find_by_id(db, user_id).await?
         .ok_or(AppError::NotFound)?;
+    if !if_match.matches(existing_user.version) {
+        return Err(AppError::version_conflict());
+    }
     
     // 构建更新模型
     let mut user: ActiveModel = existing_user.into();
@@ -82,11 +160,11 @@ pub async fn update_user(
     Ok(UserResponse::from(updated_user))
 }
 
-/// 删除用户
+/// 删除用户（放入回收站，其文章一起放入回收站，保留期内管理员可以恢复）
 pub async fn delete_user(
     db: &DatabaseConnection,
     user_id: Uuid,
 ) -> Result<()> {
-    user_repository::delete(db, user_id).await
+    user_repository::delete(db, user_id, chrono::Utc::now()).await
 }
 
@@ -0,0 +1,89 @@
+use sea_orm::DatabaseConnection;
+use std::collections::HashMap;
+use std::time::Duration;
+use uuid::Uuid;
+use crate::errors::{AppError, Result};
+use crate::models::{ArticleResponse, TrendingArticleResponse, TrendingQuery};
+use crate::repositories::{article_repository, view_repository};
+use crate::services::engagement_service;
+
+/// 缺省统计窗口
+const DEFAULT_WINDOW: &str = "24h";
+/// 最大统计窗口（30 天）
+const MAX_WINDOW: Duration = Duration::from_secs(30 * 24 * 3600);
+/// 缺省返回数量
+const DEFAULT_LIMIT: u64 = 20;
+/// 最大返回数量
+const MAX_LIMIT: u64 = 100;
+
+/// 获取热门公开文章
+///
+/// 浏览量按时间指数衰减，半衰期为统计窗口的四分之一，
+/// 因此同样的浏览量，越新的文章排名越靠前
+pub async fn trending_articles(
+    db: &DatabaseConnection,
+    query: TrendingQuery,
+    viewer: Option<Uuid>,
+) -> Result<Vec<TrendingArticleResponse>> {
+    let window = parse_window(query.window.as_deref().unwrap_or(DEFAULT_WINDOW))?;
+    let limit = query.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+
+    let half_life = window.as_secs_f64() / 4.0;
+    let decay_per_second = std::f64::consts::LN_2 / half_life;
+    let since = chrono::Utc::now()
+        - chrono::Duration::from_std(window)
+            .map_err(|e| AppError::Internal(anyhow::anyhow!("统计窗口超出范围: {}", e)))?;
+
+    let ranked = view_repository::find_trending(db, since, decay_per_second, limit).await?;
+
+    let ids: Vec<Uuid> = ranked.iter().map(|(id, _, _)| *id).collect();
+    let mut articles: HashMap<Uuid, _> = article_repository::find_by_ids(db, &ids)
+        .await?
+        .into_iter()
+        .map(|a| (a.id, a))
+        .collect();
+
+    let (mut responses, stats): (Vec<ArticleResponse>, Vec<(f64, i64)>) = ranked
+        .into_iter()
+        .filter_map(|(id, score, window_views)| {
+            let article = articles.remove(&id)?;
+            Some((ArticleResponse::summary(article), (score, window_views)))
+        })
+        .unzip();
+    engagement_service::attach_engagement(db, &mut responses, viewer).await?;
+
+    Ok(responses
+        .into_iter()
+        .zip(stats)
+        .map(|(article, (score, window_views))| TrendingArticleResponse {
+            article,
+            score,
+            window_views,
+        })
+        .collect())
+}
+
+/// 解析统计窗口，支持 `h`（小时）和 `d`（天）两种单位
+fn parse_window(window: &str) -> Result<Duration> {
+    let invalid = || AppError::Validation(format!("无效的统计窗口: {}，示例：1h、24h、7d", window));
+
+    let window = window.trim();
+    let (number, unit_secs) = if let Some(hours) = window.strip_suffix('h') {
+        (hours, 3600)
+    } else if let Some(days) = window.strip_suffix('d') {
+        (days, 24 * 3600)
+    } else {
+        return Err(invalid());
+    };
+    let number: u64 = number.parse().map_err(|_| invalid())?;
+    if number == 0 {
+        return Err(invalid());
+    }
+
+    let duration = Duration::from_secs(number.saturating_mul(unit_secs));
+    if duration > MAX_WINDOW {
+        return Err(AppError::Validation("统计窗口不能超过 30 天".to_string()));
+    }
+
+    Ok(duration)
+}
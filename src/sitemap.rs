@@ -0,0 +1,63 @@
+use crate::errors::Result;
+use crate::services::sitemap_service::{self, Sitemap};
+use sea_orm::DatabaseConnection;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+/// 站点地图缓存
+///
+/// 站点地图需要扫描文章表和用户表，因此由后台任务定期重新生成，
+/// 请求只读取缓存；缓存尚未生成（如刚启动）时才在请求中同步生成一次
+pub struct SitemapCache {
+    base_url: String,
+    current: RwLock<Option<Arc<Sitemap>>>,
+}
+
+impl SitemapCache {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            current: RwLock::new(None),
+        }
+    }
+
+    /// 获取当前站点地图，缓存为空时立即生成
+    pub async fn get(&self, db: &DatabaseConnection) -> Result<Arc<Sitemap>> {
+        let cached = self
+            .current
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone();
+        match cached {
+            Some(sitemap) => Ok(sitemap),
+            None => self.refresh(db).await,
+        }
+    }
+
+    /// 重新生成站点地图并替换缓存
+    pub async fn refresh(&self, db: &DatabaseConnection) -> Result<Arc<Sitemap>> {
+        let sitemap = Arc::new(sitemap_service::generate(db, &self.base_url).await?);
+        *self.current.write().unwrap_or_else(|e| e.into_inner()) = Some(sitemap.clone());
+        Ok(sitemap)
+    }
+
+    /// 启动定期重新生成的后台任务（启动时立即生成一次）
+    pub fn spawn_refresh_task(
+        self: Arc<Self>,
+        db: DatabaseConnection,
+        interval: Duration,
+    ) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                match self.refresh(&db).await {
+                    Ok(sitemap) => tracing::debug!("站点地图已生成: {} 个文件", sitemap.pages.len()),
+                    // 生成失败时保留旧的缓存继续提供服务
+                    Err(e) => tracing::warn!("站点地图生成失败: {}", e),
+                }
+            }
+        })
+    }
+}
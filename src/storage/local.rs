@@ -0,0 +1,80 @@
+use super::Storage;
+use crate::errors::{AppError, Result};
+use axum::async_trait;
+use axum::body::Bytes;
+use std::path::{Component, Path, PathBuf};
+
+/// 本地文件系统存储
+#[derive(Debug, Clone)]
+pub struct LocalStorage {
+    root: PathBuf,
+}
+
+impl LocalStorage {
+    /// 创建本地存储，根目录不存在时自动创建
+    pub async fn new(root: impl AsRef<Path>) -> Result<Self> {
+        let root = root.as_ref().to_path_buf();
+        tokio::fs::create_dir_all(&root)
+            .await
+            .map_err(|e| AppError::Internal(anyhow::anyhow!("创建存储目录失败: {}", e)))?;
+
+        Ok(Self { root })
+    }
+
+    /// 将 key 解析为根目录下的路径，拒绝绝对路径和 `..`
+    fn resolve(&self, key: &str) -> Result<PathBuf> {
+        let relative = Path::new(key);
+        let safe = relative
+            .components()
+            .all(|c| matches!(c, Component::Normal(_)));
+        if !safe {
+            return Err(AppError::Internal(anyhow::anyhow!("非法的存储 key: {}", key)));
+        }
+        Ok(self.root.join(relative))
+    }
+}
+
+#[async_trait]
+impl Storage for LocalStorage {
+    async fn put(&self, key: &str, data: Bytes, _content_type: &str) -> Result<()> {
+        let path = self.resolve(key)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| AppError::Internal(anyhow::anyhow!("创建目录失败: {}", e)))?;
+        }
+
+        // 先写临时文件再重命名，避免读到写了一半的文件
+        let tmp = path.with_extension("tmp");
+        tokio::fs::write(&tmp, &data)
+            .await
+            .map_err(|e| AppError::Internal(anyhow::anyhow!("写入文件失败: {}", e)))?;
+        tokio::fs::rename(&tmp, &path)
+            .await
+            .map_err(|e| AppError::Internal(anyhow::anyhow!("写入文件失败: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Bytes> {
+        let path = self.resolve(key)?;
+        match tokio::fs::read(&path).await {
+            Ok(data) => Ok(Bytes::from(data)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Err(AppError::NotFound),
+            Err(e) => Err(AppError::Internal(anyhow::anyhow!("读取文件失败: {}", e))),
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        let path = self.resolve(key)?;
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(AppError::Internal(anyhow::anyhow!("删除文件失败: {}", e))),
+        }
+    }
+
+    fn local_path(&self, key: &str) -> Option<PathBuf> {
+        self.resolve(key).ok()
+    }
+}
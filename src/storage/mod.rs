@@ -0,0 +1,44 @@
+// 文件存储模块
+//
+// 附件内容通过 `Storage` trait 读写，业务代码不关心具体后端；
+// 新增后端（例如 S3 兼容存储）只需实现该 trait 并在 `create_storage` 中注册
+pub mod local;
+
+use crate::config::{StorageBackend, StorageConfig};
+use crate::errors::Result;
+use axum::async_trait;
+use axum::body::Bytes;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+pub use local::LocalStorage;
+
+/// 对象存储抽象
+///
+/// `key` 由业务层生成（形如 `attachments/2024/01/<uuid>`），只包含安全字符
+#[async_trait]
+pub trait Storage: Send + Sync {
+    /// 写入对象（已存在时覆盖）
+    async fn put(&self, key: &str, data: Bytes, content_type: &str) -> Result<()>;
+
+    /// 读取对象全部内容
+    async fn get(&self, key: &str) -> Result<Bytes>;
+
+    /// 删除对象（不存在时不报错）
+    async fn delete(&self, key: &str) -> Result<()>;
+
+    /// 对象在本地文件系统中的路径
+    ///
+    /// 本地后端返回 `Some`，下载时可直接交给 `tower_http::services::ServeFile`
+    /// 以支持 Range 和条件请求；远程后端返回 `None`，由 `get` 读取内容
+    fn local_path(&self, _key: &str) -> Option<PathBuf> {
+        None
+    }
+}
+
+/// 根据配置创建存储后端
+pub async fn create_storage(config: &StorageConfig) -> Result<Arc<dyn Storage>> {
+    match config.backend {
+        StorageBackend::Local => Ok(Arc::new(LocalStorage::new(&config.local_root).await?)),
+    }
+}
@@ -0,0 +1,44 @@
+use crate::config::TrashConfig;
+use crate::services::trash_service;
+use crate::storage::Storage;
+use sea_orm::DatabaseConnection;
+use std::sync::Arc;
+
+/// 启动定期清理回收站的后台任务（启动时立即清理一次）
+///
+/// 删除时间超过保留期的文章和用户被彻底删除，文章的附件文件一并删除；
+/// 到期但无法删除的条目每一轮都以 error 级别报告，直到删除成功
+pub fn spawn_purge_task(
+    db: DatabaseConnection,
+    storage: Arc<dyn Storage>,
+    config: TrashConfig,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(config.purge_interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            match trash_service::purge_expired(&db, storage.as_ref(), config.retention).await {
+                Ok(report) => {
+                    if report.articles > 0 || report.users > 0 {
+                        tracing::info!(
+                            "回收站已清理: {} 篇文章, {} 个用户",
+                            report.articles,
+                            report.users
+                        );
+                    }
+                    if report.has_failures() {
+                        tracing::error!(
+                            "回收站中有 {} 篇文章、{} 个用户已过保留期但无法彻底删除: 文章 {:?}, 用户 {:?}",
+                            report.failed_articles.len(),
+                            report.failed_users.len(),
+                            report.failed_articles,
+                            report.failed_users
+                        );
+                    }
+                }
+                Err(e) => tracing::error!("回收站清理失败: {}", e),
+            }
+        }
+    })
+}
@@ -17,3 +17,64 @@ where
     let code = u16::deserialize(deserializer)?;
     StatusCode::from_u16(code).map_err(serde::de::Error::custom)
 }
+
+/// 自定义反序列化：区分“字段缺省”和“显式为 null”
+///
+/// 配合 `#[serde(default, deserialize_with = "deserialize_some")]` 用于 `Option<Option<T>>`：
+/// 缺省为 `None`，`null` 为 `Some(None)`，有值为 `Some(Some(v))`
+pub fn deserialize_some<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    T::deserialize(deserializer).map(Some)
+}
+
+/// 转义 HTML/XML 特殊字符
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// 规范化 BCP 47 语言标签：语言子标签小写，地区子标签大写，文字子标签首字母大写
+///
+/// `zh_cn`、`ZH-cn` 规范化为 `zh-CN`，`zh-hant` 规范化为 `zh-Hant`；格式无效时返回 None
+pub fn normalize_language_tag(tag: &str) -> Option<String> {
+    let tag = tag.trim();
+    if tag.is_empty() || tag.len() > 16 {
+        return None;
+    }
+
+    let mut normalized = Vec::new();
+    for (index, subtag) in tag.split(['-', '_']).enumerate() {
+        if subtag.is_empty() || subtag.len() > 8 || !subtag.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return None;
+        }
+        let subtag = if index == 0 {
+            if !(2..=3).contains(&subtag.len()) || !subtag.chars().all(|c| c.is_ascii_alphabetic()) {
+                return None;
+            }
+            subtag.to_ascii_lowercase()
+        } else if subtag.len() == 2 {
+            subtag.to_ascii_uppercase()
+        } else if subtag.len() == 4 && subtag.chars().all(|c| c.is_ascii_alphabetic()) {
+            let lower = subtag.to_ascii_lowercase();
+            lower[..1].to_ascii_uppercase() + &lower[1..]
+        } else {
+            subtag.to_ascii_lowercase()
+        };
+        normalized.push(subtag);
+    }
+
+    Some(normalized.join("-"))
+}
@@ -0,0 +1,275 @@
+use crate::config::ViewConfig;
+use crate::repositories::view_repository;
+use axum::http::HeaderMap;
+use chrono::{DateTime, DurationRound, Utc};
+use sea_orm::DatabaseConnection;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+/// 文章浏览量计数器
+///
+/// - 去重：同一访客在 `dedup_window` 内重复访问同一篇文章只计一次
+/// - 批量写入：浏览量先在内存中按 `(文章, 小时)` 聚合，由后台任务定期写入数据库，
+///   避免每次读取文章都执行一次 UPDATE
+///
+/// 计数保存在单个进程的内存中，多实例部署时各实例独立去重；
+/// 去重记录最多保存 `max_tracked_visitors` 条，记满后新访客的浏览不计入，
+/// 直到过期记录在下一次写入时被清理
+pub struct ViewCounter {
+    dedup_window: Duration,
+    max_tracked_visitors: usize,
+    state: Mutex<CounterState>,
+}
+
+#[derive(Default)]
+struct CounterState {
+    /// (文章 ID, 访客标识) -> 最近一次计数时间
+    seen: HashMap<(Uuid, u64), Instant>,
+    /// (文章 ID, 小时桶) -> 待写入的浏览量
+    pending: HashMap<(Uuid, DateTime<Utc>), u64>,
+}
+
+impl ViewCounter {
+    pub fn new(config: &ViewConfig) -> Self {
+        Self {
+            dedup_window: config.dedup_window,
+            max_tracked_visitors: config.max_tracked_visitors,
+            state: Mutex::new(CounterState::default()),
+        }
+    }
+
+    /// 记录一次浏览，返回是否计入（去重窗口内的重复访问、去重记录已满时的新访客返回 false）
+    pub fn record(&self, article_id: Uuid, visitor: u64) -> bool {
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+
+        match state.seen.get(&(article_id, visitor)) {
+            Some(last) if now.duration_since(*last) < self.dedup_window => return false,
+            Some(_) => {}
+            None if state.seen.len() >= self.max_tracked_visitors => return false,
+            None => {}
+        }
+        state.seen.insert((article_id, visitor), now);
+
+        *state.pending.entry((article_id, current_bucket())).or_default() += 1;
+        true
+    }
+
+    /// 将待写入的浏览量写入数据库，失败时放回内存等待下次重试
+    pub async fn flush(&self, db: &DatabaseConnection) {
+        let pending: Vec<(Uuid, DateTime<Utc>, u64)> = {
+            let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+            // 顺便清理已过期的去重记录，防止内存无限增长
+            let window = self.dedup_window;
+            state.seen.retain(|_, last| last.elapsed() < window);
+            state
+                .pending
+                .drain()
+                .map(|((article_id, bucket), count)| (article_id, bucket, count))
+                .collect()
+        };
+        if pending.is_empty() {
+            return;
+        }
+
+        match view_repository::add_views(db, &pending).await {
+            Ok(()) => tracing::debug!("浏览量写入成功: {} 条", pending.len()),
+            Err(e) => {
+                tracing::warn!("浏览量写入失败，稍后重试: {}", e);
+                let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+                for (article_id, bucket, count) in pending {
+                    *state.pending.entry((article_id, bucket)).or_default() += count;
+                }
+            }
+        }
+    }
+
+    /// 启动定期写入的后台任务
+    pub fn spawn_flush_task(
+        self: Arc<Self>,
+        db: DatabaseConnection,
+        interval: Duration,
+    ) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                self.flush(&db).await;
+            }
+        })
+    }
+}
+
+/// 计算访客标识
+///
+/// 登录用户使用用户 ID；匿名访客使用客户端 IP（见 [`client_ip`]）和 User-Agent 的哈希
+pub fn visitor_key(
+    user_id: Option<Uuid>,
+    headers: &HeaderMap,
+    addr: Option<SocketAddr>,
+    trusted_proxies: &[IpAddr],
+) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    match user_id {
+        Some(user_id) => {
+            "user".hash(&mut hasher);
+            user_id.hash(&mut hasher);
+        }
+        None => {
+            let ip = client_ip(headers, addr, trusted_proxies);
+            let user_agent = headers
+                .get(axum::http::header::USER_AGENT)
+                .and_then(|v| v.to_str().ok());
+
+            "anonymous".hash(&mut hasher);
+            ip.hash(&mut hasher);
+            user_agent.hash(&mut hasher);
+        }
+    }
+    hasher.finish()
+}
+
+/// 解析客户端 IP
+///
+/// 直连地址不是可信代理时直接使用直连地址，忽略客户端可以伪造的代理头；
+/// 来自可信代理时从 `X-Forwarded-For` 的右侧向左跳过可信代理，取第一个不可信的地址，
+/// 没有 `X-Forwarded-For` 时使用 `X-Real-IP`；代理头无效时退回直连地址
+pub fn client_ip(
+    headers: &HeaderMap,
+    addr: Option<SocketAddr>,
+    trusted_proxies: &[IpAddr],
+) -> Option<IpAddr> {
+    let peer = addr?.ip();
+    if !trusted_proxies.contains(&peer) {
+        return Some(peer);
+    }
+
+    let hops: Vec<&str> = headers
+        .get_all("x-forwarded-for")
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .map(str::trim)
+        .collect();
+    if hops.is_empty() {
+        let real_ip = headers
+            .get("x-real-ip")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.trim().parse().ok());
+        return Some(real_ip.unwrap_or(peer));
+    }
+
+    let mut client = peer;
+    for hop in hops.iter().rev() {
+        let Ok(ip) = hop.parse::<IpAddr>() else {
+            return Some(peer);
+        };
+        client = ip;
+        if !trusted_proxies.contains(&ip) {
+            break;
+        }
+    }
+    Some(client)
+}
+
+/// 当前时间所在小时的起始时间
+fn current_bucket() -> DateTime<Utc> {
+    let now = Utc::now();
+    now.duration_trunc(chrono::Duration::hours(1)).unwrap_or(now)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROXY: [u8; 4] = [10, 0, 0, 1];
+
+    fn peer(ip: [u8; 4]) -> Option<SocketAddr> {
+        Some(SocketAddr::from((ip, 50000)))
+    }
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.append(*name, value.parse().unwrap());
+        }
+        headers
+    }
+
+    fn trusted() -> Vec<IpAddr> {
+        vec![IpAddr::from(PROXY)]
+    }
+
+    #[test]
+    fn forwarding_headers_are_ignored_without_trusted_proxy() {
+        let spoofed = headers(&[("x-forwarded-for", "1.2.3.4"), ("x-real-ip", "5.6.7.8")]);
+        assert_eq!(
+            client_ip(&spoofed, peer([203, 0, 113, 9]), &[]),
+            Some(IpAddr::from([203, 0, 113, 9]))
+        );
+        // 直连地址不是可信代理时同样忽略
+        assert_eq!(
+            client_ip(&spoofed, peer([203, 0, 113, 9]), &trusted()),
+            Some(IpAddr::from([203, 0, 113, 9]))
+        );
+    }
+
+    #[test]
+    fn trusted_proxy_uses_right_most_untrusted_hop() {
+        // 客户端伪造的最左侧地址不会被采用
+        let forwarded = headers(&[("x-forwarded-for", "1.2.3.4, 198.51.100.7, 10.0.0.1")]);
+        assert_eq!(
+            client_ip(&forwarded, peer(PROXY), &trusted()),
+            Some(IpAddr::from([198, 51, 100, 7]))
+        );
+
+        let split = headers(&[("x-forwarded-for", "1.2.3.4"), ("x-forwarded-for", "198.51.100.7")]);
+        assert_eq!(
+            client_ip(&split, peer(PROXY), &trusted()),
+            Some(IpAddr::from([198, 51, 100, 7]))
+        );
+    }
+
+    #[test]
+    fn trusted_proxy_falls_back_to_real_ip_and_peer() {
+        let real_ip = headers(&[("x-real-ip", "198.51.100.7")]);
+        assert_eq!(
+            client_ip(&real_ip, peer(PROXY), &trusted()),
+            Some(IpAddr::from([198, 51, 100, 7]))
+        );
+        assert_eq!(client_ip(&HeaderMap::new(), peer(PROXY), &trusted()), Some(IpAddr::from(PROXY)));
+
+        let invalid = headers(&[("x-forwarded-for", "1.2.3.4, not-an-ip")]);
+        assert_eq!(client_ip(&invalid, peer(PROXY), &trusted()), Some(IpAddr::from(PROXY)));
+        assert_eq!(client_ip(&invalid, None, &trusted()), None);
+    }
+
+    #[test]
+    fn rotating_spoofed_headers_do_not_create_new_visitors() {
+        let a = visitor_key(None, &headers(&[("x-forwarded-for", "1.1.1.1")]), peer([203, 0, 113, 9]), &[]);
+        let b = visitor_key(None, &headers(&[("x-forwarded-for", "2.2.2.2")]), peer([203, 0, 113, 9]), &[]);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn record_stops_tracking_new_visitors_when_full() {
+        let counter = ViewCounter::new(&ViewConfig {
+            dedup_window: Duration::from_secs(60),
+            flush_interval: Duration::from_secs(30),
+            max_tracked_visitors: 2,
+        });
+        let article_id = Uuid::new_v4();
+
+        assert!(counter.record(article_id, 1));
+        assert!(!counter.record(article_id, 1));
+        assert!(counter.record(article_id, 2));
+        assert!(!counter.record(article_id, 3));
+        assert_eq!(counter.state.lock().unwrap().seen.len(), 2);
+    }
+}